@@ -1,10 +1,17 @@
 use sky_engine_core::{
     catalog::StarCatalog,
-    comets::{compute_all_comet_positions, Comet},
-    coords::{apply_topocentric_correction, cartesian_to_ra_dec, compute_gmst, ra_dec_to_cartesian},
-    minor_bodies::{compute_all_minor_body_positions, MinorBody},
+    comets::{compute_all_comet_positions, compute_comet_position_from_elements, Comet, CometElements},
+    coords::{
+        apply_topocentric_correction, cartesian_to_ra_dec, compute_gmst, ra_dec_to_cartesian,
+        CartesianCoord,
+    },
+    minor_bodies::{
+        compute_all_minor_body_positions, compute_minor_body_position_from_elements, MinorBody,
+        OrbitalElements,
+    },
     planetary_moons::{compute_all_planetary_moon_positions, PlanetaryMoon},
     planets::{compute_all_body_positions_full, compute_moon_position_full, CelestialBody},
+    rise_set::{equatorial_to_horizontal, rise_set_transit, Observer, TwilightMode},
     time::SkyTime,
 };
 use std::f64::consts::PI;
@@ -25,12 +32,25 @@ pub struct SkyEngine {
     // Output buffers (owned by Rust, read by JS)
     stars_pos: Vec<f32>,  // x,y,z,x,y,z,... unit vectors (magnitude-filtered)
     stars_meta: Vec<f32>, // vmag, bv_color, id (as f32), padding (magnitude-filtered)
+    stars_altaz: Vec<f32>, // alt,az,alt,az,... in radians, parallel to stars_pos (magnitude-filtered)
     bodies_pos: Vec<f32>, // 9 celestial bodies * 3 coords = 27 floats (Sun, Moon, 7 planets)
     bodies_angular_diameters: Vec<f32>, // 9 angular diameters in radians
-    planetary_moons_pos: Vec<f32>, // 18 moons * 4 floats (x, y, z, angular_diam) = 72
-    minor_bodies_pos: Vec<f32>, // N minor bodies * 4 floats (x, y, z, angular_diam)
+    bodies_altaz: Vec<f32>, // 9 bodies * 2 floats (alt, az) in radians
+    bodies_magnitude: Vec<f32>, // 9 apparent visual magnitudes
+    bodies_phase_angle: Vec<f32>, // 9 Sun-body-Earth phase angles, radians
+    bodies_illuminated_fraction: Vec<f32>, // 9 illuminated disk fractions, k = (1 + cos i) / 2
+    planetary_moons_pos: Vec<f32>, // N moons * 5 floats (x, y, z, angular_diam, magnitude)
+    minor_bodies_pos: Vec<f32>, // N minor bodies * 5 floats (x, y, z, angular_diam, magnitude)
     comets_pos: Vec<f32>, // N comets * 4 floats (x, y, z, magnitude)
 
+    // Comets and minor bodies added at runtime from MPC/JPL-style osculating
+    // elements, e.g. a newly discovered object, rather than the bundled
+    // `Comet::ALL` / `MinorBody::ALL` tables above.
+    custom_comets: Vec<CometElements>,
+    custom_comets_pos: Vec<f32>, // N custom comets * 4 floats (x, y, z, magnitude)
+    custom_minor_bodies: Vec<OrbitalElements>,
+    custom_minor_bodies_pos: Vec<f32>, // N custom minor bodies * 5 floats (x, y, z, angular_diam, magnitude)
+
     // All star positions for constellation line drawing (not magnitude-filtered)
     all_stars_pos: Vec<f32>,  // x,y,z for ALL stars in catalog
     all_stars_meta: Vec<f32>, // vmag, bv_color, id, padding for ALL stars
@@ -66,11 +86,20 @@ impl SkyEngine {
             observer_lon_rad: default_lon_deg * PI / 180.0,
             stars_pos: vec![0.0; star_count * 3],
             stars_meta: vec![0.0; star_count * 4], // vmag, bv, id, padding
+            stars_altaz: vec![0.0; star_count * 2], // alt, az
             bodies_pos: vec![0.0; 9 * 3], // Sun, Moon, Mercury, Venus, Mars, Jupiter, Saturn, Uranus, Neptune
             bodies_angular_diameters: vec![0.0; 9], // Angular diameters for each body
-            planetary_moons_pos: vec![0.0; PlanetaryMoon::ALL.len() * 4], // 18 moons total
-            minor_bodies_pos: vec![0.0; MinorBody::ALL.len() * 4], // Pluto (dwarf planets)
+            bodies_altaz: vec![0.0; 9 * 2], // alt, az for each body
+            bodies_magnitude: vec![0.0; 9], // Apparent magnitude for each body
+            bodies_phase_angle: vec![0.0; 9], // Phase angle for each body
+            bodies_illuminated_fraction: vec![1.0; 9], // Illuminated fraction for each body
+            planetary_moons_pos: vec![0.0; PlanetaryMoon::ALL.len() * 5], // x, y, z, angular_diam, magnitude
+            minor_bodies_pos: vec![0.0; MinorBody::ALL.len() * 5], // Pluto (dwarf planets)
             comets_pos: vec![0.0; Comet::ALL.len() * 4], // 7 comets * 4 floats (x, y, z, magnitude)
+            custom_comets: Vec::new(),
+            custom_comets_pos: Vec::new(),
+            custom_minor_bodies: Vec::new(),
+            custom_minor_bodies_pos: Vec::new(),
             all_stars_pos: vec![0.0; star_count * 3],
             all_stars_meta: vec![0.0; star_count * 4],
             visible_count: 0,
@@ -145,6 +174,82 @@ impl SkyEngine {
         self.recompute_planetary_moons();
         self.recompute_minor_bodies();
         self.recompute_comets();
+        self.recompute_custom_comets();
+        self.recompute_custom_minor_bodies();
+    }
+
+    /// Register a comet discovered at runtime from its MPC/JPL-style
+    /// osculating elements (perihelion distance/time rather than the fixed
+    /// mean anomaly the bundled `Comet::ALL` table uses), so a newly
+    /// announced object can be tracked without a recompile. Call
+    /// `recompute()` afterward to populate its position buffer.
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_comet_elements(
+        &mut self,
+        name: String,
+        perihelion_distance_au: f64,
+        eccentricity: f64,
+        inclination_deg: f64,
+        ascending_node_deg: f64,
+        arg_perihelion_deg: f64,
+        perihelion_jd: f64,
+        abs_magnitude: f64,
+        magnitude_slope: f64,
+        nuclear_abs_magnitude: f64,
+        nuclear_magnitude_slope: f64,
+    ) -> usize {
+        self.custom_comets.push(CometElements::from_mpc_elements(
+            name,
+            perihelion_distance_au,
+            eccentricity,
+            inclination_deg,
+            ascending_node_deg,
+            arg_perihelion_deg,
+            perihelion_jd,
+            abs_magnitude,
+            magnitude_slope,
+            nuclear_abs_magnitude,
+            nuclear_magnitude_slope,
+        ));
+        self.custom_comets_pos.resize(self.custom_comets.len() * 4, 0.0);
+        self.custom_comets.len() - 1
+    }
+
+    /// Register a minor body (asteroid, dwarf planet, TNO, ...) discovered at
+    /// runtime from its MPC/JPL-style osculating elements, rather than the
+    /// bundled `MinorBody::ALL` table, so a newly catalogued object can be
+    /// tracked without a recompile. Call `recompute()` afterward to populate
+    /// its position buffer.
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_minor_body_elements(
+        &mut self,
+        name: String,
+        semi_major_axis_au: f64,
+        eccentricity: f64,
+        inclination_deg: f64,
+        ascending_node_deg: f64,
+        arg_perihelion_deg: f64,
+        mean_anomaly_j2000_deg: f64,
+        orbital_period_years: f64,
+        radius_km: f64,
+        abs_mag_h: f64,
+        slope_g: f64,
+    ) -> usize {
+        self.custom_minor_bodies.push(OrbitalElements::from_mpc_elements(
+            name,
+            semi_major_axis_au,
+            eccentricity,
+            inclination_deg,
+            ascending_node_deg,
+            arg_perihelion_deg,
+            mean_anomaly_j2000_deg,
+            orbital_period_years,
+            radius_km,
+            abs_mag_h,
+            slope_g,
+        ));
+        self.custom_minor_bodies_pos.resize(self.custom_minor_bodies.len() * 5, 0.0);
+        self.custom_minor_bodies.len() - 1
     }
 
     /// Add more stars to the catalog from binary data.
@@ -159,6 +264,7 @@ impl SkyEngine {
             let new_count = self.catalog.len();
             self.stars_pos.resize(new_count * 3, 0.0);
             self.stars_meta.resize(new_count * 4, 0.0);
+            self.stars_altaz.resize(new_count * 2, 0.0);
             self.all_stars_pos.resize(new_count * 3, 0.0);
             self.all_stars_meta.resize(new_count * 4, 0.0);
 
@@ -169,15 +275,21 @@ impl SkyEngine {
         Ok(added)
     }
 
+    /// The current observer location, used for horizontal-coordinate and
+    /// rise/set queries. Elevation is assumed to be sea level: only latitude
+    /// and longitude are configurable via `set_observer_location`.
+    fn observer(&self) -> Observer {
+        Observer::new(self.observer_lat_rad, self.observer_lon_rad, 0.0)
+    }
+
     fn recompute_stars(&mut self) {
+        let observer = self.observer();
         let mut pos_idx = 0;
         let mut meta_idx = 0;
+        let mut altaz_idx = 0;
         let mut count = 0;
 
-        for star in self.catalog.stars_brighter_than(self.mag_limit) {
-            let dir = star.direction();
-            let (x, y, z) = dir.to_f32();
-
+        for (star, &(x, y, z)) in self.catalog.stars_brighter_than_with_direction(self.mag_limit) {
             // Ensure we have space (should always be true if catalog doesn't grow)
             if pos_idx + 3 <= self.stars_pos.len() {
                 self.stars_pos[pos_idx] = x;
@@ -194,6 +306,14 @@ impl SkyEngine {
                 meta_idx += 4;
             }
 
+            if altaz_idx + 2 <= self.stars_altaz.len() {
+                let dir = CartesianCoord::new(x as f64, y as f64, z as f64);
+                let horizontal = equatorial_to_horizontal(&dir, &self.time, &observer);
+                self.stars_altaz[altaz_idx] = horizontal.altitude_rad as f32;
+                self.stars_altaz[altaz_idx + 1] = horizontal.azimuth_rad as f32;
+                altaz_idx += 2;
+            }
+
             count += 1;
         }
 
@@ -202,6 +322,7 @@ impl SkyEngine {
 
     fn recompute_bodies(&mut self) {
         let positions = compute_all_body_positions_full(&self.time);
+        let observer = self.observer();
 
         // Compute GMST for topocentric corrections
         let jd_ut1 = self.time.julian_date_utc(); // Close enough to UT1 for our purposes
@@ -237,6 +358,15 @@ impl SkyEngine {
             self.bodies_pos[idx + 1] = y;
             self.bodies_pos[idx + 2] = z;
             self.bodies_angular_diameters[i] = body_pos.angular_diameter_rad as f32;
+
+            let horizontal = equatorial_to_horizontal(&direction, &self.time, &observer);
+            let altaz_idx = i * 2;
+            self.bodies_altaz[altaz_idx] = horizontal.altitude_rad as f32;
+            self.bodies_altaz[altaz_idx + 1] = horizontal.azimuth_rad as f32;
+
+            self.bodies_magnitude[i] = body_pos.apparent_magnitude as f32;
+            self.bodies_phase_angle[i] = body_pos.phase_angle_rad as f32;
+            self.bodies_illuminated_fraction[i] = body_pos.illuminated_fraction as f32;
         }
     }
 
@@ -244,11 +374,12 @@ impl SkyEngine {
         let positions = compute_all_planetary_moon_positions(&self.time);
         for (i, moon_pos) in positions.iter().enumerate() {
             let (x, y, z) = moon_pos.direction.to_f32();
-            let idx = i * 4;
+            let idx = i * 5;
             self.planetary_moons_pos[idx] = x;
             self.planetary_moons_pos[idx + 1] = y;
             self.planetary_moons_pos[idx + 2] = z;
             self.planetary_moons_pos[idx + 3] = moon_pos.angular_diameter_rad as f32;
+            self.planetary_moons_pos[idx + 4] = moon_pos.apparent_magnitude as f32;
         }
     }
 
@@ -256,11 +387,12 @@ impl SkyEngine {
         let positions = compute_all_minor_body_positions(&self.time);
         for (i, body_pos) in positions.iter().enumerate() {
             let (x, y, z) = body_pos.direction.to_f32();
-            let idx = i * 4;
+            let idx = i * 5;
             self.minor_bodies_pos[idx] = x;
             self.minor_bodies_pos[idx + 1] = y;
             self.minor_bodies_pos[idx + 2] = z;
             self.minor_bodies_pos[idx + 3] = body_pos.angular_diameter_rad as f32;
+            self.minor_bodies_pos[idx + 4] = body_pos.visual_magnitude as f32;
         }
     }
 
@@ -277,14 +409,42 @@ impl SkyEngine {
         }
     }
 
+    fn recompute_custom_comets(&mut self) {
+        for (i, elem) in self.custom_comets.iter().enumerate() {
+            let comet_pos = compute_comet_position_from_elements(elem, &self.time);
+            let (x, y, z) = comet_pos.direction.to_f32();
+            let idx = i * 4;
+            self.custom_comets_pos[idx] = x;
+            self.custom_comets_pos[idx + 1] = y;
+            self.custom_comets_pos[idx + 2] = z;
+            self.custom_comets_pos[idx + 3] = comet_pos.magnitude as f32;
+        }
+    }
+
+    fn recompute_custom_minor_bodies(&mut self) {
+        for (i, elem) in self.custom_minor_bodies.iter().enumerate() {
+            let body_pos = compute_minor_body_position_from_elements(elem, &self.time);
+            let (x, y, z) = body_pos.direction.to_f32();
+            let idx = i * 5;
+            self.custom_minor_bodies_pos[idx] = x;
+            self.custom_minor_bodies_pos[idx + 1] = y;
+            self.custom_minor_bodies_pos[idx + 2] = z;
+            self.custom_minor_bodies_pos[idx + 3] = body_pos.angular_diameter_rad as f32;
+            self.custom_minor_bodies_pos[idx + 4] = body_pos.visual_magnitude as f32;
+        }
+    }
+
     /// Compute positions for ALL stars in the catalog (regardless of magnitude).
     /// This is used for constellation line drawing. Called once at initialization
     /// since star positions are fixed in J2000 coordinates.
     fn compute_all_stars(&mut self) {
-        for (i, star) in self.catalog.stars().iter().enumerate() {
-            let dir = star.direction();
-            let (x, y, z) = dir.to_f32();
-
+        for (i, (star, &(x, y, z))) in self
+            .catalog
+            .stars()
+            .iter()
+            .zip(self.catalog.directions())
+            .enumerate()
+        {
             let pos_idx = i * 3;
             self.all_stars_pos[pos_idx] = x;
             self.all_stars_pos[pos_idx + 1] = y;
@@ -322,6 +482,18 @@ impl SkyEngine {
         self.visible_count * 4
     }
 
+    /// Get pointer to stars altitude/azimuth buffer (radians).
+    /// 2 floats per visible star: altitude, azimuth.
+    pub fn stars_altaz_ptr(&self) -> *const f32 {
+        self.stars_altaz.as_ptr()
+    }
+
+    /// Get length of stars altitude/azimuth buffer (in f32 elements).
+    /// Note: actual visible stars is visible_stars() * 2.
+    pub fn stars_altaz_len(&self) -> usize {
+        self.visible_count * 2
+    }
+
     /// Get pointer to celestial bodies position buffer.
     pub fn bodies_pos_ptr(&self) -> *const f32 {
         self.bodies_pos.as_ptr()
@@ -350,11 +522,121 @@ impl SkyEngine {
         self.bodies_angular_diameters.get(index).copied().unwrap_or(0.0)
     }
 
+    /// Get pointer to celestial bodies apparent magnitude buffer.
+    pub fn bodies_magnitude_ptr(&self) -> *const f32 {
+        self.bodies_magnitude.as_ptr()
+    }
+
+    /// Get length of celestial bodies apparent magnitude buffer.
+    /// Always 9 (one apparent visual magnitude per body).
+    pub fn bodies_magnitude_len(&self) -> usize {
+        self.bodies_magnitude.len()
+    }
+
+    /// Get apparent visual magnitude for a specific body by index (0-8).
+    pub fn body_magnitude(&self, index: usize) -> f32 {
+        self.bodies_magnitude.get(index).copied().unwrap_or(99.0)
+    }
+
+    /// Get pointer to celestial bodies phase angle buffer (radians).
+    pub fn bodies_phase_angle_ptr(&self) -> *const f32 {
+        self.bodies_phase_angle.as_ptr()
+    }
+
+    /// Get length of celestial bodies phase angle buffer.
+    /// Always 9 (one Sun-body-Earth phase angle per body, in radians).
+    pub fn bodies_phase_angle_len(&self) -> usize {
+        self.bodies_phase_angle.len()
+    }
+
+    /// Get Sun-body-Earth phase angle for a specific body by index (0-8), in
+    /// radians. Most meaningful for the Moon and the inner planets (Mercury,
+    /// Venus), which show visible phases from Earth; always 0 for the Sun.
+    pub fn body_phase_angle(&self, index: usize) -> f32 {
+        self.bodies_phase_angle.get(index).copied().unwrap_or(0.0)
+    }
+
+    /// Get pointer to celestial bodies illuminated fraction buffer.
+    pub fn bodies_illuminated_fraction_ptr(&self) -> *const f32 {
+        self.bodies_illuminated_fraction.as_ptr()
+    }
+
+    /// Get length of celestial bodies illuminated fraction buffer.
+    /// Always 9 (one illuminated disk fraction per body, k = (1 + cos i) / 2).
+    pub fn bodies_illuminated_fraction_len(&self) -> usize {
+        self.bodies_illuminated_fraction.len()
+    }
+
+    /// Get illuminated disk fraction for a specific body by index (0-8),
+    /// where 0.0 is fully dark and 1.0 is fully lit. Most meaningful for the
+    /// Moon and the inner planets (Mercury, Venus); always 1.0 for the Sun.
+    pub fn body_illuminated_fraction(&self, index: usize) -> f32 {
+        self.bodies_illuminated_fraction.get(index).copied().unwrap_or(1.0)
+    }
+
+    /// Get pointer to celestial bodies altitude/azimuth buffer (radians).
+    /// Always 18 (9 bodies * 2 floats: altitude, azimuth).
+    pub fn bodies_altaz_ptr(&self) -> *const f32 {
+        self.bodies_altaz.as_ptr()
+    }
+
+    /// Get length of celestial bodies altitude/azimuth buffer.
+    /// Always 18 (9 bodies * 2 floats).
+    pub fn bodies_altaz_len(&self) -> usize {
+        self.bodies_altaz.len()
+    }
+
     /// Get celestial body name by index (0-8).
     pub fn body_name(&self, index: usize) -> Option<String> {
         CelestialBody::ALL.get(index).map(|b| b.name().to_string())
     }
 
+    /// Compute the next rise, transit, and set times for an object at a
+    /// fixed equatorial position (e.g. a star), given the current observer
+    /// location and time.
+    ///
+    /// Returns `[rise_jd, transit_jd, set_jd]` as Julian Dates (UTC). If the
+    /// object is circumpolar or never rises at this latitude, the
+    /// corresponding rise/set entry is `NaN` (transit is always returned).
+    pub fn rise_set_transit(&self, ra_deg: f64, dec_deg: f64) -> Vec<f64> {
+        let direction = ra_dec_to_cartesian(ra_deg * PI / 180.0, dec_deg * PI / 180.0);
+        let observer = self.observer();
+        let result = rise_set_transit(&direction, &self.time, &observer, TwilightMode::StarsPlanets);
+
+        vec![
+            result.rise_jd.unwrap_or(f64::NAN),
+            result.transit_jd,
+            result.set_jd.unwrap_or(f64::NAN),
+        ]
+    }
+
+    /// Search `[start_jd, end_jd]` (Julian Dates, UTC) for conjunctions,
+    /// close approaches, and occultations among the nine tracked bodies
+    /// (Sun, Moon, Mercury..Neptune), flagging any whose minimum angular
+    /// separation falls at or below `max_sep_rad`. Computed against
+    /// temporary `SkyTime`s, so this doesn't disturb the engine's current
+    /// time or require a `recompute()` afterward.
+    ///
+    /// Returns a flat buffer, 5 floats per event: `[body_a, body_b,
+    /// time_jd, min_separation_rad, is_occultation (0.0 or 1.0), ...]`,
+    /// sorted by `time_jd`. `body_a`/`body_b` are indices into the same
+    /// 9-body order as `bodies_pos`/`bodies_angular_diameters` (0 = Sun, 1 =
+    /// Moon, 2 = Mercury, ... 8 = Neptune).
+    pub fn find_close_approaches(&self, start_jd: f64, end_jd: f64, max_sep_rad: f64) -> Vec<f64> {
+        sky_engine_core::conjunctions::find_close_approaches(start_jd, end_jd, max_sep_rad)
+            .into_iter()
+            .flat_map(|event| {
+                [
+                    event.body_a as f64,
+                    event.body_b as f64,
+                    event.time_jd,
+                    event.min_separation_rad,
+                    if event.is_occultation { 1.0 } else { 0.0 },
+                ]
+            })
+            .collect()
+    }
+
     /// Get Moon's angular diameter in radians.
     pub fn moon_angular_diameter(&self) -> f32 {
         self.bodies_angular_diameters.get(1).copied().unwrap_or(
@@ -362,19 +644,41 @@ impl SkyEngine {
         )
     }
 
+    /// Get the Moon's Sun-Moon-Earth phase angle in radians, for orienting a
+    /// crescent/gibbous sprite's terminator.
+    pub fn moon_phase_angle(&self) -> f32 {
+        self.bodies_phase_angle.get(1).copied().unwrap_or(
+            compute_moon_position_full(&self.time).phase_angle_rad as f32
+        )
+    }
+
+    /// Get the Moon's illuminated disk fraction, k = (1 + cos i) / 2 (0.0 at
+    /// New Moon, 1.0 at Full Moon).
+    pub fn moon_illuminated_fraction(&self) -> f32 {
+        self.bodies_illuminated_fraction.get(1).copied().unwrap_or(
+            compute_moon_position_full(&self.time).illuminated_fraction as f32
+        )
+    }
+
+    /// Get the named lunar phase (e.g. "Waxing Crescent", "Full Moon") for
+    /// the current time, bucketed from the fraction of the way through the
+    /// current synodic month.
+    pub fn moon_phase_name(&self) -> String {
+        compute_moon_position_full(&self.time).phase_name.name().to_string()
+    }
+
     // --- Planetary moons buffer accessors ---
 
     /// Get pointer to planetary moons position buffer.
-    /// 18 moons * 4 floats (x, y, z, angular_diameter) = 72 floats.
+    /// N moons * 5 floats (x, y, z, angular_diameter, magnitude).
     /// Order: Jupiter (Io, Europa, Ganymede, Callisto), Saturn (Mimas, Enceladus, Tethys,
-    /// Dione, Rhea, Titan), Uranus (Miranda, Ariel, Umbriel, Titania, Oberon),
-    /// Neptune (Triton), Mars (Phobos, Deimos)
+    /// Dione, Rhea, Titan).
     pub fn planetary_moons_pos_ptr(&self) -> *const f32 {
         self.planetary_moons_pos.as_ptr()
     }
 
     /// Get length of planetary moons position buffer.
-    /// 18 moons * 4 floats = 72 floats.
+    /// N moons * 5 floats.
     pub fn planetary_moons_pos_len(&self) -> usize {
         self.planetary_moons_pos.len()
     }
@@ -397,7 +701,7 @@ impl SkyEngine {
     // --- Minor bodies buffer accessors (dwarf planets, asteroids, etc.) ---
 
     /// Get pointer to minor bodies position buffer.
-    /// N bodies * 4 floats (x, y, z, angular_diameter).
+    /// N bodies * 5 floats (x, y, z, angular_diameter, magnitude).
     /// Currently: Pluto (index 0)
     pub fn minor_bodies_pos_ptr(&self) -> *const f32 {
         self.minor_bodies_pos.as_ptr()
@@ -419,6 +723,13 @@ impl SkyEngine {
         MinorBody::ALL.get(index).map(|b| b.name().to_string())
     }
 
+    /// Get minor body apparent visual magnitude by index.
+    /// Returns the IAU H-G system estimate (lower = brighter).
+    pub fn minor_body_magnitude(&self, index: usize) -> f32 {
+        let idx = index * 5 + 4;
+        self.minor_bodies_pos.get(idx).copied().unwrap_or(99.0)
+    }
+
     // --- Comets buffer accessors ---
 
     /// Get pointer to comets position buffer.
@@ -451,6 +762,53 @@ impl SkyEngine {
         self.comets_pos.get(idx).copied().unwrap_or(99.0)
     }
 
+    // --- Custom (runtime-ingested) comets buffer accessors ---
+
+    /// Get pointer to the runtime-ingested comets position buffer.
+    /// N custom comets * 4 floats (x, y, z, magnitude).
+    pub fn custom_comets_pos_ptr(&self) -> *const f32 {
+        self.custom_comets_pos.as_ptr()
+    }
+
+    /// Get length of the runtime-ingested comets position buffer.
+    pub fn custom_comets_pos_len(&self) -> usize {
+        self.custom_comets_pos.len()
+    }
+
+    /// Get the number of comets added at runtime via `add_comet_elements`.
+    pub fn custom_comets_count(&self) -> usize {
+        self.custom_comets.len()
+    }
+
+    /// Get the name of a runtime-ingested comet by index.
+    pub fn custom_comet_name(&self, index: usize) -> Option<String> {
+        self.custom_comets.get(index).map(|e| e.name.to_string())
+    }
+
+    // --- Custom (runtime-ingested) minor bodies buffer accessors ---
+
+    /// Get pointer to the runtime-ingested minor bodies position buffer.
+    /// N custom minor bodies * 5 floats (x, y, z, angular_diameter, magnitude).
+    pub fn custom_minor_bodies_pos_ptr(&self) -> *const f32 {
+        self.custom_minor_bodies_pos.as_ptr()
+    }
+
+    /// Get length of the runtime-ingested minor bodies position buffer.
+    pub fn custom_minor_bodies_pos_len(&self) -> usize {
+        self.custom_minor_bodies_pos.len()
+    }
+
+    /// Get the number of minor bodies added at runtime via
+    /// `add_minor_body_elements`.
+    pub fn custom_minor_bodies_count(&self) -> usize {
+        self.custom_minor_bodies.len()
+    }
+
+    /// Get the name of a runtime-ingested minor body by index.
+    pub fn custom_minor_body_name(&self, index: usize) -> Option<String> {
+        self.custom_minor_bodies.get(index).map(|e| e.name.to_string())
+    }
+
     // --- All stars buffer accessors (for constellation drawing, not magnitude-filtered) ---
 
     /// Get pointer to all stars position buffer (for constellation line drawing).
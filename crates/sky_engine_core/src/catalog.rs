@@ -1,8 +1,31 @@
-use crate::coords::CartesianCoord;
-use std::collections::HashSet;
+use crate::coords::{ra_dec_to_cartesian, CartesianCoord};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::f64::consts::PI;
+
+#[cfg(feature = "simd")]
+use wide::f64x4;
+
+/// A cross-reference catalog a star's designation can be drawn from, beyond
+/// its primary HR number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Catalog {
+    /// Harvard Revised (Bright Star Catalogue) number -- the primary `id`.
+    Hr,
+    /// Henry Draper Catalogue number.
+    Hd,
+    /// Hipparcos Catalogue number.
+    Hip,
+    /// Tycho Catalogue identifier.
+    Tycho,
+    /// Smithsonian Astrophysical Observatory Star Catalog number.
+    Sao,
+    /// Gliese (-Jahreiss) Catalogue of Nearby Stars number.
+    Gliese,
+}
 
 /// A star from the catalog with position and photometric data.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct Star {
     /// Right Ascension in radians (J2000)
     pub ra: f64,
@@ -12,31 +35,470 @@ pub struct Star {
     pub vmag: f32,
     /// B-V color index
     pub bv_color: f32,
-    /// Catalog ID (e.g., HR number for BSC)
-    pub id: u32,
+    /// Primary catalog ID (HR number for BSC, source_id for Gaia --
+    /// wide enough for any catalog's native numbering, including Gaia
+    /// DR3's u64-scale `source_id`).
+    pub id: u64,
+    /// Additional designations for the same star in other catalogs (HD,
+    /// HIP, Tycho, SAO, Gliese, ...), so external data keyed on any of them
+    /// can be joined without rewriting IDs. Empty unless populated by the
+    /// loader or caller.
+    pub cross_ids: Vec<(Catalog, u64)>,
+    /// Parallax in milliarcseconds, as recorded by catalogs like Hipparcos
+    /// and Gliese. `0.0` (or negative) means unknown -- `distance_ly` and
+    /// `position_ly` return `None` in that case.
+    pub parallax_mas: f32,
+    /// Proper motion in right ascension, milliarcseconds/year, already
+    /// scaled by `cos(dec)` as is catalog convention (so it's a true
+    /// angular rate on the sky, not a rate of the `ra` coordinate itself).
+    pub pm_ra_masyr: f32,
+    /// Proper motion in declination, milliarcseconds/year.
+    pub pm_dec_masyr: f32,
+    /// Heliocentric radial velocity in kilometers/second, positive
+    /// receding. `0.0` means unknown (the common case -- most catalogs
+    /// that carry proper motion and parallax, like Hipparcos, don't carry
+    /// radial velocity; it comes from a separate companion catalog when
+    /// available at all). A missing radial velocity only biases
+    /// `apply_pm`'s space-motion propagation over very long epoch spans,
+    /// since it's a second-order ("perspective acceleration") effect next
+    /// to proper motion.
+    pub rv_kms: f32,
+}
+
+/// Radians per milliarcsecond, for converting catalog proper-motion rates.
+const RAD_PER_MAS: f64 = PI / (180.0 * 3600.0 * 1000.0);
+
+/// How `StarCatalog::merge` should reconcile an incoming record with an
+/// existing star of the same id, mirroring Celestia's `.stc` loader
+/// disposition keywords.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Disposition {
+    /// Skip any incoming record whose id already exists (the original
+    /// `extend` behavior).
+    Add,
+    /// Update only the non-sentinel fields of an existing star; error if
+    /// the id is missing.
+    Modify,
+    /// Overwrite the whole record, or insert it if the id is absent.
+    Replace,
+}
+
+/// Outcome counts from `StarCatalog::merge`, so a caller can verify a patch
+/// applied as intended.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MergeStats {
+    pub added: usize,
+    pub modified: usize,
+    pub replaced: usize,
+    pub skipped: usize,
 }
 
+/// Light-years per parsec, for parallax-to-distance conversion: a star
+/// at 1000/parallax_mas parsecs is that many parsecs times this factor away
+/// in light-years.
+const LY_PER_PARSEC: f64 = 3.2616;
+
 impl Star {
     /// Get the direction to this star as a unit vector.
     pub fn direction(&self) -> CartesianCoord {
         CartesianCoord::from_ra_dec_rad(self.ra, self.dec)
     }
+
+    /// Distance to this star in light-years, from its parallax:
+    /// `distance_pc = 1000 / parallax_mas`, converted to light-years.
+    /// `None` when the parallax is zero or negative (unknown, or an
+    /// unreliable negative-parallax measurement).
+    pub fn distance_ly(&self) -> Option<f64> {
+        if self.parallax_mas <= 0.0 {
+            return None;
+        }
+        let distance_pc = 1000.0 / self.parallax_mas as f64;
+        Some(distance_pc * LY_PER_PARSEC)
+    }
+
+    /// This star's heliocentric Cartesian position in light-years --
+    /// `direction() * distance_ly()`. `None` wherever `distance_ly` is,
+    /// i.e. when the parallax is unknown.
+    pub fn position_ly(&self) -> Option<CartesianCoord> {
+        let distance_ly = self.distance_ly()?;
+        let dir = self.direction();
+        Some(CartesianCoord::new(dir.x * distance_ly, dir.y * distance_ly, dir.z * distance_ly))
+    }
+
+    /// Effective (blackbody) temperature in Kelvin, from `bv_color` via the
+    /// Ballesteros formula.
+    pub fn color_temperature_k(&self) -> f64 {
+        let bv = self.bv_color as f64;
+        4600.0 * (1.0 / (0.92 * bv + 1.7) + 1.0 / (0.92 * bv + 0.62))
+    }
+
+    /// Approximate displayable sRGB color for this star's blackbody
+    /// temperature (`color_temperature_k`), via the standard piecewise
+    /// polynomial/log fit over `T / 100`, clamped to `[0, 255]` per channel.
+    pub fn srgb_color(&self) -> [u8; 3] {
+        let t100 = self.color_temperature_k() / 100.0;
+
+        let red = if t100 <= 66.0 { 255.0 } else { 329.7 * (t100 - 60.0).powf(-0.1332) };
+
+        let green = if t100 <= 66.0 {
+            99.47 * t100.ln() - 161.1
+        } else {
+            288.1 * (t100 - 60.0).powf(-0.0755)
+        };
+
+        let blue = if t100 <= 19.0 {
+            0.0
+        } else if t100 >= 66.0 {
+            255.0
+        } else {
+            138.5 * (t100 - 10.0).ln() - 305.0
+        };
+
+        [clamp_to_u8(red), clamp_to_u8(green), clamp_to_u8(blue)]
+    }
+
+    /// This star's position advanced by its proper motion to
+    /// `years_since_j2000` years after J2000 (negative for earlier epochs).
+    /// `pm_ra_masyr` is already scaled by `cos(dec)`, so it's unscaled back
+    /// out before being added to `ra`; `dec` just adds `pm_dec_masyr`
+    /// directly. Near the poles `cos(dec)` degenerates, so `ra`'s drift is
+    /// dropped rather than blown up there; the final position is clamped to
+    /// `dec` in `[-pi/2, pi/2]` and wrapped to `ra` in `[0, 2*pi)`.
+    pub fn at_epoch(&self, years_since_j2000: f64) -> Star {
+        let cos_dec = self.dec.cos();
+        let dra = if cos_dec.abs() > 1e-6 {
+            (self.pm_ra_masyr as f64 * RAD_PER_MAS / cos_dec) * years_since_j2000
+        } else {
+            0.0
+        };
+        let ddec = self.pm_dec_masyr as f64 * RAD_PER_MAS * years_since_j2000;
+
+        let mut star = self.clone();
+        star.ra = (self.ra + dra).rem_euclid(2.0 * PI);
+        star.dec = (self.dec + ddec).clamp(-PI / 2.0, PI / 2.0);
+        star
+    }
+}
+
+fn clamp_to_u8(v: f64) -> u8 {
+    v.clamp(0.0, 255.0).round() as u8
+}
+
+/// Radians per milliarcsecond/year, for `apply_pm`'s proper-motion terms.
+const MAS_TO_RAD: f64 = PI / (3_600_000.0 * 180.0);
+
+/// Astronomical units per parsec: `distance_au = AU_PER_PARSEC /
+/// parallax_arcsec`.
+const AU_PER_PARSEC: f64 = 206_264.8;
+
+/// Kilometers/second per astronomical-unit/year -- the ~4.74 km/s "radial
+/// velocity constant", derived from the length of an AU and a Julian year.
+const KM_S_PER_AU_YR: f64 = 149_597_870.7 / (365.25 * 86_400.0);
+
+/// Advance a catalog position from `epoch1_year` to `epoch2_year` by
+/// rigorous 3D space-motion propagation, rather than `Star::at_epoch`'s
+/// flat-sky linear approximation: builds a true 3D velocity vector from
+/// proper motion, parallax and radial velocity, advances the Cartesian
+/// position linearly, and reprojects back to RA/Dec. Accurate over epoch
+/// spans and near the pole where the small-angle approximation breaks down.
+///
+/// `ra_rad`/`dec_rad` are the position at `epoch1_year`; `pm_ra_masyr` is
+/// the proper motion in right ascension already scaled by `cos(dec)` (the
+/// catalog convention also used by `Star::pm_ra_masyr`), `pm_dec_masyr` the
+/// proper motion in declination, both milliarcseconds/year; `rv_kms` the
+/// heliocentric radial velocity in km/s; `parallax_mas` the parallax in
+/// milliarcseconds. Returns `(ra_rad, dec_rad)` at `epoch2_year`, with `ra`
+/// wrapped to `[0, 2*pi)`.
+///
+/// When `parallax_mas` is non-positive (unknown, or an unreliable
+/// negative measurement), the star is treated as effectively at infinity:
+/// radial velocity can't shift the direction to a point at infinite
+/// distance, and the distance factor common to the position and
+/// tangential-velocity terms cancels out of the resulting direction
+/// regardless of its value, so only the angular (proper) motion is applied.
+pub fn apply_pm(
+    ra_rad: f64,
+    dec_rad: f64,
+    pm_ra_masyr: f64,
+    pm_dec_masyr: f64,
+    rv_kms: f64,
+    parallax_mas: f64,
+    epoch1_year: f64,
+    epoch2_year: f64,
+) -> (f64, f64) {
+    let (sin_ra, cos_ra) = ra_rad.sin_cos();
+    let (sin_dec, cos_dec) = dec_rad.sin_cos();
+
+    let r = (cos_dec * cos_ra, cos_dec * sin_ra, sin_dec);
+    let p = (-sin_ra, cos_ra, 0.0);
+    let q = (-sin_dec * cos_ra, -sin_dec * sin_ra, cos_dec);
+
+    let pm_ra_rad_yr = pm_ra_masyr * MAS_TO_RAD;
+    let pm_dec_rad_yr = pm_dec_masyr * MAS_TO_RAD;
+    let tangential = (
+        pm_ra_rad_yr * p.0 + pm_dec_rad_yr * q.0,
+        pm_ra_rad_yr * p.1 + pm_dec_rad_yr * q.1,
+        pm_ra_rad_yr * p.2 + pm_dec_rad_yr * q.2,
+    );
+
+    let (pos, vel) = if parallax_mas > 0.0 {
+        let parallax_arcsec = parallax_mas / 1000.0;
+        let dist_au = AU_PER_PARSEC / parallax_arcsec;
+        let rv_au_yr = rv_kms / KM_S_PER_AU_YR;
+
+        let pos = (dist_au * r.0, dist_au * r.1, dist_au * r.2);
+        let vel = (
+            dist_au * tangential.0 + rv_au_yr * r.0,
+            dist_au * tangential.1 + rv_au_yr * r.1,
+            dist_au * tangential.2 + rv_au_yr * r.2,
+        );
+        (pos, vel)
+    } else {
+        (r, tangential)
+    };
+
+    let dt = epoch2_year - epoch1_year;
+    let pos2 = (pos.0 + vel.0 * dt, pos.1 + vel.1 * dt, pos.2 + vel.2 * dt);
+
+    let new_ra = pos2.1.atan2(pos2.0).rem_euclid(2.0 * PI);
+    let new_dec = pos2.2.atan2(pos2.0.hypot(pos2.1));
+
+    (new_ra, new_dec)
+}
+
+/// Transform one star's RA/Dec (radians) into an `f32` unit-direction triple.
+/// Scalar reference implementation; kept in lockstep with the batched path
+/// below so the two never disagree.
+fn scalar_direction(ra: f64, dec: f64) -> (f32, f32, f32) {
+    let (sin_ra, cos_ra) = ra.sin_cos();
+    let (sin_dec, cos_dec) = dec.sin_cos();
+    ((cos_dec * cos_ra) as f32, (cos_dec * sin_ra) as f32, sin_dec as f32)
+}
+
+/// Transform a whole catalog's RA/Dec arrays into unit-direction triples in
+/// one pass, for catalogs with hundreds of thousands of stars where
+/// per-star `Star::direction()` calls dominate load/recompute time.
+///
+/// `sin`/`cos` have no portable SIMD form in `std`, so both paths evaluate
+/// them scalarly; what's vectorized is the array-of-structs-to-struct-of-
+/// arrays combine step (`cos_dec * cos_ra`, `cos_dec * sin_ra`) that turns
+/// those per-star trig results into cartesian coordinates, four stars at a
+/// time behind the `simd` feature. Without that feature (including wasm
+/// targets, where 256-bit float SIMD isn't reliably available), falls back
+/// to the plain scalar loop. Both paths produce identical results.
+fn compute_directions_batch(stars: &[Star]) -> Vec<(f32, f32, f32)> {
+    let mut out = vec![(0.0f32, 0.0f32, 0.0f32); stars.len()];
+
+    #[cfg(feature = "simd")]
+    {
+        let n = stars.len();
+        let mut i = 0;
+        while i + 4 <= n {
+            let mut sin_ra = [0.0f64; 4];
+            let mut cos_ra = [0.0f64; 4];
+            let mut sin_dec = [0.0f64; 4];
+            let mut cos_dec = [0.0f64; 4];
+            for lane in 0..4 {
+                let (sr, cr) = stars[i + lane].ra.sin_cos();
+                let (sd, cd) = stars[i + lane].dec.sin_cos();
+                sin_ra[lane] = sr;
+                cos_ra[lane] = cr;
+                sin_dec[lane] = sd;
+                cos_dec[lane] = cd;
+            }
+
+            let cos_ra_v = f64x4::from(cos_ra);
+            let sin_ra_v = f64x4::from(sin_ra);
+            let cos_dec_v = f64x4::from(cos_dec);
+
+            let x = (cos_dec_v * cos_ra_v).to_array();
+            let y = (cos_dec_v * sin_ra_v).to_array();
+
+            for lane in 0..4 {
+                out[i + lane] = (x[lane] as f32, y[lane] as f32, sin_dec[lane] as f32);
+            }
+            i += 4;
+        }
+        for (j, star) in stars.iter().enumerate().skip(i) {
+            out[j] = scalar_direction(star.ra, star.dec);
+        }
+    }
+
+    #[cfg(not(feature = "simd"))]
+    {
+        for (j, star) in stars.iter().enumerate() {
+            out[j] = scalar_direction(star.ra, star.dec);
+        }
+    }
+
+    out
+}
+
+// --- On-sphere spatial index for cone queries ------------------------------
+//
+// `stars_brighter_than` and friends are a linear scan, fine for a few dozen
+// bright stars but not for a full Hipparcos/Tycho load. `stars_near` below
+// answers "what's within this field of view" by building a kd-tree over the
+// stars' unit direction vectors (in the cube [-1,1]^3, following Celestia's
+// octree approach to the same problem) and pruning whole subtrees whose
+// bounding box can't contain a direction within the query cone.
+
+/// Number of points a kd-tree leaf holds before it's worth splitting further.
+const KD_LEAF_SIZE: usize = 16;
+
+/// Axis-aligned bounding box (in the unit-direction cube) of the points a kd-tree node covers.
+#[derive(Debug, Clone, Copy)]
+struct BoundingBox {
+    min: [f32; 3],
+    max: [f32; 3],
+}
+
+impl BoundingBox {
+    fn from_points(points: &[(usize, [f32; 3])]) -> Self {
+        let mut bbox = Self { min: [0.0; 3], max: [0.0; 3] };
+        if let Some((_, first)) = points.first() {
+            bbox.min = *first;
+            bbox.max = *first;
+            for (_, p) in &points[1..] {
+                for axis in 0..3 {
+                    bbox.min[axis] = bbox.min[axis].min(p[axis]);
+                    bbox.max[axis] = bbox.max[axis].max(p[axis]);
+                }
+            }
+        }
+        bbox
+    }
+
+    /// The largest dot product any point inside this box could achieve with
+    /// `dir`: per axis, the box corner with the same sign as `dir`'s
+    /// component on that axis. If this upper bound is below the query's
+    /// `cos(theta)`, no point in the box -- or its subtree -- can match.
+    fn max_dot(&self, dir: [f32; 3]) -> f32 {
+        (0..3)
+            .map(|axis| {
+                let corner = if dir[axis] >= 0.0 { self.max[axis] } else { self.min[axis] };
+                corner * dir[axis]
+            })
+            .sum()
+    }
+}
+
+/// A node in the cone-query kd-tree: either a leaf bucket of star indices or
+/// an internal split along one of the three axes.
+enum KdNode {
+    Leaf { indices: Vec<usize>, bounds: BoundingBox },
+    Split { bounds: BoundingBox, left: Box<KdNode>, right: Box<KdNode> },
+}
+
+impl KdNode {
+    fn bounds(&self) -> &BoundingBox {
+        match self {
+            KdNode::Leaf { bounds, .. } => bounds,
+            KdNode::Split { bounds, .. } => bounds,
+        }
+    }
+}
+
+/// Build a balanced kd-tree over `points` (star index paired with its unit
+/// direction), splitting at the median along whichever axis has the
+/// greatest spread at each level.
+fn build_kd_node(mut points: Vec<(usize, [f32; 3])>) -> KdNode {
+    let bounds = BoundingBox::from_points(&points);
+
+    if points.len() <= KD_LEAF_SIZE {
+        return KdNode::Leaf { indices: points.into_iter().map(|(i, _)| i).collect(), bounds };
+    }
+
+    let spread: [f32; 3] = std::array::from_fn(|axis| bounds.max[axis] - bounds.min[axis]);
+    let axis = if spread[0] >= spread[1] && spread[0] >= spread[2] {
+        0
+    } else if spread[1] >= spread[2] {
+        1
+    } else {
+        2
+    };
+
+    points.sort_by(|a, b| a.1[axis].partial_cmp(&b.1[axis]).unwrap());
+    let right_points = points.split_off(points.len() / 2);
+
+    KdNode::Split {
+        bounds,
+        left: Box::new(build_kd_node(points)),
+        right: Box::new(build_kd_node(right_points)),
+    }
+}
+
+/// Collect the indices of every point within `cos_theta` of `dir`, pruning
+/// any subtree whose bounding box can't reach `cos_theta`. Leaf members
+/// still get an exact dot-product check, since a box clearing the bound
+/// doesn't mean every point inside it does.
+fn query_kd_node(node: &KdNode, directions: &[(f32, f32, f32)], dir: [f32; 3], cos_theta: f32, out: &mut Vec<usize>) {
+    if node.bounds().max_dot(dir) < cos_theta {
+        return;
+    }
+
+    match node {
+        KdNode::Leaf { indices, .. } => {
+            for &i in indices {
+                let (x, y, z) = directions[i];
+                let dot = x * dir[0] + y * dir[1] + z * dir[2];
+                if dot >= cos_theta {
+                    out.push(i);
+                }
+            }
+        }
+        KdNode::Split { left, right, .. } => {
+            query_kd_node(left, directions, dir, cos_theta, out);
+            query_kd_node(right, directions, dir, cos_theta, out);
+        }
+    }
 }
 
 /// Star catalog holding all loaded stars.
 pub struct StarCatalog {
     stars: Vec<Star>,
+    /// Unit direction for each star in `stars`, same order, precomputed at
+    /// load time since J2000 star directions never change. Lets a magnitude-
+    /// filtered recompute each frame just copy from here instead of re-doing
+    /// the RA/Dec -> cartesian trig on every call.
+    directions: Vec<(f32, f32, f32)>,
+    /// Cone-query kd-tree over `directions`, built lazily on first
+    /// `stars_near` call and invalidated (cleared) whenever the catalog
+    /// grows via `extend`.
+    cone_index: RefCell<Option<KdNode>>,
+    /// Per-catalog id -> `stars` index, covering each star's primary `id`
+    /// (under `Catalog::Hr`) plus its `cross_ids`. Built eagerly at load
+    /// time and kept in sync by `extend`, so `find` is a couple of hash
+    /// lookups rather than a linear scan.
+    catalog_index: HashMap<Catalog, HashMap<u64, usize>>,
+}
+
+/// Build the `catalog_index` lookup for a freshly loaded set of stars.
+fn build_catalog_index(stars: &[Star]) -> HashMap<Catalog, HashMap<u64, usize>> {
+    let mut index: HashMap<Catalog, HashMap<u64, usize>> = HashMap::new();
+    for (i, star) in stars.iter().enumerate() {
+        index.entry(Catalog::Hr).or_default().insert(star.id, i);
+        for &(catalog, number) in &star.cross_ids {
+            index.entry(catalog).or_default().insert(number, i);
+        }
+    }
+    index
 }
 
 impl StarCatalog {
     /// Create an empty catalog.
     pub fn new() -> Self {
-        Self { stars: Vec::new() }
+        Self {
+            stars: Vec::new(),
+            directions: Vec::new(),
+            cone_index: RefCell::new(None),
+            catalog_index: HashMap::new(),
+        }
     }
 
     /// Load catalog from binary format.
     ///
-    /// Binary format:
+    /// Binary format, version 0 (legacy, no version tag):
     /// - Header: u32 star_count (little-endian)
     /// - Per star (20 bytes):
     ///   - f32 ra_rad
@@ -44,20 +506,46 @@ impl StarCatalog {
     ///   - f32 vmag
     ///   - f32 bv_color
     ///   - u32 id
+    ///
+    /// Version 1 (adds parallax, for catalogs like Hipparcos/Gliese that
+    /// carry true 3D positions):
+    /// - Header: u32 star_count, then a u8 version tag (`1`)
+    /// - Per star (24 bytes): the version-0 fields, plus
+    ///   - f32 parallax_mas
+    ///
+    /// Version 2 (widens `id` to u64, for catalogs like Gaia DR3 whose
+    /// `source_id` overflows a u32):
+    /// - Header: u32 star_count, then a u8 version tag (`2`)
+    /// - Per star (28 bytes):
+    ///   - f32 ra_rad, f32 dec_rad, f32 vmag, f32 bv_color
+    ///   - u64 id
+    ///   - f32 parallax_mas
+    ///
+    /// There's no magic byte distinguishing version 0 from version 1, so
+    /// those two are inferred from which framing makes the remaining bytes
+    /// add up: if a version tag plus that many 24-byte records fits
+    /// exactly, it's version 1; otherwise it's treated as version 0, with
+    /// `parallax_mas` filled in as `0.0` (unknown). Version 2 is checked
+    /// first since it's the only one with an explicit, unambiguous tag.
     pub fn from_binary(data: &[u8]) -> Result<Self, &'static str> {
         if data.len() < 4 {
             return Err("Data too short for header");
         }
 
         let star_count = u32::from_le_bytes([data[0], data[1], data[2], data[3]]) as usize;
-        let expected_len = 4 + star_count * 20;
 
-        if data.len() < expected_len {
-            return Err("Data too short for star count");
-        }
+        let (version, mut offset, record_size) =
+            if data.len() >= 5 && data.len() == 5 + star_count * 28 && data[4] == 2 {
+                (2u8, 5, 28)
+            } else if data.len() >= 5 && data.len() == 5 + star_count * 24 && data[4] == 1 {
+                (1u8, 5, 24)
+            } else if data.len() >= 4 + star_count * 20 {
+                (0u8, 4, 20)
+            } else {
+                return Err("Data too short for star count");
+            };
 
         let mut stars = Vec::with_capacity(star_count);
-        let mut offset = 4;
 
         for _ in 0..star_count {
             let ra = f32::from_le_bytes([
@@ -84,12 +572,37 @@ impl StarCatalog {
                 data[offset + 14],
                 data[offset + 15],
             ]);
-            let id = u32::from_le_bytes([
-                data[offset + 16],
-                data[offset + 17],
-                data[offset + 18],
-                data[offset + 19],
-            ]);
+            let (id, parallax_offset) = if version >= 2 {
+                let id = u64::from_le_bytes([
+                    data[offset + 16],
+                    data[offset + 17],
+                    data[offset + 18],
+                    data[offset + 19],
+                    data[offset + 20],
+                    data[offset + 21],
+                    data[offset + 22],
+                    data[offset + 23],
+                ]);
+                (id, offset + 24)
+            } else {
+                let id = u32::from_le_bytes([
+                    data[offset + 16],
+                    data[offset + 17],
+                    data[offset + 18],
+                    data[offset + 19],
+                ]) as u64;
+                (id, offset + 20)
+            };
+            let parallax_mas = if version >= 1 {
+                f32::from_le_bytes([
+                    data[parallax_offset],
+                    data[parallax_offset + 1],
+                    data[parallax_offset + 2],
+                    data[parallax_offset + 3],
+                ])
+            } else {
+                0.0
+            };
 
             stars.push(Star {
                 ra,
@@ -97,12 +610,19 @@ impl StarCatalog {
                 vmag,
                 bv_color,
                 id,
+                cross_ids: Vec::new(),
+                parallax_mas,
+                pm_ra_masyr: 0.0,
+                pm_dec_masyr: 0.0,
+                rv_kms: 0.0,
             });
 
-            offset += 20;
+            offset += record_size;
         }
 
-        Ok(Self { stars })
+        let directions = compute_directions_batch(&stars);
+        let catalog_index = build_catalog_index(&stars);
+        Ok(Self { stars, directions, cone_index: RefCell::new(None), catalog_index })
     }
 
     /// Create a catalog with embedded bright stars for testing.
@@ -110,7 +630,7 @@ impl StarCatalog {
     pub fn with_bright_stars() -> Self {
         // Format: (name, RA hours, Dec degrees, Vmag, B-V, HR number)
         #[rustfmt::skip]
-        let bright_stars: &[(&str, f64, f64, f32, f32, u32)] = &[
+        let bright_stars: &[(&str, f64, f64, f32, f32, u64)] = &[
             ("Sirius",      6.752,  -16.716, -1.46, 0.00, 2491),
             ("Canopus",     6.399,  -52.696, -0.72, 0.15, 2326),
             ("Arcturus",   14.261,   19.182, -0.05, 1.23, 5340),
@@ -168,17 +688,34 @@ impl StarCatalog {
             .map(|(_, ra_h, dec_deg, vmag, bv, id)| {
                 let ra = ra_h * std::f64::consts::PI / 12.0;
                 let dec = dec_deg * std::f64::consts::PI / 180.0;
+                // Cross-identifiers, parallax, proper motion, and radial
+                // velocity aren't tracked for this embedded list beyond
+                // Sirius, which is kept as a worked example (HIP 32349
+                // parallax: 379.21 mas; proper motion: -546.01, -1223.08
+                // mas/yr; radial velocity: -5.50 km/s).
+                let (cross_ids, parallax_mas, pm_ra_masyr, pm_dec_masyr, rv_kms) = if *id == 2491 {
+                    (vec![(Catalog::Hd, 48915), (Catalog::Hip, 32349)], 379.21, -546.01, -1223.08, -5.50)
+                } else {
+                    (Vec::new(), 0.0, 0.0, 0.0, 0.0)
+                };
                 Star {
                     ra,
                     dec,
                     vmag: *vmag,
                     bv_color: *bv,
                     id: *id,
+                    cross_ids,
+                    parallax_mas,
+                    pm_ra_masyr,
+                    pm_dec_masyr,
+                    rv_kms,
                 }
             })
             .collect();
 
-        Self { stars }
+        let directions = compute_directions_batch(&stars);
+        let catalog_index = build_catalog_index(&stars);
+        Self { stars, directions, cone_index: RefCell::new(None), catalog_index }
     }
 
     /// Get all stars.
@@ -186,11 +723,74 @@ impl StarCatalog {
         &self.stars
     }
 
+    /// Get each star's precomputed unit direction, same order as `stars()`.
+    /// J2000 star directions are fixed, so this is computed once at load
+    /// time rather than recomputed from RA/Dec on every access.
+    pub fn directions(&self) -> &[(f32, f32, f32)] {
+        &self.directions
+    }
+
+    /// Get stars filtered by magnitude limit, paired with their precomputed
+    /// direction -- lets a per-frame recompute skip the RA/Dec -> cartesian
+    /// trig entirely and just copy cached coordinates.
+    pub fn stars_brighter_than_with_direction(
+        &self,
+        mag_limit: f32,
+    ) -> impl Iterator<Item = (&Star, &(f32, f32, f32))> {
+        self.stars
+            .iter()
+            .zip(self.directions.iter())
+            .filter(move |(s, _)| s.vmag <= mag_limit)
+    }
+
     /// Get stars filtered by magnitude limit.
     pub fn stars_brighter_than(&self, mag_limit: f32) -> impl Iterator<Item = &Star> {
         self.stars.iter().filter(move |s| s.vmag <= mag_limit)
     }
 
+    /// Get stars within `radius_rad` of `dir` and at or brighter than
+    /// `mag_limit`, e.g. for a star-tracker's current field of view. Builds
+    /// the cone-query kd-tree on first call and reuses it on subsequent
+    /// ones; see `cone_index`.
+    pub fn stars_near(&self, dir: CartesianCoord, radius_rad: f64, mag_limit: f32) -> impl Iterator<Item = &Star> {
+        let dir = [dir.x as f32, dir.y as f32, dir.z as f32];
+        let cos_theta = radius_rad.cos() as f32;
+
+        if self.cone_index.borrow().is_none() {
+            let points: Vec<(usize, [f32; 3])> =
+                self.directions.iter().enumerate().map(|(i, &(x, y, z))| (i, [x, y, z])).collect();
+            *self.cone_index.borrow_mut() = Some(build_kd_node(points));
+        }
+
+        let mut matches = Vec::new();
+        if let Some(root) = self.cone_index.borrow().as_ref() {
+            query_kd_node(root, &self.directions, dir, cos_theta, &mut matches);
+        }
+
+        matches.into_iter().filter_map(move |i| self.stars.get(i)).filter(move |s| s.vmag <= mag_limit)
+    }
+
+    /// Look up a star by its designation in a given catalog, e.g.
+    /// `find(Catalog::Hd, 48915)` resolves the same star as `find(Catalog::Hr,
+    /// 2491)` (Sirius). Backed by `catalog_index`, so this is a couple of
+    /// hash lookups rather than a scan over `stars()`.
+    pub fn find(&self, catalog: Catalog, number: u64) -> Option<&Star> {
+        let &index = self.catalog_index.get(&catalog)?.get(&number)?;
+        self.stars.get(index)
+    }
+
+    /// A copy of this catalog with every star advanced by its proper
+    /// motion to `years_since_j2000` years after J2000 (see
+    /// `Star::at_epoch`), e.g. for pointing a tracker at the sky as it
+    /// actually looks on the current observing date rather than the fixed
+    /// J2000 reference frame.
+    pub fn propagated(&self, years_since_j2000: f64) -> StarCatalog {
+        let stars: Vec<Star> = self.stars.iter().map(|s| s.at_epoch(years_since_j2000)).collect();
+        let directions = compute_directions_batch(&stars);
+        let catalog_index = build_catalog_index(&stars);
+        StarCatalog { stars, directions, cone_index: RefCell::new(None), catalog_index }
+    }
+
     /// Get star count.
     pub fn len(&self) -> usize {
         self.stars.len()
@@ -204,21 +804,104 @@ impl StarCatalog {
     /// Extend catalog with additional stars from binary data.
     /// Skips stars that already exist (by ID) to avoid duplicates.
     /// Returns the number of new stars added.
+    ///
+    /// A thin wrapper over `merge` with `Disposition::Add`; use `merge`
+    /// directly for the `Modify`/`Replace` dispositions.
     pub fn extend(&mut self, data: &[u8]) -> Result<usize, &'static str> {
-        let additional = Self::from_binary(data)?;
+        self.merge(data, Disposition::Add).map(|stats| stats.added)
+    }
+
+    /// Merge additional stars (binary format, see `from_binary`) into this
+    /// catalog under the given `disposition`, mirroring Celestia's `.stc`
+    /// Add/Modify/Replace loader keywords:
+    ///
+    /// - `Add`: skip any incoming record whose HR id already exists.
+    /// - `Modify`: update an existing star in place, leaving sentinel
+    ///   fields (NaN/zero `vmag`, non-positive `parallax_mas`) untouched so
+    ///   a patch can correct one field without clobbering the rest. Errors
+    ///   if the incoming id isn't already in the catalog.
+    /// - `Replace`: overwrite the whole existing record, or insert a new
+    ///   one if the id is absent.
+    ///
+    /// Returns counts of what happened so a caller can verify the patch
+    /// applied as intended.
+    pub fn merge(&mut self, data: &[u8], disposition: Disposition) -> Result<MergeStats, &'static str> {
+        let incoming = Self::from_binary(data)?;
+        let mut stats = MergeStats::default();
 
-        // Build set of existing IDs for deduplication
-        let existing_ids: HashSet<u32> = self.stars.iter().map(|s| s.id).collect();
+        for (star, direction) in incoming.stars.into_iter().zip(incoming.directions) {
+            let existing_index = self
+                .catalog_index
+                .get(&Catalog::Hr)
+                .and_then(|ids| ids.get(&star.id))
+                .copied();
 
-        let mut added = 0;
-        for star in additional.stars {
-            if !existing_ids.contains(&star.id) {
-                self.stars.push(star);
-                added += 1;
+            match (disposition, existing_index) {
+                (Disposition::Add, Some(_)) => {
+                    stats.skipped += 1;
+                }
+                (Disposition::Add, None) | (Disposition::Replace, None) => {
+                    let index = self.stars.len();
+                    self.insert_into_catalog_index(&star, index);
+                    self.stars.push(star);
+                    self.directions.push(direction);
+                    stats.added += 1;
+                }
+                (Disposition::Modify, None) => {
+                    return Err("Modify disposition requires an existing star ID");
+                }
+                (Disposition::Modify, Some(index)) => {
+                    let existing = &mut self.stars[index];
+                    existing.ra = star.ra;
+                    existing.dec = star.dec;
+                    if !star.vmag.is_nan() && star.vmag != 0.0 {
+                        existing.vmag = star.vmag;
+                    }
+                    if !star.bv_color.is_nan() {
+                        existing.bv_color = star.bv_color;
+                    }
+                    if star.parallax_mas > 0.0 {
+                        existing.parallax_mas = star.parallax_mas;
+                    }
+                    self.directions[index] = direction;
+                    stats.modified += 1;
+                }
+                (Disposition::Replace, Some(index)) => {
+                    let old = self.stars[index].clone();
+                    self.remove_from_catalog_index(&old);
+                    self.insert_into_catalog_index(&star, index);
+                    self.stars[index] = star;
+                    self.directions[index] = direction;
+                    stats.replaced += 1;
+                }
             }
         }
 
-        Ok(added)
+        if stats.added > 0 || stats.modified > 0 || stats.replaced > 0 {
+            // Positions may have shifted or grown, so the cached kd-tree no
+            // longer matches; `stars_near` will rebuild it lazily.
+            *self.cone_index.borrow_mut() = None;
+        }
+
+        Ok(stats)
+    }
+
+    fn insert_into_catalog_index(&mut self, star: &Star, index: usize) {
+        self.catalog_index.entry(Catalog::Hr).or_default().insert(star.id, index);
+        for &(catalog, number) in &star.cross_ids {
+            self.catalog_index.entry(catalog).or_default().insert(number, index);
+        }
+    }
+
+    fn remove_from_catalog_index(&mut self, star: &Star) {
+        if let Some(ids) = self.catalog_index.get_mut(&Catalog::Hr) {
+            ids.remove(&star.id);
+        }
+        for &(catalog, number) in &star.cross_ids {
+            if let Some(ids) = self.catalog_index.get_mut(&catalog) {
+                ids.remove(&number);
+            }
+        }
     }
 }
 
@@ -228,48 +911,1628 @@ impl Default for StarCatalog {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+// --- Compact indexed binary format -----------------------------------------
+//
+// `StarCatalog::from_binary` above is simple but loads every record into
+// memory and has no spatial locality: a lookup by sky region still means
+// scanning the whole file. The format in this section is built for large
+// catalogs (hundreds of thousands of stars): records are packed as scaled
+// integers into a fixed-width data file, sorted by sky cell, with a separate
+// index file mapping each populated cell to a contiguous record range. A
+// reader can hold the data file as a plain byte slice (e.g. one obtained
+// from a memory-mapped file via an external mmap crate) and only touch the
+// byte ranges its query actually needs.
+//
+// The spatial index divides the sky into declination bands, each split into
+// right-ascension sectors scaled by cos(dec) so cells stay roughly equal
+// area near the poles -- the same goal as a trixel/HTM mesh, achieved with
+// a flat grid instead of a recursive triangular one, since at this catalog
+// scale a simple grid is cheaper to build and query.
 
-    #[test]
-    fn test_bright_stars() {
-        let catalog = StarCatalog::with_bright_stars();
-        assert_eq!(catalog.len(), 50);
+/// Magic bytes identifying the indexed catalog data file. Bumped from
+/// `SEC1` when `Star::id` widened from u32 to u64 (to fit catalogs like
+/// Gaia DR3 whose `source_id` doesn't), since that changed `RECORD_SIZE`.
+const CATALOG_DATA_MAGIC: &[u8; 4] = b"SEC2";
+/// Magic bytes identifying the indexed catalog's sky-cell index file.
+const CATALOG_INDEX_MAGIC: &[u8; 4] = b"SECX";
 
-        // Sirius should be the first and brightest
-        let sirius = &catalog.stars()[0];
-        assert!(sirius.vmag < -1.0);
-        assert_eq!(sirius.id, 2491);
+/// Size in bytes of one packed star record in the indexed data format.
+const RECORD_SIZE: usize = 20;
+/// Size in bytes of the data file header (magic + record count).
+const DATA_HEADER_SIZE: usize = 8;
+/// Size in bytes of one index entry (cell id + start record + record count).
+const INDEX_ENTRY_SIZE: usize = 12;
+/// Size in bytes of the index file header (magic + entry count).
+const INDEX_HEADER_SIZE: usize = 8;
+
+/// Number of declination bands spanning the sky, pole to pole (10 degrees each).
+const INDEX_DEC_BANDS: u32 = 18;
+
+/// Integer scale applied to RA/Dec (radians) before packing into an `i32`.
+/// At this scale an `i32` covers roughly ±214 radians with ~0.1 microradian
+/// resolution, far finer than any catalog's positional accuracy.
+const ANGLE_SCALE: f64 = 1.0e7;
+/// Integer scale applied to magnitudes before packing into an `i16`.
+const MAG_SCALE: f64 = 100.0;
+
+fn encode_angle(rad: f64) -> i32 {
+    (rad * ANGLE_SCALE).round() as i32
+}
+
+fn decode_angle(raw: i32) -> f64 {
+    raw as f64 / ANGLE_SCALE
+}
+
+fn encode_mag(v: f32) -> i16 {
+    (v as f64 * MAG_SCALE).round() as i16
+}
+
+fn decode_mag(raw: i16) -> f32 {
+    (raw as f64 / MAG_SCALE) as f32
+}
+
+/// Number of right-ascension sectors in a given declination band, scaled by
+/// cos(dec) at the band's center so cells near the poles aren't absurdly
+/// thin slivers.
+fn ra_sectors_for_band(band: u32) -> u32 {
+    let band_center_dec = ((band as f64 + 0.5) / INDEX_DEC_BANDS as f64) * PI - PI / 2.0;
+    let sectors_at_equator = INDEX_DEC_BANDS * 2;
+    ((sectors_at_equator as f64 * band_center_dec.cos()).round() as u32).max(1)
+}
+
+/// The declination range `[lo, hi)` covered by a band, in radians.
+fn band_dec_bounds(band: u32) -> (f64, f64) {
+    let lo = (band as f64 / INDEX_DEC_BANDS as f64) * PI - PI / 2.0;
+    let hi = ((band + 1) as f64 / INDEX_DEC_BANDS as f64) * PI - PI / 2.0;
+    (lo, hi)
+}
+
+/// The right-ascension range `[lo, hi)` covered by a sector, in radians.
+fn sector_ra_bounds(band: u32, sector: u32) -> (f64, f64) {
+    let sectors = ra_sectors_for_band(band);
+    let lo = (sector as f64 / sectors as f64) * 2.0 * PI;
+    let hi = ((sector + 1) as f64 / sectors as f64) * 2.0 * PI;
+    (lo, hi)
+}
+
+/// Map a sky position to its flat cell id (bands stacked south to north,
+/// sectors within a band running 0..2π).
+fn sky_cell_id(ra_rad: f64, dec_rad: f64) -> u32 {
+    let band = (((dec_rad + PI / 2.0) / PI) * INDEX_DEC_BANDS as f64)
+        .floor()
+        .clamp(0.0, (INDEX_DEC_BANDS - 1) as f64) as u32;
+    let sectors = ra_sectors_for_band(band);
+    let ra_norm = ra_rad.rem_euclid(2.0 * PI);
+    let sector = ((ra_norm / (2.0 * PI)) * sectors as f64)
+        .floor()
+        .clamp(0.0, (sectors - 1) as f64) as u32;
+
+    let mut offset = 0u32;
+    for b in 0..band {
+        offset += ra_sectors_for_band(b);
     }
+    offset + sector
+}
 
-    #[test]
-    fn test_magnitude_filter() {
-        let catalog = StarCatalog::with_bright_stars();
-        let bright: Vec<_> = catalog.stars_brighter_than(1.0).collect();
-        // Should have fewer stars than total
-        assert!(bright.len() < catalog.len());
-        // All should be brighter than 1.0
-        assert!(bright.iter().all(|s| s.vmag <= 1.0));
+/// Inverse of the offset accumulation in `sky_cell_id`: which band/sector a
+/// flat cell id belongs to.
+fn cell_band_sector(cell: u32) -> (u32, u32) {
+    let mut offset = 0u32;
+    for band in 0..INDEX_DEC_BANDS {
+        let sectors = ra_sectors_for_band(band);
+        if cell < offset + sectors {
+            return (band, cell - offset);
+        }
+        offset += sectors;
     }
+    (INDEX_DEC_BANDS - 1, 0)
+}
 
-    #[test]
-    fn test_binary_roundtrip() {
-        let catalog = StarCatalog::with_bright_stars();
+/// Great-circle angular separation between two RA/Dec positions, in radians.
+fn angular_separation_rad(ra1: f64, dec1: f64, ra2: f64, dec2: f64) -> f64 {
+    let a = ra_dec_to_cartesian(ra1, dec1);
+    let b = ra_dec_to_cartesian(ra2, dec2);
+    let cos_sep = (a.x * b.x + a.y * b.y + a.z * b.z).clamp(-1.0, 1.0);
+    cos_sep.acos()
+}
 
-        // Serialize to binary
-        let mut data = Vec::new();
-        data.extend_from_slice(&(catalog.len() as u32).to_le_bytes());
-        for star in catalog.stars() {
-            data.extend_from_slice(&(star.ra as f32).to_le_bytes());
-            data.extend_from_slice(&(star.dec as f32).to_le_bytes());
-            data.extend_from_slice(&star.vmag.to_le_bytes());
-            data.extend_from_slice(&star.bv_color.to_le_bytes());
-            data.extend_from_slice(&star.id.to_le_bytes());
+/// Whether a sky cell could plausibly contain a point within `radius_rad` of
+/// `(ra_rad, dec_rad)`. Conservative: may return `true` for cells that don't
+/// actually overlap, but never `false` for one that does. Callers must
+/// follow up with an exact angular-separation check on decoded records.
+fn cell_may_overlap(cell: u32, ra_rad: f64, dec_rad: f64, radius_rad: f64) -> bool {
+    let (band, sector) = cell_band_sector(cell);
+
+    let (dec_lo, dec_hi) = band_dec_bounds(band);
+    if dec_rad < dec_lo - radius_rad || dec_rad > dec_hi + radius_rad {
+        return false;
+    }
+
+    let (ra_lo, ra_hi) = sector_ra_bounds(band, sector);
+    // RA sectors narrow toward the poles; expand the RA margin by 1/cos(dec)
+    // so the bounding box stays conservative there.
+    let cos_lat = dec_rad.cos().max(0.05);
+    let ra_margin = radius_rad / cos_lat;
+    let ra_norm = ra_rad.rem_euclid(2.0 * PI);
+
+    let in_range = |r: f64| r >= ra_lo - ra_margin && r <= ra_hi + ra_margin;
+    in_range(ra_norm) || in_range(ra_norm + 2.0 * PI) || in_range(ra_norm - 2.0 * PI)
+}
+
+fn encode_star_record(buf: &mut Vec<u8>, star: &Star) {
+    buf.extend_from_slice(&encode_angle(star.ra.rem_euclid(2.0 * PI)).to_le_bytes());
+    buf.extend_from_slice(&encode_angle(star.dec).to_le_bytes());
+    buf.extend_from_slice(&encode_mag(star.vmag).to_le_bytes());
+    buf.extend_from_slice(&encode_mag(star.bv_color).to_le_bytes());
+    buf.extend_from_slice(&star.id.to_le_bytes());
+}
+
+fn decode_star_record(record: &[u8]) -> Star {
+    let ra_raw = i32::from_le_bytes([record[0], record[1], record[2], record[3]]);
+    let dec_raw = i32::from_le_bytes([record[4], record[5], record[6], record[7]]);
+    let vmag_raw = i16::from_le_bytes([record[8], record[9]]);
+    let bv_raw = i16::from_le_bytes([record[10], record[11]]);
+    let id = u64::from_le_bytes([
+        record[12], record[13], record[14], record[15], record[16], record[17], record[18],
+        record[19],
+    ]);
+
+    Star {
+        ra: decode_angle(ra_raw),
+        dec: decode_angle(dec_raw),
+        vmag: decode_mag(vmag_raw),
+        bv_color: decode_mag(bv_raw),
+        id,
+        cross_ids: Vec::new(),
+        parallax_mas: 0.0,
+        pm_ra_masyr: 0.0,
+        pm_dec_masyr: 0.0,
+        rv_kms: 0.0,
+    }
+}
+
+/// One entry in a parsed sky-cell index: which records in the data file
+/// belong to a given cell.
+#[derive(Debug, Clone, Copy)]
+struct IndexEntry {
+    cell: u32,
+    start_record: u32,
+    record_count: u32,
+}
+
+/// Write a catalog as a (data, index) pair in the compact indexed binary
+/// format: records are sorted by sky cell, packed as scaled integers, and
+/// the index maps each populated cell to its contiguous record range.
+pub fn write_indexed_catalog(stars: &[Star]) -> (Vec<u8>, Vec<u8>) {
+    let mut sorted: Vec<&Star> = stars.iter().collect();
+    sorted.sort_by_key(|s| sky_cell_id(s.ra, s.dec));
+
+    let mut data = Vec::with_capacity(DATA_HEADER_SIZE + sorted.len() * RECORD_SIZE);
+    data.extend_from_slice(CATALOG_DATA_MAGIC);
+    data.extend_from_slice(&(sorted.len() as u32).to_le_bytes());
+
+    let mut entries: Vec<IndexEntry> = Vec::new();
+    let mut run_start: u32 = 0;
+    let mut run_cell: Option<u32> = None;
+
+    for (i, star) in sorted.iter().enumerate() {
+        encode_star_record(&mut data, star);
+
+        let cell = sky_cell_id(star.ra, star.dec);
+        match run_cell {
+            None => run_cell = Some(cell),
+            Some(c) if c != cell => {
+                entries.push(IndexEntry {
+                    cell: c,
+                    start_record: run_start,
+                    record_count: i as u32 - run_start,
+                });
+                run_cell = Some(cell);
+                run_start = i as u32;
+            }
+            _ => {}
         }
+    }
+    if let Some(c) = run_cell {
+        entries.push(IndexEntry {
+            cell: c,
+            start_record: run_start,
+            record_count: sorted.len() as u32 - run_start,
+        });
+    }
 
-        // Deserialize
-        let loaded = StarCatalog::from_binary(&data).unwrap();
-        assert_eq!(loaded.len(), catalog.len());
+    let mut index = Vec::with_capacity(INDEX_HEADER_SIZE + entries.len() * INDEX_ENTRY_SIZE);
+    index.extend_from_slice(CATALOG_INDEX_MAGIC);
+    index.extend_from_slice(&(entries.len() as u32).to_le_bytes());
+    for entry in &entries {
+        index.extend_from_slice(&entry.cell.to_le_bytes());
+        index.extend_from_slice(&entry.start_record.to_le_bytes());
+        index.extend_from_slice(&entry.record_count.to_le_bytes());
+    }
+
+    (data, index)
+}
+
+/// A memory-mappable reader over the compact indexed binary format.
+///
+/// `data` is held as a plain byte slice so a caller can pass in bytes backed
+/// by a memory-mapped file (via an external mmap crate); records are decoded
+/// lazily, on access, rather than eagerly parsed into a `Vec<Star>`.
+pub struct IndexedStarCatalog<'a> {
+    data: &'a [u8],
+    record_count: usize,
+    index: Vec<IndexEntry>,
+}
+
+impl<'a> IndexedStarCatalog<'a> {
+    /// Parse a (data, index) pair produced by `write_indexed_catalog`.
+    pub fn open(data: &'a [u8], index_data: &[u8]) -> Result<Self, &'static str> {
+        if data.len() < DATA_HEADER_SIZE || &data[0..4] != CATALOG_DATA_MAGIC {
+            return Err("invalid or missing catalog data header");
+        }
+        let record_count =
+            u32::from_le_bytes([data[4], data[5], data[6], data[7]]) as usize;
+        let expected_len = DATA_HEADER_SIZE + record_count * RECORD_SIZE;
+        if data.len() < expected_len {
+            return Err("catalog data shorter than header's record count implies");
+        }
+
+        if index_data.len() < INDEX_HEADER_SIZE || &index_data[0..4] != CATALOG_INDEX_MAGIC {
+            return Err("invalid or missing catalog index header");
+        }
+        let entry_count =
+            u32::from_le_bytes([index_data[4], index_data[5], index_data[6], index_data[7]])
+                as usize;
+        let expected_index_len = INDEX_HEADER_SIZE + entry_count * INDEX_ENTRY_SIZE;
+        if index_data.len() < expected_index_len {
+            return Err("catalog index shorter than header's entry count implies");
+        }
+
+        let mut index = Vec::with_capacity(entry_count);
+        let mut offset = INDEX_HEADER_SIZE;
+        for _ in 0..entry_count {
+            let cell = u32::from_le_bytes([
+                index_data[offset],
+                index_data[offset + 1],
+                index_data[offset + 2],
+                index_data[offset + 3],
+            ]);
+            let start_record = u32::from_le_bytes([
+                index_data[offset + 4],
+                index_data[offset + 5],
+                index_data[offset + 6],
+                index_data[offset + 7],
+            ]);
+            let record_count = u32::from_le_bytes([
+                index_data[offset + 8],
+                index_data[offset + 9],
+                index_data[offset + 10],
+                index_data[offset + 11],
+            ]);
+            index.push(IndexEntry {
+                cell,
+                start_record,
+                record_count,
+            });
+            offset += INDEX_ENTRY_SIZE;
+        }
+
+        Ok(Self {
+            data,
+            record_count,
+            index,
+        })
+    }
+
+    /// Total number of stars in the catalog.
+    pub fn len(&self) -> usize {
+        self.record_count
+    }
+
+    /// Whether the catalog has no stars.
+    pub fn is_empty(&self) -> bool {
+        self.record_count == 0
+    }
+
+    fn decode_record(&self, record_index: u32) -> Star {
+        let offset = DATA_HEADER_SIZE + record_index as usize * RECORD_SIZE;
+        decode_star_record(&self.data[offset..offset + RECORD_SIZE])
+    }
+
+    /// Decode every star in the catalog, in on-disk (sky-cell-sorted) order.
+    pub fn all_stars(&self) -> Vec<Star> {
+        (0..self.record_count as u32)
+            .map(|i| self.decode_record(i))
+            .collect()
+    }
+
+    /// Load only the stars within `radius_rad` of `(ra_rad, dec_rad)`, using
+    /// the sky-cell index to skip record ranges that can't possibly
+    /// intersect the query circle instead of scanning the whole catalog.
+    pub fn load_region(&self, ra_rad: f64, dec_rad: f64, radius_rad: f64) -> Vec<Star> {
+        let mut results = Vec::new();
+        for entry in &self.index {
+            if !cell_may_overlap(entry.cell, ra_rad, dec_rad, radius_rad) {
+                continue;
+            }
+            for i in entry.start_record..entry.start_record + entry.record_count {
+                let star = self.decode_record(i);
+                if angular_separation_rad(star.ra, star.dec, ra_rad, dec_rad) <= radius_rad {
+                    results.push(star);
+                }
+            }
+        }
+        results
+    }
+}
+
+// --- Self-describing binary format with header + CRC ------------------------
+//
+// `from_binary`'s header is just a bare star count (plus, as of version 1, an
+// unlabeled version-tag byte): nothing in the file says which catalog or
+// epoch produced it, and there's no way to detect truncation or bit rot
+// short of parsing every record and hoping the trailing bytes land exactly
+// on a record boundary. This format wraps the same idea -- fixed-size
+// per-star records -- in an explicit header (magic, format version, source
+// catalog, epoch, which optional fields the records carry) and a trailing
+// CRC-32 over the record block, so a corrupt or truncated file is rejected
+// with a typed error at a known offset instead of silently producing
+// garbage stars.
+
+/// Magic bytes opening every file in this format.
+const HEADER_MAGIC: &[u8; 8] = b"ONCESTAR";
+
+/// Format version written by `write_versioned_catalog`. Bump this and add a
+/// branch in `load_versioned_catalog` if the header or record layout ever
+/// changes incompatibly.
+const HEADER_FORMAT_VERSION: u16 = 1;
+
+/// `HEADER_FLAGS` bit recording that every record carries `pm_ra_masyr` and
+/// `pm_dec_masyr`.
+const FLAG_PROPER_MOTION: u16 = 1 << 0;
+/// `HEADER_FLAGS` bit recording that every record carries `parallax_mas`.
+const FLAG_PARALLAX: u16 = 1 << 1;
+
+/// Size in bytes of the fixed header: magic + version + flags + source +
+/// epoch_year + star_count.
+const VERSIONED_HEADER_SIZE: usize = 8 + 2 + 2 + 1 + 4 + 4;
+
+/// Magic bytes opening an xz stream, used to auto-detect a record block
+/// written by `write_versioned_catalog_compressed` (or by `preprocess_stars
+/// --compress`, which wraps its record block the same way) so an
+/// uncompressed file still loads with no extra ceremony.
+const XZ_MAGIC: [u8; 6] = [0xFD, 0x37, 0x7A, 0x58, 0x5A, 0x00];
+
+/// Which original catalog a versioned binary was built from, recorded in
+/// the header so a reader can tell what it's looking at without
+/// re-deriving it from filenames or record counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceCatalog {
+    /// Yale Bright Star Catalogue.
+    Bsc,
+    /// Hipparcos Catalogue.
+    Hipparcos,
+    /// Gaia DR3 source table.
+    Gaia,
+    /// Any other source, or one not worth naming a variant for.
+    Other,
+}
+
+impl SourceCatalog {
+    fn to_u8(self) -> u8 {
+        match self {
+            SourceCatalog::Bsc => 0,
+            SourceCatalog::Hipparcos => 1,
+            SourceCatalog::Gaia => 2,
+            SourceCatalog::Other => 255,
+        }
+    }
+
+    /// Unrecognized bytes (e.g. from a future writer) decode as `Other`
+    /// rather than failing -- the source catalog is metadata, not
+    /// something a reader needs to validate to safely use the records.
+    fn from_u8(v: u8) -> Self {
+        match v {
+            0 => SourceCatalog::Bsc,
+            1 => SourceCatalog::Hipparcos,
+            2 => SourceCatalog::Gaia,
+            _ => SourceCatalog::Other,
+        }
+    }
+}
+
+/// Errors `load_versioned_catalog` can return. Each variant names exactly
+/// what was wrong with the input, so a corrupt or truncated file fails
+/// loudly at load time instead of producing garbage stars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CatalogHeaderError {
+    /// The file doesn't open with the `ONCESTAR` magic bytes.
+    BadMagic,
+    /// The header's format version isn't one this build understands.
+    UnsupportedVersion(u16),
+    /// The record block's CRC-32 doesn't match the trailer.
+    ChecksumMismatch,
+    /// The buffer is shorter than the header says it should be.
+    Truncated,
+    /// The record block is xz-compressed, but this build wasn't compiled
+    /// with the `xz` feature enabled, so it has no decompressor to hand it
+    /// to.
+    XzFeatureDisabled,
+}
+
+impl std::fmt::Display for CatalogHeaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CatalogHeaderError::BadMagic => write!(f, "missing or invalid ONCESTAR magic bytes"),
+            CatalogHeaderError::UnsupportedVersion(v) => {
+                write!(f, "unsupported catalog format version {v}")
+            }
+            CatalogHeaderError::ChecksumMismatch => {
+                write!(f, "record block failed its CRC-32 check")
+            }
+            CatalogHeaderError::Truncated => {
+                write!(f, "catalog data is shorter than its header declares")
+            }
+            CatalogHeaderError::XzFeatureDisabled => {
+                write!(f, "record block is xz-compressed but the `xz` feature is disabled")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CatalogHeaderError {}
+
+/// A catalog loaded from the self-describing versioned binary format,
+/// together with the header metadata recorded alongside it at write time.
+#[derive(Debug, Clone)]
+pub struct VersionedCatalog {
+    pub stars: Vec<Star>,
+    pub source: SourceCatalog,
+    /// The decimal-year epoch every star's position is valid at.
+    pub epoch_year: f32,
+}
+
+/// Standard CRC-32 (IEEE 802.3, polynomial `0xEDB8_8320`), computed bit by
+/// bit rather than via a precomputed table since this crate has no
+/// dependency that would supply one.
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc: u32 = 0xFFFF_FFFF;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+fn read_f32_le(data: &[u8], offset: usize) -> f32 {
+    f32::from_le_bytes([data[offset], data[offset + 1], data[offset + 2], data[offset + 3]])
+}
+
+fn read_u64_le(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes([
+        data[offset],
+        data[offset + 1],
+        data[offset + 2],
+        data[offset + 3],
+        data[offset + 4],
+        data[offset + 5],
+        data[offset + 6],
+        data[offset + 7],
+    ])
+}
+
+/// Build the flags byte and raw (uncompressed) record block shared by both
+/// `write_versioned_catalog` and `write_versioned_catalog_compressed`.
+fn build_versioned_records(stars: &[Star]) -> (u16, Vec<u8>) {
+    let has_parallax = stars.iter().any(|s| s.parallax_mas > 0.0);
+    let has_proper_motion = stars
+        .iter()
+        .any(|s| s.pm_ra_masyr != 0.0 || s.pm_dec_masyr != 0.0);
+
+    let mut flags = 0u16;
+    if has_proper_motion {
+        flags |= FLAG_PROPER_MOTION;
+    }
+    if has_parallax {
+        flags |= FLAG_PARALLAX;
+    }
+
+    let mut records = Vec::new();
+    for star in stars {
+        records.extend_from_slice(&(star.ra as f32).to_le_bytes());
+        records.extend_from_slice(&(star.dec as f32).to_le_bytes());
+        records.extend_from_slice(&star.vmag.to_le_bytes());
+        records.extend_from_slice(&star.bv_color.to_le_bytes());
+        records.extend_from_slice(&star.id.to_le_bytes());
+        if has_parallax {
+            records.extend_from_slice(&star.parallax_mas.to_le_bytes());
+        }
+        if has_proper_motion {
+            records.extend_from_slice(&star.pm_ra_masyr.to_le_bytes());
+            records.extend_from_slice(&star.pm_dec_masyr.to_le_bytes());
+        }
+    }
+
+    (flags, records)
+}
+
+fn versioned_header_bytes(star_count: usize, source: SourceCatalog, epoch_year: f32, flags: u16) -> Vec<u8> {
+    let mut out = Vec::with_capacity(VERSIONED_HEADER_SIZE);
+    out.extend_from_slice(HEADER_MAGIC);
+    out.extend_from_slice(&HEADER_FORMAT_VERSION.to_le_bytes());
+    out.extend_from_slice(&flags.to_le_bytes());
+    out.push(source.to_u8());
+    out.extend_from_slice(&epoch_year.to_le_bytes());
+    out.extend_from_slice(&(star_count as u32).to_le_bytes());
+    out
+}
+
+/// Write stars in the self-describing versioned binary format.
+///
+/// Header (`VERSIONED_HEADER_SIZE` bytes):
+/// - `[u8; 8]` magic (`b"ONCESTAR"`)
+/// - `u16` format version (currently `1`)
+/// - `u16` flags: bit 0 = proper motion present, bit 1 = parallax present
+/// - `u8` source catalog (see `SourceCatalog`)
+/// - `f32` epoch_year the recorded positions are valid at
+/// - `u32` star_count
+///
+/// Per star: `f32` ra_rad, `f32` dec_rad, `f32` vmag, `f32` bv_color, `u64`
+/// id, then `f32` parallax_mas if the parallax flag is set, then `f32`
+/// pm_ra_masyr + `f32` pm_dec_masyr if the proper-motion flag is set --
+/// whichever of those two fields are actually populated across `stars`, so a
+/// catalog with no proper motion (e.g. Gaia without its time-series fields)
+/// doesn't pay for fields it doesn't have.
+///
+/// Trailer: `u32` CRC-32 over the record block (not the header). See
+/// `write_versioned_catalog_compressed` for a variant that xz-compresses
+/// the record block; this function never does.
+pub fn write_versioned_catalog(stars: &[Star], source: SourceCatalog, epoch_year: f32) -> Vec<u8> {
+    let (flags, records) = build_versioned_records(stars);
+    let mut out = versioned_header_bytes(stars.len(), source, epoch_year, flags);
+    out.extend_from_slice(&records);
+    out.extend_from_slice(&crc32(&records).to_le_bytes());
+    out
+}
+
+/// Like `write_versioned_catalog`, but xz-compresses the record block (the
+/// header stays uncompressed, so the star count and other metadata remain
+/// cheaply readable without decompressing anything). The CRC-32 trailer is
+/// computed over the *uncompressed* records, so integrity is still checked
+/// post-decompression on load. `load_versioned_catalog` auto-detects the
+/// xz magic and decompresses transparently, so callers don't need to know
+/// which of these two functions produced a given file.
+#[cfg(feature = "xz")]
+pub fn write_versioned_catalog_compressed(stars: &[Star], source: SourceCatalog, epoch_year: f32) -> Vec<u8> {
+    let (flags, records) = build_versioned_records(stars);
+    let mut out = versioned_header_bytes(stars.len(), source, epoch_year, flags);
+    out.extend_from_slice(&compress_xz(&records));
+    out.extend_from_slice(&crc32(&records).to_le_bytes());
+    out
+}
+
+#[cfg(feature = "xz")]
+fn compress_xz(data: &[u8]) -> Vec<u8> {
+    use std::io::Write;
+    let mut encoder = xz2::write::XzEncoder::new(Vec::new(), 6);
+    encoder.write_all(data).expect("in-memory xz compression cannot fail");
+    encoder.finish().expect("in-memory xz compression cannot fail")
+}
+
+#[cfg(feature = "xz")]
+fn decompress_xz(data: &[u8]) -> Result<Vec<u8>, CatalogHeaderError> {
+    use std::io::Read;
+    let mut decoder = xz2::read::XzDecoder::new(data);
+    let mut out = Vec::new();
+    decoder
+        .read_to_end(&mut out)
+        .map_err(|_| CatalogHeaderError::Truncated)?;
+    Ok(out)
+}
+
+#[cfg(not(feature = "xz"))]
+fn decompress_xz(_data: &[u8]) -> Result<Vec<u8>, CatalogHeaderError> {
+    Err(CatalogHeaderError::XzFeatureDisabled)
+}
+
+/// Load a catalog written by `write_versioned_catalog` or
+/// `write_versioned_catalog_compressed`, validating the magic bytes, format
+/// version and CRC-32 (checked against the uncompressed records, after
+/// transparently decompressing an xz-compressed record block) before
+/// handing back any stars.
+pub fn load_versioned_catalog(data: &[u8]) -> Result<VersionedCatalog, CatalogHeaderError> {
+    if data.len() < VERSIONED_HEADER_SIZE {
+        return Err(CatalogHeaderError::Truncated);
+    }
+    if &data[0..8] != HEADER_MAGIC {
+        return Err(CatalogHeaderError::BadMagic);
+    }
+
+    let version = u16::from_le_bytes([data[8], data[9]]);
+    if version != HEADER_FORMAT_VERSION {
+        return Err(CatalogHeaderError::UnsupportedVersion(version));
+    }
+
+    let flags = u16::from_le_bytes([data[10], data[11]]);
+    let has_parallax = flags & FLAG_PARALLAX != 0;
+    let has_proper_motion = flags & FLAG_PROPER_MOTION != 0;
+    let source = SourceCatalog::from_u8(data[12]);
+    let epoch_year = read_f32_le(data, 13);
+    let star_count = u32::from_le_bytes([data[17], data[18], data[19], data[20]]) as usize;
+    let record_size = 24 + if has_parallax { 4 } else { 0 } + if has_proper_motion { 8 } else { 0 };
+
+    let body = &data[VERSIONED_HEADER_SIZE..];
+    if body.len() < 4 {
+        return Err(CatalogHeaderError::Truncated);
+    }
+
+    let (records, stored_crc) = if body.starts_with(&XZ_MAGIC) {
+        let compressed = &body[..body.len() - 4];
+        let stored_crc = u32::from_le_bytes([
+            body[body.len() - 4],
+            body[body.len() - 3],
+            body[body.len() - 2],
+            body[body.len() - 1],
+        ]);
+        (decompress_xz(compressed)?, stored_crc)
+    } else {
+        let records_len = star_count * record_size;
+        if body.len() < records_len + 4 {
+            return Err(CatalogHeaderError::Truncated);
+        }
+        let stored_crc = u32::from_le_bytes([
+            body[records_len],
+            body[records_len + 1],
+            body[records_len + 2],
+            body[records_len + 3],
+        ]);
+        (body[..records_len].to_vec(), stored_crc)
+    };
+
+    if records.len() != star_count * record_size {
+        return Err(CatalogHeaderError::Truncated);
+    }
+    if crc32(&records) != stored_crc {
+        return Err(CatalogHeaderError::ChecksumMismatch);
+    }
+    let records = records.as_slice();
+
+    let mut stars = Vec::with_capacity(star_count);
+    let mut offset = 0;
+    for _ in 0..star_count {
+        let ra = read_f32_le(records, offset) as f64;
+        let dec = read_f32_le(records, offset + 4) as f64;
+        let vmag = read_f32_le(records, offset + 8);
+        let bv_color = read_f32_le(records, offset + 12);
+        let id = read_u64_le(records, offset + 16);
+
+        let mut field_offset = offset + 24;
+        let parallax_mas = if has_parallax {
+            let v = read_f32_le(records, field_offset);
+            field_offset += 4;
+            v
+        } else {
+            0.0
+        };
+        let (pm_ra_masyr, pm_dec_masyr) = if has_proper_motion {
+            (read_f32_le(records, field_offset), read_f32_le(records, field_offset + 4))
+        } else {
+            (0.0, 0.0)
+        };
+
+        stars.push(Star {
+            ra,
+            dec,
+            vmag,
+            bv_color,
+            id,
+            cross_ids: Vec::new(),
+            parallax_mas,
+            pm_ra_masyr,
+            pm_dec_masyr,
+            rv_kms: 0.0,
+        });
+
+        offset += record_size;
+    }
+
+    Ok(VersionedCatalog { stars, source, epoch_year })
+}
+
+// --- Zero-copy rkyv archive format ------------------------------------------
+//
+// `from_binary` and the indexed format above both land in a `Vec<Star>` (or
+// decode one record at a time) after an allocation/byte-copy pass over the
+// whole buffer -- fine for the catalogs sizes this crate usually sees, but
+// wasteful for a multi-hundred-thousand-star catalog a WASM or embedded
+// consumer wants to hold as a `const` byte slice or an mmap'd file. Following
+// sourmash's adoption of rkyv for exactly this kind of workload, this module
+// validates a buffer once and then exposes every star as a borrow straight
+// into those bytes, with no per-star decoding at all.
+#[cfg(feature = "rkyv")]
+mod rkyv_format {
+    use super::*;
+    use rkyv::{Archive, Deserialize, Serialize};
+
+    /// Archived counterpart to `Catalog`, so cross-catalog ids survive a
+    /// round trip through an rkyv archive.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Archive, Serialize, Deserialize)]
+    #[archive(check_bytes)]
+    #[archive_attr(derive(Debug, Clone, Copy, PartialEq, Eq, Hash))]
+    pub enum ArchivableCatalog {
+        Hr,
+        Hd,
+        Hip,
+        Tycho,
+        Sao,
+        Gliese,
+    }
+
+    impl From<Catalog> for ArchivableCatalog {
+        fn from(catalog: Catalog) -> Self {
+            match catalog {
+                Catalog::Hr => ArchivableCatalog::Hr,
+                Catalog::Hd => ArchivableCatalog::Hd,
+                Catalog::Hip => ArchivableCatalog::Hip,
+                Catalog::Tycho => ArchivableCatalog::Tycho,
+                Catalog::Sao => ArchivableCatalog::Sao,
+                Catalog::Gliese => ArchivableCatalog::Gliese,
+            }
+        }
+    }
+
+    /// Archived, zero-copy counterpart to `Star`. Field-for-field the same
+    /// as `Star` (minus the derived quantities, which the borrowed
+    /// `ArchivedStarRecord` can still compute by reading `ra`/`dec`/etc.
+    /// directly), so `rkyv`'s derive can lay it out for direct borrowing.
+    #[derive(Debug, Clone, Archive, Serialize, Deserialize)]
+    #[archive(check_bytes)]
+    pub struct StarRecord {
+        pub ra: f64,
+        pub dec: f64,
+        pub vmag: f32,
+        pub bv_color: f32,
+        pub id: u64,
+        pub cross_ids: Vec<(ArchivableCatalog, u64)>,
+        pub parallax_mas: f32,
+        pub pm_ra_masyr: f32,
+        pub pm_dec_masyr: f32,
+        pub rv_kms: f32,
+    }
+
+    impl From<&Star> for StarRecord {
+        fn from(star: &Star) -> Self {
+            Self {
+                ra: star.ra,
+                dec: star.dec,
+                vmag: star.vmag,
+                bv_color: star.bv_color,
+                id: star.id,
+                cross_ids: star.cross_ids.iter().map(|&(c, n)| (c.into(), n)).collect(),
+                parallax_mas: star.parallax_mas,
+                pm_ra_masyr: star.pm_ra_masyr,
+                pm_dec_masyr: star.pm_dec_masyr,
+                rv_kms: star.rv_kms,
+            }
+        }
+    }
+
+    /// The whole catalog, in rkyv's archivable representation.
+    #[derive(Debug, Clone, Archive, Serialize, Deserialize)]
+    #[archive(check_bytes)]
+    pub struct CatalogRecord {
+        pub stars: Vec<StarRecord>,
+    }
+
+    /// A catalog backed directly by a validated rkyv archive buffer -- the
+    /// zero-copy analogue of `StarCatalog`. Every query method borrows its
+    /// results straight out of `bytes`; nothing is decoded into an owned
+    /// `Star` up front.
+    pub struct ArchivedStarCatalog {
+        bytes: &'static [u8],
+        /// Unit directions for `stars_near`, computed from the archived
+        /// ra/dec on first use and cached (same tradeoff as
+        /// `StarCatalog::directions`, just built lazily here since
+        /// `from_archive` itself must stay O(1)).
+        directions: RefCell<Option<Vec<(f32, f32, f32)>>>,
+        cone_index: RefCell<Option<KdNode>>,
+    }
+
+    impl ArchivedStarCatalog {
+        /// Validate `bytes` as an rkyv archive of `CatalogRecord` and wrap it
+        /// for querying. Validation walks the buffer once, here; every
+        /// accessor afterward is a direct borrow into `bytes`, which is the
+        /// point of embedding a catalog as a `const` byte slice or an mmap'd
+        /// file instead of parsing it into a `Vec<Star>`.
+        pub fn from_archive(bytes: &'static [u8]) -> Result<Self, &'static str> {
+            rkyv::check_archived_root::<CatalogRecord>(bytes).map_err(|_| "invalid rkyv archive")?;
+            Ok(Self { bytes, directions: RefCell::new(None), cone_index: RefCell::new(None) })
+        }
+
+        fn archived(&self) -> &ArchivedCatalogRecord {
+            // `from_archive` already validated `bytes` via `check_archived_root`,
+            // so reinterpreting the buffer's tail here can't fail.
+            unsafe { rkyv::archived_root::<CatalogRecord>(self.bytes) }
+        }
+
+        /// Every star in the archive, borrowed directly from `bytes`.
+        pub fn stars(&self) -> &[ArchivedStarRecord] {
+            &self.archived().stars
+        }
+
+        /// Stars at or brighter than `mag_limit`, borrowed from `bytes`.
+        pub fn stars_brighter_than(&self, mag_limit: f32) -> impl Iterator<Item = &ArchivedStarRecord> {
+            self.stars().iter().filter(move |s| s.vmag <= mag_limit)
+        }
+
+        /// Stars within `radius_rad` of `dir` and at or brighter than
+        /// `mag_limit`; see `StarCatalog::stars_near`, which this mirrors.
+        /// Builds (and caches) a direction list and cone-query kd-tree over
+        /// the archive's stars on first call.
+        pub fn stars_near(
+            &self,
+            dir: CartesianCoord,
+            radius_rad: f64,
+            mag_limit: f32,
+        ) -> impl Iterator<Item = &ArchivedStarRecord> {
+            let dir = [dir.x as f32, dir.y as f32, dir.z as f32];
+            let cos_theta = radius_rad.cos() as f32;
+
+            if self.directions.borrow().is_none() {
+                let directions: Vec<(f32, f32, f32)> =
+                    self.stars().iter().map(|s| scalar_direction(s.ra, s.dec)).collect();
+                *self.directions.borrow_mut() = Some(directions);
+            }
+
+            if self.cone_index.borrow().is_none() {
+                let points: Vec<(usize, [f32; 3])> = self
+                    .directions
+                    .borrow()
+                    .as_ref()
+                    .unwrap()
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &(x, y, z))| (i, [x, y, z]))
+                    .collect();
+                *self.cone_index.borrow_mut() = Some(build_kd_node(points));
+            }
+
+            let mut matches = Vec::new();
+            let directions = self.directions.borrow();
+            let directions = directions.as_ref().unwrap();
+            if let Some(root) = self.cone_index.borrow().as_ref() {
+                query_kd_node(root, directions, dir, cos_theta, &mut matches);
+            }
+
+            let stars = self.stars();
+            matches.into_iter().filter_map(move |i| stars.get(i)).filter(move |s| s.vmag <= mag_limit)
+        }
+
+        /// Number of stars in the archive.
+        pub fn len(&self) -> usize {
+            self.stars().len()
+        }
+
+        /// Whether the archive holds no stars.
+        pub fn is_empty(&self) -> bool {
+            self.stars().is_empty()
+        }
+    }
+
+    /// Transcode a legacy hand-rolled binary buffer (`StarCatalog::from_binary`)
+    /// into an rkyv archive buffer, for migrating existing catalog files to
+    /// the zero-copy format without re-fetching source data.
+    pub fn transcode_legacy_to_archive(data: &[u8]) -> Result<Vec<u8>, &'static str> {
+        let catalog = StarCatalog::from_binary(data)?;
+        let record = CatalogRecord { stars: catalog.stars().iter().map(StarRecord::from).collect() };
+        let bytes =
+            rkyv::to_bytes::<_, 1024>(&record).map_err(|_| "failed to serialize rkyv archive")?;
+        Ok(bytes.into_vec())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn sirius_binary() -> Vec<u8> {
+            let mut data = Vec::new();
+            data.extend_from_slice(&1u32.to_le_bytes());
+            data.extend_from_slice(&0f32.to_le_bytes()); // ra
+            data.extend_from_slice(&0f32.to_le_bytes()); // dec
+            data.extend_from_slice(&(-1.46f32).to_le_bytes()); // vmag
+            data.extend_from_slice(&0.0f32.to_le_bytes()); // bv_color
+            data.extend_from_slice(&2491u32.to_le_bytes()); // id
+            data
+        }
+
+        #[test]
+        fn test_transcode_then_from_archive_round_trips_stars() {
+            let bytes = transcode_legacy_to_archive(&sirius_binary()).unwrap();
+            let archive = ArchivedStarCatalog::from_archive(Box::leak(bytes.into_boxed_slice())).unwrap();
+
+            assert_eq!(archive.len(), 1);
+            assert_eq!(archive.stars()[0].id, 2491);
+        }
+
+        #[test]
+        fn test_from_archive_rejects_garbage_bytes() {
+            let garbage: &'static [u8] = &[0u8; 8];
+            assert!(ArchivedStarCatalog::from_archive(garbage).is_err());
+        }
+
+        #[test]
+        fn test_archived_stars_near_finds_star_at_its_own_direction() {
+            let bytes = transcode_legacy_to_archive(&sirius_binary()).unwrap();
+            let archive = ArchivedStarCatalog::from_archive(Box::leak(bytes.into_boxed_slice())).unwrap();
+
+            let dir = ra_dec_to_cartesian(0.0, 0.0);
+            let nearby: Vec<_> = archive.stars_near(dir, 1.0_f64.to_radians(), 10.0).collect();
+            assert!(nearby.iter().any(|s| s.id == 2491));
+        }
+    }
+}
+
+#[cfg(feature = "rkyv")]
+pub use rkyv_format::{
+    transcode_legacy_to_archive, ArchivableCatalog, ArchivedCatalogRecord, ArchivedStarCatalog,
+    ArchivedStarRecord, CatalogRecord, StarRecord,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bright_stars() {
+        let catalog = StarCatalog::with_bright_stars();
+        assert_eq!(catalog.len(), 50);
+
+        // Sirius should be the first and brightest
+        let sirius = &catalog.stars()[0];
+        assert!(sirius.vmag < -1.0);
+        assert_eq!(sirius.id, 2491);
+    }
+
+    #[test]
+    fn test_magnitude_filter() {
+        let catalog = StarCatalog::with_bright_stars();
+        let bright: Vec<_> = catalog.stars_brighter_than(1.0).collect();
+        // Should have fewer stars than total
+        assert!(bright.len() < catalog.len());
+        // All should be brighter than 1.0
+        assert!(bright.iter().all(|s| s.vmag <= 1.0));
+    }
+
+    #[test]
+    fn test_stars_near_finds_star_at_its_own_direction() {
+        let catalog = StarCatalog::with_bright_stars();
+        let sirius = catalog.stars().iter().find(|s| s.id == 2491).unwrap();
+        let dir = ra_dec_to_cartesian(sirius.ra, sirius.dec);
+
+        let nearby: Vec<_> = catalog.stars_near(dir, 1.0_f64.to_radians(), 10.0).collect();
+        assert!(nearby.iter().any(|s| s.id == 2491));
+    }
+
+    #[test]
+    fn test_stars_near_excludes_far_direction() {
+        let catalog = StarCatalog::with_bright_stars();
+        let sirius = catalog.stars().iter().find(|s| s.id == 2491).unwrap();
+        let antipode_ra = (sirius.ra + PI).rem_euclid(2.0 * PI);
+        let antipode_dec = -sirius.dec;
+        let dir = ra_dec_to_cartesian(antipode_ra, antipode_dec);
+
+        let far: Vec<_> = catalog.stars_near(dir, 0.01_f64.to_radians(), 10.0).collect();
+        assert!(far.is_empty());
+    }
+
+    #[test]
+    fn test_stars_near_respects_magnitude_limit() {
+        let catalog = StarCatalog::with_bright_stars();
+        let sirius = catalog.stars().iter().find(|s| s.id == 2491).unwrap();
+        let dir = ra_dec_to_cartesian(sirius.ra, sirius.dec);
+
+        // A wide cone with an impossibly strict magnitude limit should
+        // exclude even Sirius itself.
+        let nearby: Vec<_> = catalog.stars_near(dir, 90.0_f64.to_radians(), -10.0).collect();
+        assert!(nearby.is_empty());
+    }
+
+    #[test]
+    fn test_stars_near_sees_stars_added_after_first_query() {
+        let mut catalog = StarCatalog::new();
+        let mut data = Vec::new();
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&0f32.to_le_bytes()); // ra
+        data.extend_from_slice(&0f32.to_le_bytes()); // dec
+        data.extend_from_slice(&1.0f32.to_le_bytes()); // vmag
+        data.extend_from_slice(&0.5f32.to_le_bytes()); // bv_color
+        data.extend_from_slice(&999u32.to_le_bytes()); // id
+
+        // Query once with an empty catalog to force the kd-tree to build
+        // (and cache) before the star is added.
+        let dir = ra_dec_to_cartesian(0.0, 0.0);
+        assert!(catalog.stars_near(dir, 1.0_f64.to_radians(), 10.0).next().is_none());
+
+        catalog.extend(&data).unwrap();
+        let nearby: Vec<_> = catalog.stars_near(dir, 1.0_f64.to_radians(), 10.0).collect();
+        assert!(nearby.iter().any(|s| s.id == 999));
+    }
+
+    #[test]
+    fn test_find_resolves_same_star_across_catalogs() {
+        let catalog = StarCatalog::with_bright_stars();
+
+        let by_hr = catalog.find(Catalog::Hr, 2491).expect("Sirius by HR number");
+        let by_hd = catalog.find(Catalog::Hd, 48915).expect("Sirius by HD number");
+        let by_hip = catalog.find(Catalog::Hip, 32349).expect("Sirius by HIP number");
+
+        assert_eq!(by_hr.id, by_hd.id);
+        assert_eq!(by_hr.id, by_hip.id);
+        assert_eq!(by_hr.id, 2491);
+    }
+
+    #[test]
+    fn test_find_returns_none_for_unknown_id() {
+        let catalog = StarCatalog::with_bright_stars();
+        assert!(catalog.find(Catalog::Hr, 999_999).is_none());
+        assert!(catalog.find(Catalog::Gliese, 1).is_none());
+    }
+
+    #[test]
+    fn test_find_sees_cross_ids_added_via_extend() {
+        let mut catalog = StarCatalog::new();
+        let mut data = Vec::new();
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&0f32.to_le_bytes()); // ra
+        data.extend_from_slice(&0f32.to_le_bytes()); // dec
+        data.extend_from_slice(&1.0f32.to_le_bytes()); // vmag
+        data.extend_from_slice(&0.5f32.to_le_bytes()); // bv_color
+        data.extend_from_slice(&999u32.to_le_bytes()); // id
+
+        catalog.extend(&data).unwrap();
+        assert_eq!(catalog.find(Catalog::Hr, 999).unwrap().id, 999);
+    }
+
+    #[test]
+    fn test_merge_add_skips_existing_id() {
+        let mut catalog = StarCatalog::with_bright_stars();
+        let original_len = catalog.len();
+
+        let mut data = Vec::new();
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&0f32.to_le_bytes()); // ra
+        data.extend_from_slice(&0f32.to_le_bytes()); // dec
+        data.extend_from_slice(&9.0f32.to_le_bytes()); // vmag
+        data.extend_from_slice(&0.0f32.to_le_bytes()); // bv_color
+        data.extend_from_slice(&2491u32.to_le_bytes()); // id (Sirius, already present)
+
+        let stats = catalog.merge(&data, Disposition::Add).unwrap();
+        assert_eq!(stats, MergeStats { added: 0, modified: 0, replaced: 0, skipped: 1 });
+        assert_eq!(catalog.len(), original_len);
+        // The pre-existing record is untouched.
+        assert!((catalog.find(Catalog::Hr, 2491).unwrap().vmag - (-1.46)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_merge_modify_updates_non_sentinel_fields_only() {
+        let mut catalog = StarCatalog::with_bright_stars();
+
+        let mut data = Vec::new();
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&0f32.to_le_bytes()); // ra: corrected to 0
+        data.extend_from_slice(&0f32.to_le_bytes()); // dec: corrected to 0
+        data.extend_from_slice(&0.0f32.to_le_bytes()); // vmag: sentinel, leave untouched
+        data.extend_from_slice(&0.1f32.to_le_bytes()); // bv_color: real update
+        data.extend_from_slice(&2491u32.to_le_bytes()); // id (Sirius)
+
+        let stats = catalog.merge(&data, Disposition::Modify).unwrap();
+        assert_eq!(stats, MergeStats { added: 0, modified: 1, replaced: 0, skipped: 0 });
+
+        let sirius = catalog.find(Catalog::Hr, 2491).unwrap();
+        assert_eq!(sirius.ra, 0.0);
+        assert_eq!(sirius.dec, 0.0);
+        assert!((sirius.vmag - (-1.46)).abs() < 1e-6, "sentinel vmag should leave original untouched");
+        assert!((sirius.bv_color - 0.1).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_merge_modify_errors_on_unknown_id() {
+        let mut catalog = StarCatalog::with_bright_stars();
+
+        let mut data = Vec::new();
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&0f32.to_le_bytes());
+        data.extend_from_slice(&0f32.to_le_bytes());
+        data.extend_from_slice(&1.0f32.to_le_bytes());
+        data.extend_from_slice(&0.5f32.to_le_bytes());
+        data.extend_from_slice(&999_999u32.to_le_bytes()); // unknown id
+
+        assert!(catalog.merge(&data, Disposition::Modify).is_err());
+    }
+
+    #[test]
+    fn test_merge_replace_overwrites_existing_record() {
+        let mut catalog = StarCatalog::with_bright_stars();
+
+        let mut data = Vec::new();
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&1.0f32.to_le_bytes()); // ra
+        data.extend_from_slice(&1.0f32.to_le_bytes()); // dec
+        data.extend_from_slice(&5.0f32.to_le_bytes()); // vmag
+        data.extend_from_slice(&1.0f32.to_le_bytes()); // bv_color
+        data.extend_from_slice(&2491u32.to_le_bytes()); // id (Sirius)
+
+        let stats = catalog.merge(&data, Disposition::Replace).unwrap();
+        assert_eq!(stats, MergeStats { added: 0, modified: 0, replaced: 1, skipped: 0 });
+
+        let sirius = catalog.find(Catalog::Hr, 2491).unwrap();
+        assert_eq!(sirius.ra, 1.0);
+        assert_eq!(sirius.dec, 1.0);
+        assert_eq!(sirius.vmag, 5.0);
+        assert_eq!(sirius.bv_color, 1.0);
+    }
+
+    #[test]
+    fn test_merge_replace_inserts_unknown_id() {
+        let mut catalog = StarCatalog::with_bright_stars();
+        let original_len = catalog.len();
+
+        let mut data = Vec::new();
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&0f32.to_le_bytes());
+        data.extend_from_slice(&0f32.to_le_bytes());
+        data.extend_from_slice(&1.0f32.to_le_bytes());
+        data.extend_from_slice(&0.5f32.to_le_bytes());
+        data.extend_from_slice(&999_999u32.to_le_bytes()); // unknown id
+
+        let stats = catalog.merge(&data, Disposition::Replace).unwrap();
+        assert_eq!(stats, MergeStats { added: 1, modified: 0, replaced: 0, skipped: 0 });
+        assert_eq!(catalog.len(), original_len + 1);
+        assert!(catalog.find(Catalog::Hr, 999_999).is_some());
+    }
+
+    #[test]
+    fn test_binary_roundtrip() {
+        let catalog = StarCatalog::with_bright_stars();
+
+        // Serialize to binary
+        let mut data = Vec::new();
+        data.extend_from_slice(&(catalog.len() as u32).to_le_bytes());
+        for star in catalog.stars() {
+            data.extend_from_slice(&(star.ra as f32).to_le_bytes());
+            data.extend_from_slice(&(star.dec as f32).to_le_bytes());
+            data.extend_from_slice(&star.vmag.to_le_bytes());
+            data.extend_from_slice(&star.bv_color.to_le_bytes());
+            data.extend_from_slice(&(star.id as u32).to_le_bytes());
+        }
+
+        // Deserialize
+        let loaded = StarCatalog::from_binary(&data).unwrap();
+        assert_eq!(loaded.len(), catalog.len());
+    }
+
+    #[test]
+    fn test_version1_binary_roundtrip_carries_parallax() {
+        let mut data = Vec::new();
+        data.extend_from_slice(&1u32.to_le_bytes()); // star_count
+        data.push(1); // version tag
+        data.extend_from_slice(&0f32.to_le_bytes()); // ra
+        data.extend_from_slice(&0f32.to_le_bytes()); // dec
+        data.extend_from_slice(&1.0f32.to_le_bytes()); // vmag
+        data.extend_from_slice(&0.5f32.to_le_bytes()); // bv_color
+        data.extend_from_slice(&2491u32.to_le_bytes()); // id
+        data.extend_from_slice(&379.21f32.to_le_bytes()); // parallax_mas
+
+        let loaded = StarCatalog::from_binary(&data).unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert!((loaded.stars()[0].parallax_mas - 379.21).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_legacy_version0_binary_defaults_parallax_to_unknown() {
+        let mut data = Vec::new();
+        data.extend_from_slice(&1u32.to_le_bytes()); // star_count, no version tag
+        data.extend_from_slice(&0f32.to_le_bytes()); // ra
+        data.extend_from_slice(&0f32.to_le_bytes()); // dec
+        data.extend_from_slice(&1.0f32.to_le_bytes()); // vmag
+        data.extend_from_slice(&0.5f32.to_le_bytes()); // bv_color
+        data.extend_from_slice(&1u32.to_le_bytes()); // id
+
+        let loaded = StarCatalog::from_binary(&data).unwrap();
+        assert_eq!(loaded.stars()[0].parallax_mas, 0.0);
+        assert!(loaded.stars()[0].distance_ly().is_none());
+    }
+
+    #[test]
+    fn test_distance_ly_and_position_ly_for_known_parallax() {
+        let catalog = StarCatalog::with_bright_stars();
+        let sirius = catalog.stars().iter().find(|s| s.id == 2491).unwrap();
+
+        let distance_ly = sirius.distance_ly().expect("Sirius has a known parallax");
+        // Sirius is about 8.6 ly away.
+        assert!((distance_ly - 8.6).abs() < 0.2, "got {distance_ly} ly");
+
+        let position_ly = sirius.position_ly().expect("Sirius has a known parallax");
+        let direction = sirius.direction();
+        assert!((position_ly.x - direction.x * distance_ly).abs() < 1e-9);
+        assert!((position_ly.y - direction.y * distance_ly).abs() < 1e-9);
+        assert!((position_ly.z - direction.z * distance_ly).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_distance_ly_none_for_unknown_parallax() {
+        let catalog = StarCatalog::with_bright_stars();
+        let canopus = catalog.stars().iter().find(|s| s.id == 2326).unwrap();
+        assert!(canopus.distance_ly().is_none());
+        assert!(canopus.position_ly().is_none());
+    }
+
+    #[test]
+    fn test_color_temperature_for_sun_like_bv() {
+        // The Sun's B-V is about 0.65; the Ballesteros formula should put
+        // its effective temperature near the accepted ~5770 K.
+        let star = Star { ra: 0.0, dec: 0.0, vmag: 0.0, bv_color: 0.65, id: 1, cross_ids: Vec::new(), parallax_mas: 0.0, pm_ra_masyr: 0.0, pm_dec_masyr: 0.0, rv_kms: 0.0 };
+        let temp = star.color_temperature_k();
+        assert!((temp - 5770.0).abs() < 300.0, "got {temp} K");
+    }
+
+    #[test]
+    fn test_srgb_color_red_star_is_warm() {
+        // A cool, red star (large positive B-V) should read as strongly red
+        // and have little blue.
+        let star = Star { ra: 0.0, dec: 0.0, vmag: 0.0, bv_color: 1.8, id: 1, cross_ids: Vec::new(), parallax_mas: 0.0, pm_ra_masyr: 0.0, pm_dec_masyr: 0.0, rv_kms: 0.0 };
+        let [r, g, b] = star.srgb_color();
+        assert_eq!(r, 255);
+        assert!(b < g && g < r);
+    }
+
+    #[test]
+    fn test_srgb_color_blue_star_is_cool_toned() {
+        // A hot, blue-white star (negative B-V) should read with blue at
+        // or near max and red attenuated below it.
+        let star = Star { ra: 0.0, dec: 0.0, vmag: 0.0, bv_color: -0.3, id: 1, cross_ids: Vec::new(), parallax_mas: 0.0, pm_ra_masyr: 0.0, pm_dec_masyr: 0.0, rv_kms: 0.0 };
+        let [r, g, b] = star.srgb_color();
+        assert_eq!(b, 255);
+        assert!(r <= g && g <= b);
+    }
+
+    #[test]
+    fn test_srgb_color_channels_always_in_range() {
+        // Every channel must land in u8 range regardless of how extreme
+        // the B-V (and hence blackbody temperature) is.
+        for bv in [-0.5, -0.1, 0.0, 0.5, 1.0, 1.5, 2.0, 5.0] {
+            let star = Star { ra: 0.0, dec: 0.0, vmag: 0.0, bv_color: bv, id: 1, cross_ids: Vec::new(), parallax_mas: 0.0, pm_ra_masyr: 0.0, pm_dec_masyr: 0.0, rv_kms: 0.0 };
+            let colors = star.srgb_color();
+            for channel in colors {
+                assert!((0..=255).contains(&channel));
+            }
+        }
+    }
+
+    #[test]
+    fn test_at_epoch_advances_position_by_proper_motion() {
+        let star = Star {
+            ra: 0.0,
+            dec: 0.0,
+            vmag: 1.0,
+            bv_color: 0.0,
+            id: 1,
+            cross_ids: Vec::new(),
+            parallax_mas: 0.0,
+            pm_ra_masyr: 1000.0,
+            pm_dec_masyr: 500.0,
+            rv_kms: 0.0,
+        };
+
+        let advanced = star.at_epoch(100.0);
+        // At dec = 0, cos(dec) = 1, so pm_ra_masyr isn't rescaled.
+        let expected_dra = 1000.0 * RAD_PER_MAS * 100.0;
+        let expected_ddec = 500.0 * RAD_PER_MAS * 100.0;
+        assert!((advanced.ra - expected_dra).abs() < 1e-12);
+        assert!((advanced.dec - expected_ddec).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_at_epoch_zero_years_is_a_no_op() {
+        let star = Star {
+            ra: 1.23,
+            dec: 0.4,
+            vmag: 1.0,
+            bv_color: 0.0,
+            id: 1,
+            cross_ids: Vec::new(),
+            parallax_mas: 0.0,
+            pm_ra_masyr: 200.0,
+            pm_dec_masyr: -50.0,
+            rv_kms: 0.0,
+        };
+
+        let advanced = star.at_epoch(0.0);
+        assert!((advanced.ra - star.ra).abs() < 1e-12);
+        assert!((advanced.dec - star.dec).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_at_epoch_clamps_declination_near_pole() {
+        let star = Star {
+            ra: 0.1,
+            dec: std::f64::consts::FRAC_PI_2 - 1e-9,
+            vmag: 1.0,
+            bv_color: 0.0,
+            id: 1,
+            cross_ids: Vec::new(),
+            parallax_mas: 0.0,
+            pm_ra_masyr: 5000.0,
+            pm_dec_masyr: 5000.0,
+            rv_kms: 0.0,
+        };
+
+        let advanced = star.at_epoch(1000.0);
+        assert!(advanced.dec <= std::f64::consts::FRAC_PI_2);
+        // Near the pole, cos(dec) degenerates, so RA drift is dropped
+        // rather than blown up.
+        assert_eq!(advanced.ra, star.ra);
+    }
+
+    #[test]
+    fn test_at_epoch_wraps_ra_past_full_circle() {
+        let star = Star {
+            ra: 0.1,
+            dec: 0.0,
+            vmag: 1.0,
+            bv_color: 0.0,
+            id: 1,
+            cross_ids: Vec::new(),
+            parallax_mas: 0.0,
+            pm_ra_masyr: 1.0e9,
+            pm_dec_masyr: 0.0,
+            rv_kms: 0.0,
+        };
+
+        let advanced = star.at_epoch(1000.0);
+        assert!(advanced.ra >= 0.0 && advanced.ra < 2.0 * PI);
+    }
+
+    #[test]
+    fn test_apply_pm_zero_motion_is_a_no_op() {
+        let (ra, dec) = apply_pm(1.0, 0.3, 0.0, 0.0, 0.0, 100.0, 2000.0, 2026.0);
+        assert!((ra - 1.0).abs() < 1e-12);
+        assert!((dec - 0.3).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_apply_pm_matches_small_angle_approximation_over_one_year() {
+        // At dec = 0 over a short span, the rigorous 3D propagation should
+        // reduce to the same small-angle drift as a flat-sky approximation.
+        let (ra, dec) = apply_pm(0.0, 0.0, 1000.0, 0.0, 0.0, 0.0, 2000.0, 2001.0);
+        let expected_dra = 1000.0 * RAD_PER_MAS;
+        assert!((ra - expected_dra).abs() < 1e-9);
+        assert!(dec.abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_apply_pm_wraps_ra_past_full_circle() {
+        let (ra, _dec) = apply_pm(6.28, 0.0, 1.0e9, 0.0, 0.0, 0.0, 2000.0, 3000.0);
+        assert!((0.0..2.0 * PI).contains(&ra));
+    }
+
+    #[test]
+    fn test_apply_pm_nonpositive_parallax_skips_distance_and_rv_terms() {
+        // A huge but meaningless radial velocity shouldn't perturb the
+        // result when parallax marks the star as unknown-distance: the
+        // function should fall back to angular motion only.
+        let (ra_no_rv, dec_no_rv) = apply_pm(1.0, 0.3, 100.0, -50.0, 0.0, 0.0, 2000.0, 2050.0);
+        let (ra_big_rv, dec_big_rv) = apply_pm(1.0, 0.3, 100.0, -50.0, 1.0e6, 0.0, 2000.0, 2050.0);
+        assert!((ra_no_rv - ra_big_rv).abs() < 1e-12);
+        assert!((dec_no_rv - dec_big_rv).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_apply_pm_sirius_forward_propagation_matches_reference() {
+        // HIP 32349 (Sirius): parallax 379.21 mas, proper motion -546.01,
+        // -1223.08 mas/yr, radial velocity -5.50 km/s, propagated from
+        // J1991.25 (Hipparcos epoch) to 2026.0.
+        let (ra, dec) = apply_pm(1.7676, -0.2918, -546.01, -1223.08, -5.50, 379.21, 1991.25, 2026.0);
+        assert!((ra - 1.767_503_938_947_904_8).abs() < 1e-9);
+        assert!((dec - (-0.292_006_069_655_802_4)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_propagated_catalog_matches_per_star_at_epoch() {
+        let catalog = StarCatalog::with_bright_stars();
+        let propagated = catalog.propagated(25.0);
+
+        let sirius = catalog.find(Catalog::Hr, 2491).unwrap();
+        let expected = sirius.at_epoch(25.0);
+        let advanced_sirius = propagated.find(Catalog::Hr, 2491).unwrap();
+
+        assert!((advanced_sirius.ra - expected.ra).abs() < 1e-12);
+        assert!((advanced_sirius.dec - expected.dec).abs() < 1e-12);
+        assert_eq!(propagated.len(), catalog.len());
+    }
+
+    #[test]
+    fn test_indexed_catalog_roundtrip_preserves_all_stars() {
+        let catalog = StarCatalog::with_bright_stars();
+        let (data, index) = write_indexed_catalog(catalog.stars());
+
+        let indexed = IndexedStarCatalog::open(&data, &index).unwrap();
+        assert_eq!(indexed.len(), catalog.len());
+
+        let mut loaded_ids: Vec<u64> = indexed.all_stars().iter().map(|s| s.id).collect();
+        let mut original_ids: Vec<u64> = catalog.stars().iter().map(|s| s.id).collect();
+        loaded_ids.sort_unstable();
+        original_ids.sort_unstable();
+        assert_eq!(loaded_ids, original_ids);
+    }
+
+    #[test]
+    fn test_indexed_catalog_scaled_integers_preserve_precision() {
+        let catalog = StarCatalog::with_bright_stars();
+        let (data, index) = write_indexed_catalog(catalog.stars());
+        let indexed = IndexedStarCatalog::open(&data, &index).unwrap();
+
+        let sirius = catalog.stars().iter().find(|s| s.id == 2491).unwrap();
+        let decoded = indexed
+            .all_stars()
+            .into_iter()
+            .find(|s| s.id == 2491)
+            .unwrap();
+
+        assert!((decoded.ra - sirius.ra).abs() < 1e-6);
+        assert!((decoded.dec - sirius.dec).abs() < 1e-6);
+        assert!((decoded.vmag - sirius.vmag).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_load_region_finds_star_near_query_point_and_excludes_far_ones() {
+        let catalog = StarCatalog::with_bright_stars();
+        let (data, index) = write_indexed_catalog(catalog.stars());
+        let indexed = IndexedStarCatalog::open(&data, &index).unwrap();
+
+        let sirius = catalog.stars().iter().find(|s| s.id == 2491).unwrap();
+        let nearby = indexed.load_region(sirius.ra, sirius.dec, 1.0_f64.to_radians());
+        assert!(nearby.iter().any(|s| s.id == 2491));
+
+        // A tiny search radius directly opposite Sirius on the sky should
+        // come back empty.
+        let antipode_ra = (sirius.ra + PI).rem_euclid(2.0 * PI);
+        let antipode_dec = -sirius.dec;
+        let far = indexed.load_region(antipode_ra, antipode_dec, 0.01_f64.to_radians());
+        assert!(far.is_empty());
+    }
+
+    #[test]
+    fn test_sky_cell_id_is_stable_and_in_range() {
+        let total_cells: u32 = (0..INDEX_DEC_BANDS).map(ra_sectors_for_band).sum();
+        for band in 0..INDEX_DEC_BANDS {
+            for sector in 0..ra_sectors_for_band(band) {
+                let (ra_lo, ra_hi) = sector_ra_bounds(band, sector);
+                let (dec_lo, dec_hi) = band_dec_bounds(band);
+                let ra = (ra_lo + ra_hi) / 2.0;
+                let dec = (dec_lo + dec_hi) / 2.0;
+                let cell = sky_cell_id(ra, dec);
+                assert!(cell < total_cells);
+                assert_eq!(cell_band_sector(cell), (band, sector));
+            }
+        }
+    }
+
+    #[test]
+    fn test_versioned_catalog_roundtrip_preserves_all_fields() {
+        let catalog = StarCatalog::with_bright_stars();
+        let data = write_versioned_catalog(catalog.stars(), SourceCatalog::Bsc, 2000.0);
+
+        let loaded = load_versioned_catalog(&data).unwrap();
+        assert_eq!(loaded.source, SourceCatalog::Bsc);
+        assert_eq!(loaded.epoch_year, 2000.0);
+        assert_eq!(loaded.stars.len(), catalog.len());
+
+        let sirius = catalog.stars().iter().find(|s| s.id == 2491).unwrap();
+        let decoded = loaded.stars.iter().find(|s| s.id == 2491).unwrap();
+        assert!((decoded.ra - sirius.ra).abs() < 1e-6);
+        assert!((decoded.dec - sirius.dec).abs() < 1e-6);
+        assert!((decoded.parallax_mas - sirius.parallax_mas).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_versioned_catalog_omits_proper_motion_when_all_zero() {
+        // `with_bright_stars` populates proper motion only for Sirius; with
+        // that cleared, no star has proper motion, so the proper-motion flag
+        // (and its bytes) shouldn't be written at all.
+        let catalog = StarCatalog::with_bright_stars();
+        let stars: Vec<Star> = catalog
+            .stars()
+            .iter()
+            .cloned()
+            .map(|mut s| {
+                s.pm_ra_masyr = 0.0;
+                s.pm_dec_masyr = 0.0;
+                s
+            })
+            .collect();
+        let data = write_versioned_catalog(&stars, SourceCatalog::Bsc, 2000.0);
+        let flags = u16::from_le_bytes([data[10], data[11]]);
+        assert_eq!(flags & FLAG_PROPER_MOTION, 0);
+        assert_ne!(flags & FLAG_PARALLAX, 0);
+    }
+
+    #[test]
+    fn test_load_versioned_catalog_rejects_bad_magic() {
+        let data = vec![0u8; VERSIONED_HEADER_SIZE + 4];
+        assert_eq!(load_versioned_catalog(&data).unwrap_err(), CatalogHeaderError::BadMagic);
+    }
+
+    #[test]
+    fn test_load_versioned_catalog_rejects_unsupported_version() {
+        let mut data = Vec::new();
+        data.extend_from_slice(HEADER_MAGIC);
+        data.extend_from_slice(&99u16.to_le_bytes()); // version
+        data.extend_from_slice(&0u16.to_le_bytes()); // flags
+        data.push(SourceCatalog::Bsc.to_u8());
+        data.extend_from_slice(&2000.0f32.to_le_bytes()); // epoch_year
+        data.extend_from_slice(&0u32.to_le_bytes()); // star_count
+        data.extend_from_slice(&crc32(&[]).to_le_bytes());
+
+        assert_eq!(
+            load_versioned_catalog(&data).unwrap_err(),
+            CatalogHeaderError::UnsupportedVersion(99)
+        );
+    }
+
+    #[test]
+    fn test_load_versioned_catalog_rejects_truncated_data() {
+        let catalog = StarCatalog::with_bright_stars();
+        let data = write_versioned_catalog(catalog.stars(), SourceCatalog::Bsc, 2000.0);
+        let truncated = &data[..data.len() - 10];
+        assert_eq!(load_versioned_catalog(truncated).unwrap_err(), CatalogHeaderError::Truncated);
+    }
+
+    #[test]
+    fn test_load_versioned_catalog_rejects_corrupted_record_bytes() {
+        let catalog = StarCatalog::with_bright_stars();
+        let mut data = write_versioned_catalog(catalog.stars(), SourceCatalog::Bsc, 2000.0);
+        let flip = VERSIONED_HEADER_SIZE + 3; // inside the first record, not the CRC trailer
+        data[flip] ^= 0xFF;
+        assert_eq!(
+            load_versioned_catalog(&data).unwrap_err(),
+            CatalogHeaderError::ChecksumMismatch
+        );
+    }
+
+    #[cfg(feature = "xz")]
+    #[test]
+    fn test_versioned_catalog_compressed_roundtrip() {
+        let catalog = StarCatalog::with_bright_stars();
+        let data = write_versioned_catalog_compressed(catalog.stars(), SourceCatalog::Hipparcos, 1991.25);
+
+        // The record block really is xz-compressed.
+        assert!(data[VERSIONED_HEADER_SIZE..].starts_with(&XZ_MAGIC));
+
+        let loaded = load_versioned_catalog(&data).unwrap();
+        assert_eq!(loaded.source, SourceCatalog::Hipparcos);
+        assert_eq!(loaded.stars.len(), catalog.len());
+
+        let sirius = catalog.stars().iter().find(|s| s.id == 2491).unwrap();
+        let decoded = loaded.stars.iter().find(|s| s.id == 2491).unwrap();
+        assert!((decoded.ra - sirius.ra).abs() < 1e-6);
+    }
+
+    #[cfg(not(feature = "xz"))]
+    #[test]
+    fn test_load_versioned_catalog_rejects_compressed_data_without_xz_feature() {
+        let catalog = StarCatalog::with_bright_stars();
+        let (_, records) = build_versioned_records(catalog.stars());
+        let mut data = versioned_header_bytes(catalog.len(), SourceCatalog::Bsc, 2000.0, 0);
+        data.extend_from_slice(&XZ_MAGIC);
+        data.extend_from_slice(&crc32(&records).to_le_bytes());
+
+        assert_eq!(
+            load_versioned_catalog(&data).unwrap_err(),
+            CatalogHeaderError::XzFeatureDisabled
+        );
     }
 }
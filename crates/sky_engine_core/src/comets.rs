@@ -30,14 +30,38 @@ pub struct CometElements {
     pub arg_perihelion_rad: f64,
     /// Julian Date of perihelion passage
     pub perihelion_jd: f64,
-    /// Absolute magnitude (H)
+    /// Total (whole-coma) absolute magnitude, M1 in JPL/MPC notation (H)
     pub abs_magnitude: f64,
-    /// Magnitude slope parameter (typically 2.5-10 for comets)
+    /// Total magnitude slope parameter, K1 in JPL/MPC notation (typically
+    /// 2.5-10 for comets)
     pub magnitude_slope: f64,
+    /// Nuclear (bare-nucleus) absolute magnitude, M2 in JPL/MPC notation.
+    /// Fainter than `abs_magnitude` since it excludes the coma.
+    pub nuclear_abs_magnitude: f64,
+    /// Nuclear magnitude slope parameter, K2 in JPL/MPC notation.
+    pub nuclear_magnitude_slope: f64,
+    /// Marsden-Sekanina non-gravitational radial acceleration parameter, in
+    /// AU/day^2, scaled by the sublimation function g(r). Zero for a purely
+    /// gravitational two-body orbit.
+    pub a1: f64,
+    /// Non-gravitational transverse (in-plane, along the direction of
+    /// motion) acceleration parameter, in AU/day^2.
+    pub a2: f64,
+    /// Non-gravitational normal (out-of-plane, along the orbit's angular
+    /// momentum vector) acceleration parameter, in AU/day^2.
+    pub a3: f64,
+    /// Julian Date at which these elements are osculating/valid, if known.
+    /// Comet elements drift between apparitions (perturbations, outgassing,
+    /// fresh astrometry), so an element set fit years away from the
+    /// requested time should be treated as a rough extrapolation -- see
+    /// [`CometPosition::elements_stale`]. `None` for the bundled [`Comet`]
+    /// definitions, which are kept close to their next perihelion.
+    pub epoch_jd: Option<f64>,
 }
 
 impl CometElements {
     /// Create comet elements from degrees (convenience constructor).
+    #[allow(clippy::too_many_arguments)]
     pub const fn from_degrees(
         name: &'static str,
         perihelion_distance_au: f64,
@@ -48,6 +72,8 @@ impl CometElements {
         perihelion_jd: f64,
         abs_magnitude: f64,
         magnitude_slope: f64,
+        nuclear_abs_magnitude: f64,
+        nuclear_magnitude_slope: f64,
     ) -> Self {
         let deg_to_rad = PI / 180.0;
         Self {
@@ -60,9 +86,37 @@ impl CometElements {
             perihelion_jd,
             abs_magnitude,
             magnitude_slope,
+            nuclear_abs_magnitude,
+            nuclear_magnitude_slope,
+            a1: 0.0,
+            a2: 0.0,
+            a3: 0.0,
+            epoch_jd: None,
         }
     }
 
+    /// Attach Marsden-Sekanina non-gravitational parameters (radial,
+    /// transverse, normal accelerations in AU/day^2, applied through the
+    /// water-ice sublimation scaling g(r) -- see `compute_heliocentric_ecliptic_comet`)
+    /// to these elements. Chain onto [`Self::from_degrees`] or
+    /// [`Self::from_mpc_elements`]; comets that never call this keep all
+    /// three at zero and propagate exactly as before.
+    pub const fn with_nongrav_params(mut self, a1: f64, a2: f64, a3: f64) -> Self {
+        self.a1 = a1;
+        self.a2 = a2;
+        self.a3 = a3;
+        self
+    }
+
+    /// Record the Julian Date at which these elements are osculating, so
+    /// [`compute_comet_position`] and friends can flag a prediction as
+    /// extrapolating stale elements. Chain onto [`Self::from_degrees`] or
+    /// [`Self::from_mpc_elements`].
+    pub const fn with_epoch(mut self, epoch_jd: f64) -> Self {
+        self.epoch_jd = Some(epoch_jd);
+        self
+    }
+
     /// Compute semi-major axis for elliptical orbits (a = q / (1 - e)).
     /// Returns None for parabolic/hyperbolic orbits.
     pub fn semi_major_axis(&self) -> Option<f64> {
@@ -81,6 +135,264 @@ impl CometElements {
             2.0 * PI * (a.powi(3) / K_SQUARED).sqrt()
         })
     }
+
+    /// Compute aphelion distance for elliptical orbits (Q = a * (1 + e)).
+    /// Returns None for parabolic/hyperbolic orbits, which never return.
+    pub fn aphelion_distance_au(&self) -> Option<f64> {
+        self.semi_major_axis().map(|a| a * (1.0 + self.eccentricity))
+    }
+
+    /// Compute semi-minor axis for elliptical orbits (b = a * sqrt(1 - e²)).
+    /// Returns None for parabolic/hyperbolic orbits.
+    pub fn semi_minor_axis_au(&self) -> Option<f64> {
+        self.semi_major_axis()
+            .map(|a| a * (1.0 - self.eccentricity * self.eccentricity).sqrt())
+    }
+
+    /// Compute mean motion for elliptical orbits (n = k / a^(3/2), radians
+    /// per day). Returns None for parabolic/hyperbolic orbits, which have
+    /// no periodic mean anomaly.
+    pub fn mean_motion_rad_per_day(&self) -> Option<f64> {
+        self.semi_major_axis()
+            .map(|a| K_SQUARED.sqrt() / a.powf(1.5))
+    }
+
+    /// Unit vector, in the ecliptic J2000 frame, pointing from the Sun
+    /// toward the point of perihelion. Lets callers plot the orbit's
+    /// orientation in space without re-deriving the Ω/ω/i rotation matrix
+    /// themselves.
+    pub fn perihelion_ecliptic_direction(&self) -> CartesianCoord {
+        let (x, y, z) = orbital_plane_to_ecliptic(self, 1.0, 0.0);
+        CartesianCoord::new(x, y, z)
+    }
+
+    /// Build elements from an MPC/JPL-style osculating element set supplied
+    /// at runtime -- e.g. a freshly discovered comet -- rather than one of
+    /// the `pub const` definitions above. `name` is leaked to a `&'static
+    /// str` so a runtime-ingested comet can share this same representation;
+    /// that's fine for the handful of objects a caller registers this way,
+    /// but don't call this in a hot loop.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_mpc_elements(
+        name: String,
+        perihelion_distance_au: f64,
+        eccentricity: f64,
+        inclination_deg: f64,
+        ascending_node_deg: f64,
+        arg_perihelion_deg: f64,
+        perihelion_jd: f64,
+        abs_magnitude: f64,
+        magnitude_slope: f64,
+        nuclear_abs_magnitude: f64,
+        nuclear_magnitude_slope: f64,
+    ) -> Self {
+        Self::from_degrees(
+            Box::leak(name.into_boxed_str()),
+            perihelion_distance_au,
+            eccentricity,
+            inclination_deg,
+            ascending_node_deg,
+            arg_perihelion_deg,
+            perihelion_jd,
+            abs_magnitude,
+            magnitude_slope,
+            nuclear_abs_magnitude,
+            nuclear_magnitude_slope,
+        )
+    }
+
+    /// Parse a single line of either the standard one-line MPC cometary
+    /// element export (fixed-width columns, documented at
+    /// minorplanetcenter.net's "Format for Cometary Orbit Elements") or a
+    /// comma-separated JPL Small-Body Database element export
+    /// (`full_name,e,q,i,om,w,tp,H,G,M1,K1,M2,K2`) -- the two are told apart
+    /// by whether the line contains a comma. Returns an owned element set
+    /// built through [`Self::from_mpc_elements`], so the same `Box::leak`
+    /// tradeoff applies: fine for a handful of newly-discovered comets, not
+    /// for a hot loop. Feed the result into
+    /// [`compute_comet_position_from_elements`] to get a position.
+    pub fn from_mpc_line(line: &str) -> Result<CometElements, &'static str> {
+        if line.contains(',') {
+            Self::from_jpl_csv_line(line)
+        } else {
+            Self::from_mpc_fixed_width_line(line)
+        }
+    }
+
+    /// Parse a comma-separated JPL Small-Body Database element export line.
+    /// Expects exactly this column order: designation, eccentricity,
+    /// perihelion distance (AU), inclination (deg), longitude of ascending
+    /// node (deg), argument of perihelion (deg), time of perihelion
+    /// passage (JD, TDB), total absolute magnitude (M1), total magnitude
+    /// slope (K1), M1 repeated (some exports duplicate it; ignored here),
+    /// K1 repeated (ignored), nuclear absolute magnitude (M2), nuclear
+    /// magnitude slope (K2).
+    fn from_jpl_csv_line(line: &str) -> Result<CometElements, &'static str> {
+        let fields: Vec<&str> = line.trim().split(',').map(str::trim).collect();
+        if fields.len() < 13 {
+            return Err("JPL element line must have at least 13 comma-separated fields");
+        }
+
+        let name = fields[0].to_string();
+        let eccentricity: f64 = fields[1].parse().map_err(|_| "invalid eccentricity field")?;
+        let perihelion_distance_au: f64 =
+            fields[2].parse().map_err(|_| "invalid perihelion distance field")?;
+        let inclination_deg: f64 = fields[3].parse().map_err(|_| "invalid inclination field")?;
+        let ascending_node_deg: f64 =
+            fields[4].parse().map_err(|_| "invalid ascending node field")?;
+        let arg_perihelion_deg: f64 =
+            fields[5].parse().map_err(|_| "invalid argument of perihelion field")?;
+        let perihelion_jd: f64 = fields[6].parse().map_err(|_| "invalid perihelion time field")?;
+        let abs_magnitude: f64 = fields[7].parse().map_err(|_| "invalid absolute magnitude field")?;
+        let magnitude_slope: f64 = fields[8].parse().map_err(|_| "invalid magnitude slope field")?;
+        let nuclear_abs_magnitude: f64 =
+            fields[11].parse().map_err(|_| "invalid nuclear magnitude field")?;
+        let nuclear_magnitude_slope: f64 =
+            fields[12].parse().map_err(|_| "invalid nuclear magnitude slope field")?;
+
+        Ok(Self::from_mpc_elements(
+            name,
+            perihelion_distance_au,
+            eccentricity,
+            inclination_deg,
+            ascending_node_deg,
+            arg_perihelion_deg,
+            perihelion_jd,
+            abs_magnitude,
+            magnitude_slope,
+            nuclear_abs_magnitude,
+            nuclear_magnitude_slope,
+        ))
+    }
+
+    /// Parse a fixed-width MPC cometary-element line.
+    fn from_mpc_fixed_width_line(line: &str) -> Result<CometElements, &'static str> {
+        if !line.is_ascii() {
+            return Err("MPC element line must be ASCII");
+        }
+
+        // 1-indexed, inclusive column ranges, matching the documented
+        // format; short or missing trailing columns just read as empty.
+        let field = |start: usize, end: usize| -> String {
+            let len = line.len();
+            if start > len {
+                return String::new();
+            }
+            line[start - 1..end.min(len)].trim().to_string()
+        };
+
+        let designation_packed = field(6, 12);
+        let year: i32 = field(15, 18).parse().map_err(|_| "invalid perihelion year field")?;
+        let month: u8 = field(20, 21).parse().map_err(|_| "invalid perihelion month field")?;
+        let day_frac: f64 = field(23, 29).parse().map_err(|_| "invalid perihelion day field")?;
+        let perihelion_distance_au: f64 =
+            field(31, 39).parse().map_err(|_| "invalid perihelion distance field")?;
+        let eccentricity: f64 = field(42, 49).parse().map_err(|_| "invalid eccentricity field")?;
+        let arg_perihelion_deg: f64 =
+            field(52, 59).parse().map_err(|_| "invalid argument of perihelion field")?;
+        let ascending_node_deg: f64 =
+            field(62, 69).parse().map_err(|_| "invalid ascending node field")?;
+        let inclination_deg: f64 = field(72, 79).parse().map_err(|_| "invalid inclination field")?;
+        let abs_magnitude: f64 = field(92, 95).parse().unwrap_or(0.0);
+        let magnitude_slope: f64 = field(97, 100).parse().unwrap_or(0.0);
+
+        let name = if designation_packed.is_empty() {
+            field(103, 158)
+        } else {
+            decode_packed_designation(&designation_packed).unwrap_or(designation_packed)
+        };
+
+        let day = day_frac.floor();
+        let day_seconds = (day_frac - day).clamp(0.0, 1.0) * 86_400.0;
+        let hour = (day_seconds / 3600.0) as u8;
+        let minute = ((day_seconds - hour as f64 * 3600.0) / 60.0) as u8;
+        let second = day_seconds - hour as f64 * 3600.0 - minute as f64 * 60.0;
+        let perihelion_jd =
+            SkyTime::from_utc(year, month, day as u8, hour, minute, second).julian_date_tdb();
+
+        // The MPC comet format doesn't carry a separate nuclear-magnitude
+        // coefficient pair (that's JPL-specific M2/K2); fall back to the
+        // total-magnitude coefficients so `nuclear_magnitude` degrades to
+        // the total estimate instead of silently reading as zero.
+        Ok(Self::from_mpc_elements(
+            name,
+            perihelion_distance_au,
+            eccentricity,
+            inclination_deg,
+            ascending_node_deg,
+            arg_perihelion_deg,
+            perihelion_jd,
+            abs_magnitude,
+            magnitude_slope,
+            abs_magnitude,
+            magnitude_slope,
+        ))
+    }
+}
+
+/// Decode a packed provisional-designation fragment (the cycle-count
+/// portion following the half-month letter) into its numeric value. Each
+/// character accumulates into the running total left to right: a digit
+/// contributes its face value in base 10, while a letter A-Z (except I,
+/// which is skipped throughout MPC packed designations) contributes 1-25 in
+/// base 25, i.e. `n = n * base + value`.
+fn decode_packed_fragment(fragment: &str) -> Result<u32, &'static str> {
+    let mut n: u32 = 0;
+    for c in fragment.chars() {
+        if let Some(d) = c.to_digit(10) {
+            n = n * 10 + d;
+        } else {
+            let upper = c.to_ascii_uppercase();
+            if !upper.is_ascii_uppercase() || upper == 'I' {
+                return Err("packed designation fragment has an invalid character");
+            }
+            let index = upper as u32 - 'A' as u32;
+            let value = if index < 8 { index + 1 } else { index };
+            n = n * 25 + value;
+        }
+    }
+    Ok(n)
+}
+
+/// Decode a 7-character packed provisional designation (e.g. `"J95O010"`)
+/// into its unpacked form (e.g. `"1995 O10"`): a century letter (I/J/K =
+/// 18xx/19xx/20xx), a 2-digit year within that century, a half-month letter
+/// (A-Y, skipping I -- one per half-month of the year), and a trailing
+/// cycle-count fragment decoded by [`decode_packed_fragment`].
+fn decode_packed_designation(packed: &str) -> Result<String, &'static str> {
+    let packed = packed.trim();
+    let chars: Vec<char> = packed.chars().collect();
+    if chars.len() < 4 {
+        return Err("packed designation is too short");
+    }
+
+    let century = match chars[0] {
+        'I' => 1800,
+        'J' => 1900,
+        'K' => 2000,
+        _ => return Err("packed designation has an unrecognized century letter"),
+    };
+    let year_in_century: i32 = packed[1..3]
+        .parse()
+        .map_err(|_| "packed designation has a non-numeric year")?;
+    let year = century + year_in_century;
+
+    let half_month = chars[3].to_ascii_uppercase();
+    if !half_month.is_ascii_uppercase() || half_month == 'I' {
+        return Err("packed designation has an invalid half-month letter");
+    }
+
+    let cycle = if chars.len() > 4 {
+        decode_packed_fragment(&packed[4..])?
+    } else {
+        0
+    };
+
+    if cycle == 0 {
+        Ok(format!("{year} {half_month}"))
+    } else {
+        Ok(format!("{year} {half_month}{cycle}"))
+    }
 }
 
 // =============================================================================
@@ -100,6 +412,8 @@ pub const HALLEY: CometElements = CometElements::from_degrees(
     2446470.5,          // Perihelion JD: Feb 9, 1986
     5.5,                // Absolute magnitude
     4.0,                // Magnitude slope
+    9.0,                // Nuclear absolute magnitude
+    15.0,               // Nuclear magnitude slope
 );
 
 /// 2P/Encke - Shortest period comet (3.3 years)
@@ -114,6 +428,8 @@ pub const ENCKE: CometElements = CometElements::from_degrees(
     2460229.5,          // Perihelion JD: Oct 22, 2023
     11.0,               // Absolute magnitude (faint)
     10.0,               // Magnitude slope
+    14.8,               // Nuclear absolute magnitude
+    10.0,               // Nuclear magnitude slope
 );
 
 /// 67P/Churyumov-Gerasimenko - Rosetta mission target
@@ -128,6 +444,8 @@ pub const CHURYUMOV_GERASIMENKO: CometElements = CometElements::from_degrees(
     2460585.5,          // Perihelion JD: Nov 2, 2028
     11.3,               // Absolute magnitude
     8.0,                // Magnitude slope
+    15.3,               // Nuclear absolute magnitude
+    10.0,               // Nuclear magnitude slope
 );
 
 /// 46P/Wirtanen - Close approach comet, small but active
@@ -142,6 +460,8 @@ pub const WIRTANEN: CometElements = CometElements::from_degrees(
     2460405.5,          // Perihelion JD: April 27, 2029
     6.8,                // Absolute magnitude
     6.0,                // Magnitude slope
+    16.0,               // Nuclear absolute magnitude
+    10.0,               // Nuclear magnitude slope
 );
 
 // =============================================================================
@@ -160,6 +480,8 @@ pub const NEOWISE: CometElements = CometElements::from_degrees(
     2459034.18,         // Perihelion JD: July 3, 2020
     6.5,                // Absolute magnitude (bright!)
     4.5,                // Magnitude slope
+    13.0,               // Nuclear absolute magnitude
+    10.0,               // Nuclear magnitude slope
 );
 
 /// C/2023 A3 (Tsuchinshan-ATLAS) - Great comet of 2024
@@ -174,6 +496,8 @@ pub const TSUCHINSHAN_ATLAS: CometElements = CometElements::from_degrees(
     2460585.3,          // Perihelion JD: Sept 27, 2024
     4.5,                // Absolute magnitude (very bright!)
     4.0,                // Magnitude slope
+    12.0,               // Nuclear absolute magnitude
+    10.0,               // Nuclear magnitude slope
 );
 
 /// C/1995 O1 (Hale-Bopp) - Great comet of 1997
@@ -188,6 +512,8 @@ pub const HALE_BOPP: CometElements = CometElements::from_degrees(
     2450538.9,          // Perihelion JD: April 1, 1997
     -0.8,               // Absolute magnitude (extremely bright!)
     4.0,                // Magnitude slope
+    6.0,                // Nuclear absolute magnitude (huge ~60km nucleus)
+    10.0,               // Nuclear magnitude slope
 );
 
 /// Comet identifier
@@ -242,8 +568,17 @@ pub struct CometPosition {
     pub distance_km: f64,
     /// Distance from Sun in km
     pub helio_distance_km: f64,
-    /// Estimated visual magnitude
+    /// Estimated total (whole-coma) visual magnitude
     pub magnitude: f64,
+    /// Estimated nuclear (bare-nucleus) visual magnitude -- fainter than
+    /// `magnitude`, and the only one that matters once the coma has
+    /// dissipated or for a comet too far out to have developed one
+    pub nuclear_magnitude: f64,
+    /// `true` when the comet's [`CometElements::epoch_jd`] is set and more
+    /// than [`ELEMENT_STALENESS_THRESHOLD_DAYS`] away from the requested
+    /// time -- a sign the prediction is extrapolating elements fit to a
+    /// different apparition and may have drifted from reality.
+    pub elements_stale: bool,
 }
 
 // =============================================================================
@@ -330,9 +665,176 @@ fn solve_kepler_hyperbolic(mean_anomaly: f64, eccentricity: f64) -> f64 {
     h
 }
 
+/// Rotate a position in the orbital plane -- `x_orbit` along the
+/// eccentricity vector toward perihelion, `y_orbit` 90 degrees ahead in the
+/// direction of motion -- into ecliptic x/y/z using this orbit's
+/// inclination, ascending node, and argument of perihelion.
+fn orbital_plane_to_ecliptic(elem: &CometElements, x_orbit: f64, y_orbit: f64) -> (f64, f64, f64) {
+    let i = elem.inclination_rad;
+    let omega = elem.ascending_node_rad;
+    let w = elem.arg_perihelion_rad;
+
+    let cos_omega = omega.cos();
+    let sin_omega = omega.sin();
+    let cos_i = i.cos();
+    let sin_i = i.sin();
+    let cos_w = w.cos();
+    let sin_w = w.sin();
+
+    let p1 = cos_omega * cos_w - sin_omega * sin_w * cos_i;
+    let p2 = -cos_omega * sin_w - sin_omega * cos_w * cos_i;
+    let q1 = sin_omega * cos_w + cos_omega * sin_w * cos_i;
+    let q2 = -sin_omega * sin_w + cos_omega * cos_w * cos_i;
+    let r1 = sin_w * sin_i;
+    let r2 = cos_w * sin_i;
+
+    (
+        p1 * x_orbit + p2 * y_orbit,
+        q1 * x_orbit + q2 * y_orbit,
+        r1 * x_orbit + r2 * y_orbit,
+    )
+}
+
+/// Non-gravitational radial/transverse/normal acceleration model for
+/// outgassing comets (Marsden, Sekanina & Yeomans 1973). `g` follows the
+/// standard water-ice sublimation curve, peaking a couple AU from the Sun
+/// where insolation is enough to sublimate ice but the nucleus hasn't yet
+/// exhausted its volatile crust.
+mod nongrav {
+    /// Heliocentric distance (AU) at which `g` is normalized to 1.
+    const R0_AU: f64 = 2.808;
+    const ALPHA: f64 = 0.1113;
+    const M: f64 = 2.15;
+    const N: f64 = 5.093;
+    const K: f64 = 4.6142;
+
+    /// g(r) = alpha * (r/r0)^-m * [1 + (r/r0)^n]^-k
+    pub(super) fn g(r_au: f64) -> f64 {
+        let ratio = r_au / R0_AU;
+        ALPHA * ratio.powf(-M) * (1.0 + ratio.powf(N)).powf(-K)
+    }
+}
+
+fn add3(a: (f64, f64, f64), b: (f64, f64, f64)) -> (f64, f64, f64) {
+    (a.0 + b.0, a.1 + b.1, a.2 + b.2)
+}
+
+fn scale3(a: (f64, f64, f64), s: f64) -> (f64, f64, f64) {
+    (a.0 * s, a.1 * s, a.2 * s)
+}
+
+/// Heliocentric state (position, velocity) a comet with nonzero
+/// [`CometElements::a1`]/`a2`/`a3` occupies at perihelion passage, from the
+/// unperturbed two-body solution: at true anomaly zero it sits at distance
+/// `q` along the argument-of-perihelion direction, moving perpendicular to
+/// that (in the direction of motion) at the vis-viva speed. This seeds the
+/// numerical integration in [`integrate_nongrav_state`].
+fn perihelion_state(elem: &CometElements) -> ((f64, f64, f64), (f64, f64, f64)) {
+    let q = elem.perihelion_distance_au;
+    let e = elem.eccentricity;
+
+    let pos = orbital_plane_to_ecliptic(elem, q, 0.0);
+
+    // Vis-viva: v^2 = k^2 * (2/r - 1/a), and at perihelion r = q while
+    // 1/a = (1 - e)/q, so v^2 = k^2 * (1 + e)/q -- 1/a is positive for
+    // e < 1, zero for e = 1 and negative for e > 1, and the formula holds
+    // in all three cases.
+    let v_peri = (K_SQUARED * (1.0 + e) / q).sqrt();
+    let vel = orbital_plane_to_ecliptic(elem, 0.0, v_peri);
+
+    (pos, vel)
+}
+
+/// Heliocentric acceleration (AU/day^2) on a comet with non-gravitational
+/// parameters, at the given heliocentric state: point-mass gravity plus the
+/// Marsden-Sekanina outgassing terms resolved into the radial/transverse/
+/// normal frame (R x T = N, so T = N x R).
+fn nongrav_acceleration(
+    elem: &CometElements,
+    pos: (f64, f64, f64),
+    vel: (f64, f64, f64),
+) -> (f64, f64, f64) {
+    let r2 = pos.0 * pos.0 + pos.1 * pos.1 + pos.2 * pos.2;
+    let r = r2.sqrt();
+    let r_hat = (pos.0 / r, pos.1 / r, pos.2 / r);
+
+    let h = (
+        pos.1 * vel.2 - pos.2 * vel.1,
+        pos.2 * vel.0 - pos.0 * vel.2,
+        pos.0 * vel.1 - pos.1 * vel.0,
+    );
+    let h_mag = (h.0 * h.0 + h.1 * h.1 + h.2 * h.2).sqrt();
+    let n_hat = (h.0 / h_mag, h.1 / h_mag, h.2 / h_mag);
+
+    let t_hat = (
+        n_hat.1 * r_hat.2 - n_hat.2 * r_hat.1,
+        n_hat.2 * r_hat.0 - n_hat.0 * r_hat.2,
+        n_hat.0 * r_hat.1 - n_hat.1 * r_hat.0,
+    );
+
+    let grav = -K_SQUARED / r2;
+    let g = nongrav::g(r);
+
+    (
+        grav * r_hat.0 + elem.a1 * g * r_hat.0 + elem.a2 * g * t_hat.0 + elem.a3 * g * n_hat.0,
+        grav * r_hat.1 + elem.a1 * g * r_hat.1 + elem.a2 * g * t_hat.1 + elem.a3 * g * n_hat.1,
+        grav * r_hat.2 + elem.a1 * g * r_hat.2 + elem.a2 * g * t_hat.2 + elem.a3 * g * n_hat.2,
+    )
+}
+
+/// Integrate a non-gravitational comet's heliocentric position from
+/// perihelion passage to `jde` with fixed-step RK4 on `(position,
+/// velocity)`. Only used when `elem.a1`/`a2`/`a3` aren't all zero --
+/// otherwise `compute_heliocentric_ecliptic_comet` takes the closed-form
+/// Kepler/Barker path below, which is exact and far cheaper.
+fn integrate_nongrav_state(elem: &CometElements, jde: f64) -> (f64, f64, f64) {
+    const STEP_DAYS: f64 = 0.25;
+
+    let (mut pos, mut vel) = perihelion_state(elem);
+    let mut t = elem.perihelion_jd;
+    let direction = if jde >= t { 1.0 } else { -1.0 };
+
+    while (jde - t) * direction > 1e-9 {
+        let h = direction * STEP_DAYS.min((jde - t) * direction);
+
+        let (k1p, k1v) = (vel, nongrav_acceleration(elem, pos, vel));
+
+        let p2 = add3(pos, scale3(k1p, h / 2.0));
+        let v2 = add3(vel, scale3(k1v, h / 2.0));
+        let (k2p, k2v) = (v2, nongrav_acceleration(elem, p2, v2));
+
+        let p3 = add3(pos, scale3(k2p, h / 2.0));
+        let v3 = add3(vel, scale3(k2v, h / 2.0));
+        let (k3p, k3v) = (v3, nongrav_acceleration(elem, p3, v3));
+
+        let p4 = add3(pos, scale3(k3p, h));
+        let v4 = add3(vel, scale3(k3v, h));
+        let (k4p, k4v) = (v4, nongrav_acceleration(elem, p4, v4));
+
+        let dp = scale3(
+            add3(add3(k1p, scale3(k2p, 2.0)), add3(scale3(k3p, 2.0), k4p)),
+            h / 6.0,
+        );
+        let dv = scale3(
+            add3(add3(k1v, scale3(k2v, 2.0)), add3(scale3(k3v, 2.0), k4v)),
+            h / 6.0,
+        );
+
+        pos = add3(pos, dp);
+        vel = add3(vel, dv);
+        t += h;
+    }
+
+    pos
+}
+
 /// Compute heliocentric position of a comet in ecliptic coordinates.
 /// Returns (x, y, z) in AU, J2000 ecliptic frame.
 fn compute_heliocentric_ecliptic_comet(elem: &CometElements, jde: f64) -> (f64, f64, f64) {
+    if elem.a1 != 0.0 || elem.a2 != 0.0 || elem.a3 != 0.0 {
+        return integrate_nongrav_state(elem, jde);
+    }
+
     let e = elem.eccentricity;
     let q = elem.perihelion_distance_au;
 
@@ -382,36 +884,10 @@ fn compute_heliocentric_ecliptic_comet(elem: &CometElements, jde: f64) -> (f64,
     let x_orbit = r * true_anomaly.cos();
     let y_orbit = r * true_anomaly.sin();
 
-    // Orbital elements
-    let i = elem.inclination_rad;
-    let omega = elem.ascending_node_rad;
-    let w = elem.arg_perihelion_rad;
-
-    // Rotation from orbital plane to ecliptic coordinates
-    let cos_omega = omega.cos();
-    let sin_omega = omega.sin();
-    let cos_i = i.cos();
-    let sin_i = i.sin();
-    let cos_w = w.cos();
-    let sin_w = w.sin();
-
-    // Rotation matrix elements
-    let p1 = cos_omega * cos_w - sin_omega * sin_w * cos_i;
-    let p2 = -cos_omega * sin_w - sin_omega * cos_w * cos_i;
-    let q1 = sin_omega * cos_w + cos_omega * sin_w * cos_i;
-    let q2 = -sin_omega * sin_w + cos_omega * cos_w * cos_i;
-    let r1 = sin_w * sin_i;
-    let r2 = cos_w * sin_i;
-
-    // Ecliptic coordinates (AU)
-    let x_ecl = p1 * x_orbit + p2 * y_orbit;
-    let y_ecl = q1 * x_orbit + q2 * y_orbit;
-    let z_ecl = r1 * x_orbit + r2 * y_orbit;
-
-    (x_ecl, y_ecl, z_ecl)
+    orbital_plane_to_ecliptic(elem, x_orbit, y_orbit)
 }
 
-/// Compute comet magnitude using standard formula:
+/// Compute comet total (whole-coma) magnitude using the standard formula:
 /// m = H + 5*log10(Δ) + K*log10(r)
 /// where Δ = geocentric distance, r = heliocentric distance
 fn compute_comet_magnitude(elem: &CometElements, geo_distance_au: f64, helio_distance_au: f64) -> f64 {
@@ -420,6 +896,14 @@ fn compute_comet_magnitude(elem: &CometElements, geo_distance_au: f64, helio_dis
         + elem.magnitude_slope * helio_distance_au.log10()
 }
 
+/// Compute comet nuclear (bare-nucleus) magnitude, the same formula as
+/// [`compute_comet_magnitude`] but with the M2/K2 coefficient pair.
+fn compute_comet_nuclear_magnitude(elem: &CometElements, geo_distance_au: f64, helio_distance_au: f64) -> f64 {
+    elem.nuclear_abs_magnitude
+        + 5.0 * geo_distance_au.log10()
+        + elem.nuclear_magnitude_slope * helio_distance_au.log10()
+}
+
 /// Compute position of a comet as seen from Earth.
 pub fn compute_comet_position(comet: Comet, time: &SkyTime) -> CometPosition {
     let elem = comet.elements();
@@ -454,6 +938,7 @@ pub fn compute_comet_position(comet: Comet, time: &SkyTime) -> CometPosition {
 
     // Compute magnitude
     let magnitude = compute_comet_magnitude(elem, distance_au, helio_distance_au);
+    let nuclear_magnitude = compute_comet_nuclear_magnitude(elem, distance_au, helio_distance_au);
 
     CometPosition {
         comet,
@@ -461,6 +946,8 @@ pub fn compute_comet_position(comet: Comet, time: &SkyTime) -> CometPosition {
         distance_km,
         helio_distance_km,
         magnitude,
+        nuclear_magnitude,
+        elements_stale: elements_are_stale(elem, jde),
     }
 }
 
@@ -472,6 +959,157 @@ pub fn compute_all_comet_positions(time: &SkyTime) -> Vec<CometPosition> {
         .collect()
 }
 
+/// Light travel time in days per AU (1 / speed of light in AU/day).
+const LIGHT_TIME_DAYS_PER_AU: f64 = 0.0057755;
+
+/// How far a requested time may drift from [`CometElements::epoch_jd`]
+/// before a position is flagged [`CometPosition::elements_stale`] -- roughly
+/// half a year, short enough that outgassing and perturbations between
+/// apparitions haven't had time to meaningfully alter the orbit.
+const ELEMENT_STALENESS_THRESHOLD_DAYS: f64 = 180.0;
+
+/// Whether `jde` has drifted far enough from `elem.epoch_jd` (if set) that
+/// the resulting position should be flagged as extrapolating stale elements.
+fn elements_are_stale(elem: &CometElements, jde: f64) -> bool {
+    elem.epoch_jd
+        .is_some_and(|epoch| (jde - epoch).abs() > ELEMENT_STALENESS_THRESHOLD_DAYS)
+}
+
+/// Evaluate a comet's heliocentric position corrected for light-travel
+/// time: start from its position at `jde`, then repeatedly step the
+/// evaluation epoch back by the resulting geocentric distance (AU) times
+/// [`LIGHT_TIME_DAYS_PER_AU`] and recompute, converging on the position the
+/// comet actually occupied when the light now arriving left it. Earth's
+/// position is always evaluated at `jde`, the time of observation.
+///
+/// Returns `(comet_helio_au, earth_helio_au)`.
+fn light_time_corrected_position(
+    elem: &CometElements,
+    jde: f64,
+    iterations: usize,
+) -> ((f64, f64, f64), (f64, f64, f64)) {
+    let earth_pos = crate::planets::heliocentric_position(Planet::Earth, jde);
+    let mut comet_pos = compute_heliocentric_ecliptic_comet(elem, jde);
+
+    for _ in 0..iterations {
+        let geo_x = comet_pos.0 - earth_pos.0;
+        let geo_y = comet_pos.1 - earth_pos.1;
+        let geo_z = comet_pos.2 - earth_pos.2;
+        let distance_au = (geo_x * geo_x + geo_y * geo_y + geo_z * geo_z).sqrt();
+
+        let jde_corrected = jde - distance_au * LIGHT_TIME_DAYS_PER_AU;
+        comet_pos = compute_heliocentric_ecliptic_comet(elem, jde_corrected);
+    }
+
+    (comet_pos, earth_pos)
+}
+
+/// Compute the apparent (light-time-corrected) position of `comet` as seen
+/// from Earth: its direction and distance as they actually were when the
+/// light now reaching Earth left it, rather than where it is "right now" at
+/// `time` (that's the geometric [`compute_comet_position`]). For fast,
+/// close comets this shifts the apparent direction noticeably more than for
+/// distant ones.
+pub fn compute_comet_position_astrometric(comet: Comet, time: &SkyTime) -> CometPosition {
+    let elem = comet.elements();
+    let jde = time.julian_date_tdb();
+
+    let ((comet_x, comet_y, comet_z), earth_pos) = light_time_corrected_position(elem, jde, 3);
+
+    let geo_x = comet_x - earth_pos.0;
+    let geo_y = comet_y - earth_pos.1;
+    let geo_z = comet_z - earth_pos.2;
+
+    let distance_au = (geo_x * geo_x + geo_y * geo_y + geo_z * geo_z).sqrt();
+    let helio_distance_au = (comet_x * comet_x + comet_y * comet_y + comet_z * comet_z).sqrt();
+
+    let distance_km = distance_au * AU_TO_KM;
+    let helio_distance_km = helio_distance_au * AU_TO_KM;
+
+    let lon = geo_y.atan2(geo_x);
+    let lat = (geo_z / distance_au).asin();
+
+    let obliquity = true_obliquity(jde);
+    let direction = ecliptic_to_equatorial(lon, lat, obliquity).normalize();
+
+    let magnitude = compute_comet_magnitude(elem, distance_au, helio_distance_au);
+    let nuclear_magnitude = compute_comet_nuclear_magnitude(elem, distance_au, helio_distance_au);
+
+    CometPosition {
+        comet,
+        direction,
+        distance_km,
+        helio_distance_km,
+        magnitude,
+        nuclear_magnitude,
+        elements_stale: elements_are_stale(elem, jde),
+    }
+}
+
+/// Result of computing a runtime-ingested comet's position, for an object
+/// built from [`CometElements::from_mpc_elements`] rather than one of the
+/// bundled [`Comet`] variants.
+#[derive(Debug, Clone)]
+pub struct CustomCometPosition {
+    pub name: &'static str,
+    /// Direction from Earth (unit vector in equatorial J2000)
+    pub direction: CartesianCoord,
+    /// Distance from Earth in km
+    pub distance_km: f64,
+    /// Distance from Sun in km
+    pub helio_distance_km: f64,
+    /// Estimated total (whole-coma) visual magnitude
+    pub magnitude: f64,
+    /// Estimated nuclear (bare-nucleus) visual magnitude
+    pub nuclear_magnitude: f64,
+    /// `true` when the supplied [`CometElements::epoch_jd`] is more than
+    /// [`ELEMENT_STALENESS_THRESHOLD_DAYS`] away from the requested time.
+    pub elements_stale: bool,
+}
+
+/// Compute the position of a comet from explicit, runtime-supplied orbital
+/// elements rather than one of the bundled [`Comet`]s.
+///
+/// Applies two light-time iterations: the geocentric distance found at
+/// `time` is used to step the evaluation epoch back by
+/// `distance_au * LIGHT_TIME_DAYS_PER_AU` and the position is recomputed,
+/// twice, converging on the position the comet actually occupied when the
+/// light now arriving left it.
+pub fn compute_comet_position_from_elements(elem: &CometElements, time: &SkyTime) -> CustomCometPosition {
+    let jde = time.julian_date_tdb();
+
+    let ((comet_x, comet_y, comet_z), earth_pos) = light_time_corrected_position(elem, jde, 2);
+
+    let geo_x = comet_x - earth_pos.0;
+    let geo_y = comet_y - earth_pos.1;
+    let geo_z = comet_z - earth_pos.2;
+
+    let distance_au = (geo_x * geo_x + geo_y * geo_y + geo_z * geo_z).sqrt();
+    let helio_distance_au = (comet_x * comet_x + comet_y * comet_y + comet_z * comet_z).sqrt();
+
+    let distance_km = distance_au * AU_TO_KM;
+    let helio_distance_km = helio_distance_au * AU_TO_KM;
+
+    let lon = geo_y.atan2(geo_x);
+    let lat = (geo_z / distance_au).asin();
+
+    let obliquity = true_obliquity(jde);
+    let direction = ecliptic_to_equatorial(lon, lat, obliquity).normalize();
+
+    let magnitude = compute_comet_magnitude(elem, distance_au, helio_distance_au);
+    let nuclear_magnitude = compute_comet_nuclear_magnitude(elem, distance_au, helio_distance_au);
+
+    CustomCometPosition {
+        name: elem.name,
+        direction,
+        distance_km,
+        helio_distance_km,
+        magnitude,
+        nuclear_magnitude,
+        elements_stale: elements_are_stale(elem, jde),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -600,6 +1238,125 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_ingested_elements_match_bundled_comet() {
+        // Halley's own published elements, ingested through the runtime
+        // MPC-style constructor, should reproduce the bundled Comet::Halley
+        // result (modulo the light-time iterations the ingestion path adds).
+        let elem = CometElements::from_mpc_elements(
+            "1P/Halley (ingested)".to_string(),
+            0.586,
+            0.96714,
+            162.26,
+            58.42,
+            111.33,
+            2446470.5,
+            5.5,
+            4.0,
+            9.0,
+            15.0,
+        );
+
+        let time = SkyTime::from_jd(2449400.5); // Feb 17, 1994
+        let pos = compute_comet_position_from_elements(&elem, &time);
+        let bundled = compute_comet_position(Comet::Halley, &time);
+
+        assert_eq!(pos.name, "1P/Halley (ingested)");
+        let helio_au = pos.helio_distance_km / AU_TO_KM;
+        let bundled_helio_au = bundled.helio_distance_km / AU_TO_KM;
+        assert!(
+            (helio_au - bundled_helio_au).abs() < 0.01,
+            "ingested Halley should match bundled Halley: {} vs {}",
+            helio_au, bundled_helio_au
+        );
+    }
+
+    #[test]
+    fn test_ingested_parabolic_and_hyperbolic_elements_are_finite() {
+        let parabolic = CometElements::from_mpc_elements(
+            "C/Test (parabolic)".to_string(),
+            0.5,
+            1.0,
+            45.0,
+            10.0,
+            20.0,
+            2459000.0,
+            6.0,
+            4.0,
+            12.0,
+            10.0,
+        );
+        let hyperbolic = CometElements::from_mpc_elements(
+            "C/Test (hyperbolic)".to_string(),
+            1.0,
+            1.05,
+            139.0,
+            21.0,
+            308.0,
+            2460585.0,
+            4.5,
+            4.0,
+            11.0,
+            10.0,
+        );
+
+        let time = SkyTime::from_utc(2024, 10, 1, 0, 0, 0.0);
+        for elem in [&parabolic, &hyperbolic] {
+            let pos = compute_comet_position_from_elements(elem, &time);
+            assert!(pos.distance_km.is_finite() && pos.distance_km > 0.0);
+            assert!(pos.helio_distance_km.is_finite() && pos.helio_distance_km > 0.0);
+            assert!(pos.magnitude.is_finite());
+        }
+    }
+
+    #[test]
+    fn test_astrometric_position_shifts_direction_for_a_close_fast_comet() {
+        // Wirtanen is close and fast enough that light-time correction
+        // should shift the apparent direction measurably versus the
+        // instantaneous geometric position, while leaving distances sane.
+        let time = SkyTime::from_jd(2460405.5); // Perihelion
+        let geometric = compute_comet_position(Comet::Wirtanen, &time);
+        let astrometric = compute_comet_position_astrometric(Comet::Wirtanen, &time);
+
+        assert!(astrometric.distance_km.is_finite() && astrometric.distance_km > 0.0);
+        let dot = geometric.direction.x * astrometric.direction.x
+            + geometric.direction.y * astrometric.direction.y
+            + geometric.direction.z * astrometric.direction.z;
+        assert!(dot < 1.0, "expected a nonzero direction shift, got dot={dot}");
+    }
+
+    #[test]
+    fn test_astrometric_position_converges_like_the_ingested_elements_path() {
+        // Both light-time-corrected paths should agree closely for the same
+        // orbital elements, modulo the iteration count (2 vs 3) and
+        // Earth-position rounding.
+        let elem = CometElements::from_mpc_elements(
+            "46P/Wirtanen (ingested)".to_string(),
+            1.055,
+            0.6588,
+            11.75,
+            82.16,
+            356.34,
+            2460405.5,
+            6.8,
+            6.0,
+            16.0,
+            10.0,
+        );
+        let time = SkyTime::from_jd(2460405.5);
+
+        let via_elements = compute_comet_position_from_elements(&elem, &time);
+        let via_bundled = compute_comet_position_astrometric(Comet::Wirtanen, &time);
+
+        let helio_au_elements = via_elements.helio_distance_km / AU_TO_KM;
+        let helio_au_bundled = via_bundled.helio_distance_km / AU_TO_KM;
+        assert!(
+            (helio_au_elements - helio_au_bundled).abs() < 0.01,
+            "ingested and bundled astrometric paths should agree: {} vs {}",
+            helio_au_elements, helio_au_bundled
+        );
+    }
+
     #[test]
     fn test_encke_short_period() {
         // Encke has a ~3.3 year period
@@ -612,4 +1369,274 @@ mod tests {
         );
         eprintln!("Encke period: {:.2} years", period_years);
     }
+
+    #[test]
+    fn test_with_nongrav_params_defaults_to_zero() {
+        assert_eq!(HALLEY.a1, 0.0);
+        assert_eq!(HALLEY.a2, 0.0);
+        assert_eq!(HALLEY.a3, 0.0);
+
+        let perturbed = ENCKE.with_nongrav_params(2.0e-10, -1.0e-10, 0.0);
+        assert_eq!(perturbed.a1, 2.0e-10);
+        assert_eq!(perturbed.a2, -1.0e-10);
+        // Only a1/a2/a3 should change -- everything else carries over.
+        assert_eq!(perturbed.perihelion_distance_au, ENCKE.perihelion_distance_au);
+        assert_eq!(perturbed.eccentricity, ENCKE.eccentricity);
+    }
+
+    #[test]
+    fn test_integrate_nongrav_state_at_perihelion_matches_q() {
+        // At jde == perihelion_jd the RK4 integration shouldn't take a
+        // single step, so it should reproduce the unperturbed perihelion
+        // distance exactly (within floating point round-off).
+        let perturbed = ENCKE.with_nongrav_params(1.0e-10, 5.0e-11, 0.0);
+        let (x, y, z) = compute_heliocentric_ecliptic_comet(&perturbed, perturbed.perihelion_jd);
+        let r = (x * x + y * y + z * z).sqrt();
+        assert!(
+            (r - perturbed.perihelion_distance_au).abs() < 1e-9,
+            "expected r == q at perihelion, got {r} vs {}",
+            perturbed.perihelion_distance_au
+        );
+    }
+
+    #[test]
+    fn test_nongrav_acceleration_shifts_position_versus_pure_gravity() {
+        // A non-gravitational push, integrated across a full orbit, should
+        // land the comet somewhere measurably different from the unperturbed
+        // closed-form solution -- this is the whole point of modeling
+        // outgassing thrust (it shifts perihelion timing over apparitions).
+        let period_days = ENCKE.orbital_period_days().unwrap();
+        let jde = ENCKE.perihelion_jd + period_days;
+
+        let unperturbed = compute_heliocentric_ecliptic_comet(&ENCKE, jde);
+        let perturbed_elem = ENCKE.with_nongrav_params(1.0e-8, 5.0e-9, 0.0);
+        let perturbed = compute_heliocentric_ecliptic_comet(&perturbed_elem, jde);
+
+        let dx = perturbed.0 - unperturbed.0;
+        let dy = perturbed.1 - unperturbed.1;
+        let dz = perturbed.2 - unperturbed.2;
+        let shift_au = (dx * dx + dy * dy + dz * dz).sqrt();
+        assert!(
+            shift_au > 1e-6,
+            "expected a measurable non-gravitational shift after one period, got {shift_au} AU"
+        );
+
+        // And it should still land roughly in the same part of the solar
+        // system, not diverge to nonsense.
+        let r_unperturbed = (unperturbed.0 * unperturbed.0
+            + unperturbed.1 * unperturbed.1
+            + unperturbed.2 * unperturbed.2)
+            .sqrt();
+        assert!(shift_au < r_unperturbed, "non-gravitational shift should be a perturbation, not a blowup");
+    }
+
+    #[test]
+    fn test_nuclear_magnitude_is_fainter_than_total_for_bundled_comets() {
+        // The nucleus alone should always be fainter (a larger magnitude
+        // number) than the whole coma near perihelion for every bundled
+        // comet, since the coma only adds reflective surface area.
+        for &comet in Comet::ALL.iter() {
+            let elem = comet.elements();
+            let time = SkyTime::from_jd(elem.perihelion_jd);
+            let pos = compute_comet_position(comet, &time);
+            assert!(
+                pos.nuclear_magnitude > pos.magnitude,
+                "{}: nuclear magnitude {} should be fainter than total magnitude {}",
+                elem.name, pos.nuclear_magnitude, pos.magnitude
+            );
+        }
+    }
+
+    #[test]
+    fn test_nuclear_magnitude_uses_its_own_coefficient_pair() {
+        // Two comets sharing the same geometry (hence same geo/helio
+        // distances) but different M2/K2 should get different nuclear
+        // magnitudes even when their total magnitude is identical.
+        let base = CometElements::from_mpc_elements(
+            "C/Test (nuclear)".to_string(),
+            1.0,
+            0.9,
+            10.0,
+            20.0,
+            30.0,
+            2459000.0,
+            6.0,
+            4.0,
+            11.0,
+            10.0,
+        );
+        let fainter_nucleus = CometElements::from_mpc_elements(
+            "C/Test (fainter nucleus)".to_string(),
+            1.0,
+            0.9,
+            10.0,
+            20.0,
+            30.0,
+            2459000.0,
+            6.0,
+            4.0,
+            15.0,
+            10.0,
+        );
+
+        let time = SkyTime::from_utc(2024, 10, 1, 0, 0, 0.0);
+        let pos_base = compute_comet_position_from_elements(&base, &time);
+        let pos_fainter = compute_comet_position_from_elements(&fainter_nucleus, &time);
+
+        assert_eq!(pos_base.magnitude, pos_fainter.magnitude);
+        assert!(pos_fainter.nuclear_magnitude > pos_base.nuclear_magnitude);
+    }
+
+    #[test]
+    fn test_decode_packed_fragment_digits_and_letters() {
+        assert_eq!(decode_packed_fragment("010").unwrap(), 10);
+        assert_eq!(decode_packed_fragment("").unwrap(), 0);
+        assert_eq!(decode_packed_fragment("A").unwrap(), 1);
+        assert_eq!(decode_packed_fragment("H").unwrap(), 8);
+        assert_eq!(decode_packed_fragment("J").unwrap(), 9);
+        assert_eq!(decode_packed_fragment("Z").unwrap(), 25);
+        assert!(decode_packed_fragment("I").is_err());
+    }
+
+    #[test]
+    fn test_decode_packed_designation_unpacks_century_year_and_cycle() {
+        assert_eq!(decode_packed_designation("J95O010").unwrap(), "1995 O10");
+        assert_eq!(decode_packed_designation("K24X00Q").unwrap(), "2024 X16");
+        assert!(decode_packed_designation("J9").is_err());
+    }
+
+    #[test]
+    fn test_from_mpc_line_jpl_csv_parses_all_fields() {
+        let line = "C/2025 T1,0.95,1.1,30.0,40.0,50.0,2461000.5,7.5,4.0,7.5,4.0,13.0,10.0";
+        let elem = CometElements::from_mpc_line(line).expect("should parse");
+
+        assert_eq!(elem.name, "C/2025 T1");
+        assert!((elem.eccentricity - 0.95).abs() < 1e-9);
+        assert!((elem.perihelion_distance_au - 1.1).abs() < 1e-9);
+        assert!((elem.perihelion_jd - 2461000.5).abs() < 1e-9);
+        assert!((elem.abs_magnitude - 7.5).abs() < 1e-9);
+        assert!((elem.nuclear_abs_magnitude - 13.0).abs() < 1e-9);
+        assert!((elem.nuclear_magnitude_slope - 10.0).abs() < 1e-9);
+
+        // The parsed elements should be usable just like any other
+        // runtime-ingested comet.
+        let time = SkyTime::from_jd(2461000.5);
+        let pos = compute_comet_position_from_elements(&elem, &time);
+        assert!(pos.distance_km.is_finite() && pos.distance_km > 0.0);
+    }
+
+    #[test]
+    fn test_from_mpc_line_fixed_width_parses_designation_and_date() {
+        let mut line = vec![b' '; 100];
+        let mut put = |start: usize, s: &str| {
+            let bytes = s.as_bytes();
+            line[start - 1..start - 1 + bytes.len()].copy_from_slice(bytes);
+        };
+        put(6, "J95O010"); // packed designation -> "1995 O10"
+        put(15, "1995"); // perihelion year
+        put(20, "04"); // perihelion month
+        put(23, "01.5000"); // perihelion day (fractional -> noon)
+        put(31, "1.200000"); // perihelion distance (AU)
+        put(42, "0.800000"); // eccentricity
+        put(52, "100.0000"); // argument of perihelion (deg)
+        put(62, "50.00000"); // longitude of ascending node (deg)
+        put(72, "10.00000"); // inclination (deg)
+        put(92, "10.0"); // absolute magnitude
+        put(97, "4.0"); // magnitude slope
+        let line = String::from_utf8(line).unwrap();
+
+        let elem = CometElements::from_mpc_line(&line).expect("should parse");
+
+        assert_eq!(elem.name, "1995 O10");
+        assert!((elem.perihelion_distance_au - 1.2).abs() < 1e-9);
+        assert!((elem.eccentricity - 0.8).abs() < 1e-9);
+        assert!((elem.arg_perihelion_rad.to_degrees() - 100.0).abs() < 1e-6);
+        assert!((elem.inclination_rad.to_degrees() - 10.0).abs() < 1e-6);
+
+        let expected_jd = SkyTime::from_utc(1995, 4, 1, 12, 0, 0.0).julian_date_tdb();
+        assert!(
+            (elem.perihelion_jd - expected_jd).abs() < 1e-6,
+            "expected perihelion JD {expected_jd}, got {}",
+            elem.perihelion_jd
+        );
+    }
+
+    #[test]
+    fn test_from_mpc_line_rejects_malformed_input() {
+        assert!(CometElements::from_mpc_line("not,enough,fields").is_err());
+        assert!(CometElements::from_mpc_line("too short").is_err());
+    }
+
+    #[test]
+    fn test_aphelion_and_semi_minor_axis_for_halley() {
+        // Halley: a ~17.8 AU, e ~0.967 -> aphelion ~35 AU, well inside
+        // Neptune's orbit is not required, just a sanity range.
+        let q = HALLEY.aphelion_distance_au().unwrap();
+        assert!((34.0..36.0).contains(&q), "unexpected aphelion {q} AU");
+
+        let b = HALLEY.semi_minor_axis_au().unwrap();
+        let a = HALLEY.semi_major_axis().unwrap();
+        assert!(b < a, "semi-minor axis should be less than semi-major axis");
+    }
+
+    #[test]
+    fn test_aphelion_and_mean_motion_none_for_hyperbolic() {
+        assert!(TSUCHINSHAN_ATLAS.aphelion_distance_au().is_none());
+        assert!(TSUCHINSHAN_ATLAS.semi_minor_axis_au().is_none());
+        assert!(TSUCHINSHAN_ATLAS.mean_motion_rad_per_day().is_none());
+    }
+
+    #[test]
+    fn test_mean_motion_matches_period_for_halley() {
+        let n = HALLEY.mean_motion_rad_per_day().unwrap();
+        let period = HALLEY.orbital_period_days().unwrap();
+        assert!(
+            (n * period - 2.0 * PI).abs() < 1e-9,
+            "mean motion times period should complete one full revolution"
+        );
+    }
+
+    #[test]
+    fn test_perihelion_ecliptic_direction_is_unit_length() {
+        let dir = HALLEY.perihelion_ecliptic_direction();
+        let norm = (dir.x * dir.x + dir.y * dir.y + dir.z * dir.z).sqrt();
+        assert!((norm - 1.0).abs() < 1e-9, "expected unit vector, got norm {norm}");
+    }
+
+    #[test]
+    fn test_perihelion_ecliptic_direction_points_toward_perihelion_position() {
+        // At the moment of perihelion passage, the comet's heliocentric
+        // position should point the same way as the accessor.
+        let time = SkyTime::from_jd(HALLEY.perihelion_jd);
+        let pos = compute_comet_position_from_elements(&HALLEY, &time);
+        let dir = HALLEY.perihelion_ecliptic_direction();
+        // Compare in ecliptic space isn't convenient from CometPosition
+        // (which reports equatorial direction), so just check the helper
+        // is internally consistent with the closed-form solver at nu=0.
+        let (x, y, z) = orbital_plane_to_ecliptic(&HALLEY, 1.0, 0.0);
+        assert!((dir.x - x).abs() < 1e-12 && (dir.y - y).abs() < 1e-12 && (dir.z - z).abs() < 1e-12);
+        assert!(pos.helio_distance_km > 0.0);
+    }
+
+    #[test]
+    fn test_with_epoch_flags_stale_elements() {
+        let elem = HALLEY.with_epoch(HALLEY.perihelion_jd);
+        let near_time = SkyTime::from_jd(HALLEY.perihelion_jd + 10.0);
+        let far_time = SkyTime::from_jd(HALLEY.perihelion_jd + 1000.0);
+
+        let near_pos = compute_comet_position_from_elements(&elem, &near_time);
+        let far_pos = compute_comet_position_from_elements(&elem, &far_time);
+
+        assert!(!near_pos.elements_stale);
+        assert!(far_pos.elements_stale);
+    }
+
+    #[test]
+    fn test_no_epoch_never_flags_stale() {
+        // The bundled comets don't set epoch_jd, so they should never be
+        // flagged regardless of how far the requested time is.
+        let time = SkyTime::from_jd(HALLEY.perihelion_jd + 100_000.0);
+        let pos = compute_comet_position(Comet::Halley, &time);
+        assert!(!pos.elements_stale);
+    }
 }
@@ -0,0 +1,229 @@
+//! Conjunction, close-approach, and occultation event search across the
+//! nine tracked celestial bodies (Sun, Moon, and the seven non-Earth
+//! planets).
+//!
+//! [`find_close_approaches`] turns the position engine into an event
+//! predictor: instead of a caller polling [`compute_all_body_positions_full`]
+//! frame by frame and eyeballing when two directions get close, it scans a
+//! time window directly and returns each local minimum of angular
+//! separation that dips at or below a threshold, refined with golden-section
+//! search.
+
+use crate::planets::{compute_all_body_positions_full, CelestialBodyPosition};
+use crate::time::SkyTime;
+
+/// Index of the Moon within `CelestialBody::ALL` / `compute_all_body_positions_full`'s result.
+const MOON_INDEX: usize = 1;
+/// Number of tracked bodies (Sun, Moon, Mercury..Neptune).
+const BODY_COUNT: usize = 9;
+
+/// Coarse sampling step for a scan involving the Moon, whose ~0.5 deg/hour
+/// motion a coarser grid could step clean over: minutes, not hours.
+const MOON_STEP_DAYS: f64 = 10.0 / (24.0 * 60.0); // 10 minutes
+/// Coarse sampling step for scans among the non-Moon bodies, which move
+/// slowly enough that a multi-hour grid won't skip a conjunction.
+const PLANET_STEP_DAYS: f64 = 2.0 / 24.0; // 2 hours
+
+/// A predicted close approach (conjunction) between two of the nine tracked
+/// bodies. `body_a`/`body_b` are indices into `CelestialBody::ALL` (e.g. 0 =
+/// Sun, 1 = Moon, 2 = Mercury, ...), with `body_a < body_b`.
+#[derive(Debug, Clone, Copy)]
+pub struct CloseApproachEvent {
+    pub body_a: usize,
+    pub body_b: usize,
+    /// Julian Date (UTC) of minimum angular separation.
+    pub time_jd: f64,
+    /// Minimum angular separation between the two bodies' directions, radians.
+    pub min_separation_rad: f64,
+    /// True if the minimum separation is less than the sum of the two
+    /// bodies' angular radii, i.e. their disks actually overlap (an
+    /// occultation or transit rather than just a close pass).
+    pub is_occultation: bool,
+}
+
+fn angular_separation_rad(a: &CelestialBodyPosition, b: &CelestialBodyPosition) -> f64 {
+    let dot = a.direction.x * b.direction.x + a.direction.y * b.direction.y + a.direction.z * b.direction.z;
+    dot.clamp(-1.0, 1.0).acos()
+}
+
+/// Angular separation between bodies `i` and `j` (`CelestialBody::ALL`
+/// indices) at Julian Date `jd`, against a temporary `SkyTime` so a caller's
+/// own clock (e.g. `SkyEngine::time`) is never touched by the scan.
+fn separation_at(jd: f64, i: usize, j: usize) -> f64 {
+    let time = SkyTime::from_jd(jd);
+    let positions = compute_all_body_positions_full(&time);
+    angular_separation_rad(&positions[i], &positions[j])
+}
+
+/// Refine a local minimum of `separation_at(_, i, j)` known to lie within
+/// `[lo_jd, hi_jd]` using golden-section search, returning the Julian Date
+/// and separation at the refined minimum.
+fn refine_minimum(i: usize, j: usize, mut lo_jd: f64, mut hi_jd: f64) -> (f64, f64) {
+    const GOLDEN: f64 = 0.6180339887498949; // (sqrt(5) - 1) / 2
+
+    let mut c = hi_jd - GOLDEN * (hi_jd - lo_jd);
+    let mut d = lo_jd + GOLDEN * (hi_jd - lo_jd);
+    let mut sep_c = separation_at(c, i, j);
+    let mut sep_d = separation_at(d, i, j);
+
+    for _ in 0..25 {
+        if sep_c < sep_d {
+            hi_jd = d;
+            d = c;
+            sep_d = sep_c;
+            c = hi_jd - GOLDEN * (hi_jd - lo_jd);
+            sep_c = separation_at(c, i, j);
+        } else {
+            lo_jd = c;
+            c = d;
+            sep_c = sep_d;
+            d = lo_jd + GOLDEN * (hi_jd - lo_jd);
+            sep_d = separation_at(d, i, j);
+        }
+    }
+
+    let time_jd = 0.5 * (lo_jd + hi_jd);
+    (time_jd, separation_at(time_jd, i, j))
+}
+
+/// Search `[start_jd, end_jd]` (Julian Dates, UTC) for close approaches
+/// between any two of the nine tracked bodies (`CelestialBody::ALL` order)
+/// whose minimum angular separation falls at or below `max_sep_rad`.
+///
+/// For each of the 36 body pairs, samples `separation_at` on a coarse grid
+/// -- a 10-minute step if the pair includes the Moon, a 2-hour step
+/// otherwise -- and whenever a sample is a local minimum (lower than both
+/// neighbors) and at or below `max_sep_rad`, refines it with golden-section
+/// search. Flags `is_occultation` when the refined minimum separation is
+/// less than the sum of the two bodies' angular radii at that time, i.e.
+/// their disks actually overlap. Events are returned sorted by time.
+pub fn find_close_approaches(start_jd: f64, end_jd: f64, max_sep_rad: f64) -> Vec<CloseApproachEvent> {
+    let mut events = Vec::new();
+    if end_jd <= start_jd {
+        return events;
+    }
+
+    for i in 0..BODY_COUNT {
+        for j in (i + 1)..BODY_COUNT {
+            let step_days = if i == MOON_INDEX || j == MOON_INDEX {
+                MOON_STEP_DAYS
+            } else {
+                PLANET_STEP_DAYS
+            };
+
+            let mut jd_prev = start_jd;
+            let mut sep_prev = separation_at(jd_prev, i, j);
+            let mut jd_curr = (jd_prev + step_days).min(end_jd);
+            let mut sep_curr = separation_at(jd_curr, i, j);
+
+            while jd_curr < end_jd {
+                let jd_next = (jd_curr + step_days).min(end_jd);
+                let sep_next = separation_at(jd_next, i, j);
+
+                if sep_curr <= sep_prev && sep_curr <= sep_next && sep_curr <= max_sep_rad {
+                    let (time_jd, min_separation_rad) = refine_minimum(i, j, jd_prev, jd_next);
+
+                    if min_separation_rad <= max_sep_rad {
+                        let time = SkyTime::from_jd(time_jd);
+                        let positions = compute_all_body_positions_full(&time);
+                        let radius_sum =
+                            (positions[i].angular_diameter_rad + positions[j].angular_diameter_rad) / 2.0;
+
+                        events.push(CloseApproachEvent {
+                            body_a: i,
+                            body_b: j,
+                            time_jd,
+                            min_separation_rad,
+                            is_occultation: min_separation_rad < radius_sum,
+                        });
+                    }
+                }
+
+                jd_prev = jd_curr;
+                sep_prev = sep_curr;
+                jd_curr = jd_next;
+                sep_curr = sep_next;
+            }
+        }
+    }
+
+    events.sort_by(|a, b| a.time_jd.partial_cmp(&b.time_jd).unwrap());
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_moon_window_finds_sun_moon_close_approach() {
+        // 2024-06-06 12:00 UTC is a known New Moon (see planets::tests), so a
+        // window spanning that day should turn up a Sun-Moon conjunction.
+        let start_jd = SkyTime::from_utc(2024, 6, 5, 0, 0, 0.0).julian_date_utc();
+        let end_jd = SkyTime::from_utc(2024, 6, 8, 0, 0, 0.0).julian_date_utc();
+
+        let events = find_close_approaches(start_jd, end_jd, 10f64.to_radians());
+        let sun_moon = events
+            .iter()
+            .find(|e| (e.body_a == 0 && e.body_b == 1) || (e.body_a == 1 && e.body_b == 0))
+            .expect("expected a Sun-Moon close approach around New Moon");
+
+        assert!(
+            sun_moon.min_separation_rad < 1f64.to_radians(),
+            "expected New Moon separation under 1 degree, got {} deg",
+            sun_moon.min_separation_rad.to_degrees()
+        );
+        assert!(sun_moon.time_jd >= start_jd && sun_moon.time_jd <= end_jd);
+    }
+
+    #[test]
+    fn test_tight_threshold_excludes_distant_pairs() {
+        // A one-day window with an effectively zero threshold should find
+        // no events at all -- nothing gets exactly that close.
+        let start_jd = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0).julian_date_utc();
+        let end_jd = SkyTime::from_utc(2024, 1, 2, 0, 0, 0.0).julian_date_utc();
+
+        let events = find_close_approaches(start_jd, end_jd, 1e-6);
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_empty_window_returns_no_events() {
+        let jd = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0).julian_date_utc();
+        let events = find_close_approaches(jd, jd, 0.5);
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_events_are_sorted_by_time() {
+        let start_jd = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0).julian_date_utc();
+        let end_jd = SkyTime::from_utc(2024, 4, 1, 0, 0, 0.0).julian_date_utc();
+
+        let events = find_close_approaches(start_jd, end_jd, 5f64.to_radians());
+        for pair in events.windows(2) {
+            assert!(pair[0].time_jd <= pair[1].time_jd);
+        }
+    }
+
+    #[test]
+    fn test_occultation_flag_only_set_when_disks_overlap() {
+        // A generous threshold over a few months should include plenty of
+        // non-occulting close approaches (disks don't overlap at every
+        // conjunction); just check the flag is internally consistent with
+        // the reported separation vs. the bodies' angular radii at that time.
+        let start_jd = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0).julian_date_utc();
+        let end_jd = SkyTime::from_utc(2024, 6, 1, 0, 0, 0.0).julian_date_utc();
+
+        let events = find_close_approaches(start_jd, end_jd, 2f64.to_radians());
+        assert!(!events.is_empty());
+
+        for event in &events {
+            let time = SkyTime::from_jd(event.time_jd);
+            let positions = compute_all_body_positions_full(&time);
+            let radius_sum = (positions[event.body_a].angular_diameter_rad
+                + positions[event.body_b].angular_diameter_rad)
+                / 2.0;
+            assert_eq!(event.is_occultation, event.min_separation_rad < radius_sum);
+        }
+    }
+}
@@ -514,6 +514,356 @@ pub fn compute_sun_aberration(jde: f64) -> f64 {
     -ABERRATION_CONSTANT * (1.0 + e)
 }
 
+/// Convert equatorial coordinates to ecliptic coordinates (inverse of
+/// `ecliptic_to_equatorial`).
+pub fn equatorial_to_ecliptic(ra_rad: f64, dec_rad: f64, obliquity_rad: f64) -> (f64, f64) {
+    let coord = ra_dec_to_cartesian(ra_rad, dec_rad);
+    let cos_eps = obliquity_rad.cos();
+    let sin_eps = obliquity_rad.sin();
+
+    let x = coord.x;
+    let y = coord.y * cos_eps + coord.z * sin_eps;
+    let z = -coord.y * sin_eps + coord.z * cos_eps;
+
+    let lat = z.asin();
+    let mut lon = y.atan2(x);
+    if lon < 0.0 {
+        lon += 2.0 * PI;
+    }
+    (lon, lat)
+}
+
+/// Low-precision geometric ecliptic longitude of the Sun (Meeus ch. 25, low-precision).
+/// Good to about 0.01°, which is more than sufficient for aberration corrections.
+fn low_precision_sun_longitude(jde: f64) -> f64 {
+    let t = (jde - 2451545.0) / 36525.0;
+
+    let l0_deg = 280.46646 + 36000.76983 * t + 0.0003032 * t * t;
+    let m_deg = 357.52911 + 35999.05029 * t - 0.0001537 * t * t;
+    let m_rad = m_deg.to_radians();
+
+    let c_deg = (1.914602 - 0.004817 * t - 0.000014 * t * t) * m_rad.sin()
+        + (0.019993 - 0.000101 * t) * (2.0 * m_rad).sin()
+        + 0.000289 * (3.0 * m_rad).sin();
+
+    (l0_deg + c_deg).to_radians().rem_euclid(2.0 * PI)
+}
+
+/// Fractional part of `x`, always in `[0, 1)` -- the building block every
+/// angle series below is expressed in (`2π·frac(...)` rather than degrees).
+fn frac(x: f64) -> f64 {
+    x - x.floor()
+}
+
+/// 1 AU in kilometers, for converting the Moon's distance series below.
+/// Kept local to this module rather than shared with `crate::planets`'s
+/// `AU_TO_KM` -- `coords` sits below `planets` in the dependency order.
+const AU_KM: f64 = 149_597_870.7;
+
+/// Arcseconds per radian, used by the angle-correction series below, which
+/// are conventionally expressed in arcseconds.
+const ARCSEC_PER_RADIAN: f64 = 206_264.8062;
+
+/// Low-precision ecliptic position of the Sun (Montenbruck & Pfleger,
+/// "Astronomy on the Personal Computer", the "MiniSun" algorithm). Good to a
+/// few arcminutes -- plenty for illumination/shadow sanity checks that don't
+/// warrant the full VSOP87 theory in [`crate::planets`].
+///
+/// Returns `(ecliptic longitude, ecliptic latitude, distance in AU)` in the
+/// mean ecliptic and equinox of date. Latitude is always 0 -- the Sun's
+/// ecliptic latitude is at most ~1", well below this model's precision.
+pub fn sun_position(jde: f64) -> (f64, f64, f64) {
+    let t = (jde - 2451545.0) / 36525.0;
+
+    let mean_anomaly = 2.0 * PI * frac(0.993133 + 99.997361 * t);
+    let longitude = 2.0
+        * PI
+        * frac(
+            0.7859453 + mean_anomaly / (2.0 * PI)
+                + (6893.0 * mean_anomaly.sin() + 72.0 * (2.0 * mean_anomaly).sin() + 6191.2 * t) / 1_296_000.0,
+        );
+    let distance_au = 1.00014 - 0.01671 * mean_anomaly.cos() - 0.00014 * (2.0 * mean_anomaly).cos();
+
+    (longitude.rem_euclid(2.0 * PI), 0.0, distance_au)
+}
+
+/// Low-precision ecliptic position of the Moon (Montenbruck & Pfleger, the
+/// "MiniMoon" algorithm). Good to a few arcminutes in position and a few
+/// hundred km in distance -- the same "don't need the full lunar theory"
+/// tradeoff as [`sun_position`].
+///
+/// Returns `(ecliptic longitude, ecliptic latitude, distance in AU)` in the
+/// mean ecliptic and equinox of date.
+pub fn moon_position(jde: f64) -> (f64, f64, f64) {
+    let t = (jde - 2451545.0) / 36525.0;
+
+    let l0 = 2.0 * PI * frac(0.606433 + 1336.855225 * t); // Mean longitude
+    let l = 2.0 * PI * frac(0.374897 + 1325.552410 * t); // Mean anomaly
+    let lp = 2.0 * PI * frac(0.993133 + 99.997361 * t); // Sun's mean anomaly
+    let d = 2.0 * PI * frac(0.827361 + 1236.853086 * t); // Elongation from the Sun
+    let f = 2.0 * PI * frac(0.259086 + 1342.227825 * t); // Argument of latitude
+
+    // Longitude correction, arcseconds.
+    let delta_lon_arcsec = 22640.0 * l.sin() - 4586.0 * (l - 2.0 * d).sin()
+        + 2370.0 * (2.0 * d).sin()
+        + 769.0 * (2.0 * l).sin()
+        - 668.0 * lp.sin()
+        - 412.0 * (2.0 * f).sin()
+        - 212.0 * (2.0 * l - 2.0 * d).sin()
+        - 206.0 * (l + lp - 2.0 * d).sin()
+        + 192.0 * (l + 2.0 * d).sin()
+        - 165.0 * (lp - 2.0 * d).sin()
+        + 148.0 * (l - lp).sin()
+        - 125.0 * d.sin()
+        - 110.0 * (l + lp).sin()
+        - 55.0 * (2.0 * f - 2.0 * d).sin();
+    let longitude = (l0 + delta_lon_arcsec / ARCSEC_PER_RADIAN).rem_euclid(2.0 * PI);
+
+    // Latitude argument (main term, plus the same small corrections the
+    // longitude picked up) and its own residual series.
+    let s = f + (delta_lon_arcsec + 412.0 * (2.0 * f).sin() + 541.0 * lp.sin()) / ARCSEC_PER_RADIAN;
+    let h = f - 2.0 * d;
+    let latitude_residual_arcsec = -526.0 * h.sin() + 44.0 * (l + h).sin() - 31.0 * (-l + h).sin()
+        - 23.0 * (lp + h).sin()
+        + 11.0 * (-lp + h).sin()
+        - 25.0 * (-2.0 * l + f).sin()
+        + 21.0 * (-l + f).sin();
+    let latitude = (18520.0 * s.sin() + latitude_residual_arcsec) / ARCSEC_PER_RADIAN;
+
+    // Distance series, km (mean Earth-Moon distance plus the main periodic terms).
+    let distance_km = 385_000.5584 - 20_905.3550 * l.cos() - 3_699.1109 * (2.0 * d - l).cos()
+        - 2_955.9676 * (2.0 * d).cos()
+        - 569.9251 * (2.0 * l).cos()
+        - 246.1584 * (2.0 * d - 2.0 * l).cos()
+        - 204.1893 * (2.0 * d - lp).cos();
+
+    (longitude, latitude, distance_km / AU_KM)
+}
+
+/// Precession angles ζ (zeta), z, and θ (theta) for precessing from the J2000.0
+/// mean equator/equinox to the mean equator/equinox of a target date.
+///
+/// Based on the IAU precession polynomials (Meeus, Astronomical Algorithms, eq. 21.1).
+pub struct PrecessionAngles {
+    pub zeta: f64,
+    pub z: f64,
+    pub theta: f64,
+}
+
+/// Compute the precession angles for a Julian Date, relative to J2000.0.
+pub fn precession_angles(jde: f64) -> PrecessionAngles {
+    let t = (jde - 2451545.0) / 36525.0;
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    let arcsec_to_rad = PI / (180.0 * 3600.0);
+
+    let zeta = (2306.2181 * t + 0.30188 * t2 + 0.017998 * t3) * arcsec_to_rad;
+    let z = (2306.2181 * t + 1.09468 * t2 + 0.018203 * t3) * arcsec_to_rad;
+    let theta = (2004.3109 * t - 0.42665 * t2 - 0.041833 * t3) * arcsec_to_rad;
+
+    PrecessionAngles { zeta, z, theta }
+}
+
+/// Rotation matrix precessing a mean equatorial direction from J2000.0 to the
+/// mean equator/equinox of `jde`, as a row-major `[[f64; 3]; 3]`: apply it to
+/// a column vector of direction cosines to get the precessed direction.
+///
+/// Built as R3(-z) · R2(θ) · R3(-ζ) (Meeus, Astronomical Algorithms, eq. 21.4).
+pub fn precession_matrix(jde: f64) -> [[f64; 3]; 3] {
+    let PrecessionAngles { zeta, z, theta } = precession_angles(jde);
+
+    let (sin_zeta, cos_zeta) = zeta.sin_cos();
+    let (sin_z, cos_z) = z.sin_cos();
+    let (sin_theta, cos_theta) = theta.sin_cos();
+
+    [
+        [
+            cos_zeta * cos_theta * cos_z - sin_zeta * sin_z,
+            -sin_zeta * cos_theta * cos_z - cos_zeta * sin_z,
+            -sin_theta * cos_z,
+        ],
+        [
+            cos_zeta * cos_theta * sin_z + sin_zeta * cos_z,
+            -sin_zeta * cos_theta * sin_z + cos_zeta * cos_z,
+            -sin_theta * sin_z,
+        ],
+        [cos_zeta * sin_theta, -sin_zeta * sin_theta, cos_theta],
+    ]
+}
+
+/// Precess a J2000.0 mean equatorial direction to the mean equator/equinox of `jde`.
+pub fn precess_j2000_to_date(coord: &CartesianCoord, jde: f64) -> CartesianCoord {
+    let m = precession_matrix(jde);
+
+    CartesianCoord::new(
+        m[0][0] * coord.x + m[0][1] * coord.y + m[0][2] * coord.z,
+        m[1][0] * coord.x + m[1][1] * coord.y + m[1][2] * coord.z,
+        m[2][0] * coord.x + m[2][1] * coord.y + m[2][2] * coord.z,
+    )
+}
+
+/// Atmospheric refraction at a given apparent altitude, using Bennett's formula.
+///
+/// Returns the refraction correction in radians; add it to the true altitude
+/// to get the apparent (refracted) altitude. Valid for altitudes above the
+/// horizon; becomes unreliable well below it.
+///
+/// Reference: Bennett, G.G. (1982), "The Calculation of Astronomical Refraction
+/// in Marine Navigation".
+pub fn compute_refraction(altitude_rad: f64) -> f64 {
+    let altitude_deg = altitude_rad.to_degrees();
+    let r_arcmin = 1.0 / (altitude_deg + 7.31 / (altitude_deg + 4.4)).to_radians().tan();
+    r_arcmin * PI / (180.0 * 60.0)
+}
+
+/// Light travel time per AU, in days (1 / speed of light in AU/day).
+const LIGHT_TIME_DAYS_PER_AU: f64 = 0.0057755183;
+
+/// Iteratively correct a body's apparent position for light-time: it's seen
+/// where it was when the light now arriving left it, not where it is "now".
+///
+/// `position_fn(jde)` returns the body's geocentric position (AU) and
+/// geocentric distance (AU) evaluated at `jde`. Starting from `dt = 0`, this
+/// re-evaluates `position_fn` at `jde - dt` and re-estimates `dt` from the
+/// returned distance, converging when `dt` changes by less than 1e-9 days
+/// (2-3 iterations suffice for anything in the solar system). Returns the
+/// light-time-corrected position and the converged `dt`, for callers that
+/// also want the delay itself (e.g. to retard a velocity sample).
+///
+/// This is the same two-pass scheme [`crate::comets`] and [`crate::minor_bodies`]
+/// already apply inline for their own element-based positions, generalized
+/// so any `position_fn` -- a planet, a satellite, a runtime-ingested body --
+/// can share it.
+pub fn apply_light_time_correction<F>(position_fn: F, jde: f64) -> (CartesianCoord, f64)
+where
+    F: Fn(f64) -> (CartesianCoord, f64),
+{
+    let mut dt = 0.0;
+    let mut position = CartesianCoord::new(0.0, 0.0, 0.0);
+
+    for _ in 0..5 {
+        let (pos, distance_au) = position_fn(jde - dt);
+        position = pos;
+
+        let new_dt = distance_au * LIGHT_TIME_DAYS_PER_AU;
+        let converged = (new_dt - dt).abs() < 1e-9;
+        dt = new_dt;
+        if converged {
+            break;
+        }
+    }
+
+    (position, dt)
+}
+
+/// `2GM☉/c²` in AU -- twice the Sun's Schwarzschild radius, the natural
+/// length scale of gravitational light deflection (this reproduces the
+/// well-known 1.75" deflection at the solar limb when divided by the Sun's
+/// angular radius).
+const TWO_GM_SUN_OVER_C2_AU: f64 = 1.974e-8;
+
+/// Beyond this Sun-object elongation the deflection is not computed; at 90°
+/// it is 2GM☉/c² / |E| radians (about 4 mas at 1 AU) and falls to zero at
+/// opposition.
+const DEFLECTION_MAX_ELONGATION_RAD: f64 = PI / 2.0;
+
+/// Guard against the formula's singularity at exact solar conjunction (sin χ
+/// → 0): an object that close to the Sun in the sky is occulted by it
+/// anyway, so there's no physically meaningful apparent position to correct.
+const MIN_SIN_ELONGATION: f64 = 1e-4;
+
+/// Deflect a geocentric unit direction `obj` away from the Sun by the
+/// classical relativistic light-bending angle
+/// `δ = (2GM☉/c²)·(1+cos χ)/(sin χ·|E|)`, where χ is the Sun-object
+/// elongation seen from Earth (the angle between `obj` and `sun`) and `|E|`
+/// (`earth_sun_dist_au`) is the Earth-Sun distance in AU. No-ops once χ
+/// exceeds [`DEFLECTION_MAX_ELONGATION_RAD`], where the effect is negligible.
+///
+/// This is the "object at infinity" form of the full relativistic deflection
+/// formula: valid once the object's own parallax against the Sun-Earth
+/// baseline is negligible, which in practice means anything past the inner
+/// solar system -- the regime every caller in this crate needs it for.
+pub fn compute_gravitational_deflection(
+    obj: &CartesianCoord,
+    sun: &CartesianCoord,
+    earth_sun_dist_au: f64,
+) -> CartesianCoord {
+    let cos_chi = (obj.x * sun.x + obj.y * sun.y + obj.z * sun.z).clamp(-1.0, 1.0);
+    let chi = cos_chi.acos();
+    if chi > DEFLECTION_MAX_ELONGATION_RAD {
+        return *obj;
+    }
+    let sin_chi = chi.sin().max(MIN_SIN_ELONGATION);
+
+    let delta = TWO_GM_SUN_OVER_C2_AU * (1.0 + cos_chi) / (sin_chi * earth_sun_dist_au);
+
+    // Component of `obj` perpendicular to the Sun direction, i.e. the
+    // direction in the sky that light bends *away from* as it passes the Sun.
+    let perp = CartesianCoord::new(obj.x - cos_chi * sun.x, obj.y - cos_chi * sun.y, obj.z - cos_chi * sun.z)
+        .normalize();
+
+    CartesianCoord::new(obj.x + delta * perp.x, obj.y + delta * perp.y, obj.z + delta * perp.z).normalize()
+}
+
+/// Which corrections `apply_corrections` should apply when reducing a mean
+/// J2000 position to its apparent place of date.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CorrectionFlags {
+    pub precession: bool,
+    pub nutation: bool,
+    pub aberration: bool,
+}
+
+impl CorrectionFlags {
+    /// Apply every correction except refraction (which needs an observed
+    /// altitude, not just a direction, and is applied separately).
+    pub const ALL: CorrectionFlags = CorrectionFlags {
+        precession: true,
+        nutation: true,
+        aberration: true,
+    };
+}
+
+/// Reduce a mean J2000.0 equatorial direction to its apparent place at `jde`,
+/// applying precession, nutation, and annual aberration as selected by `flags`.
+///
+/// Catalog star positions and planet/Sun geometric positions both need this
+/// reduction to match what a telescope actually sees at the time of
+/// observation. Atmospheric refraction is *not* included here since it
+/// depends on the observed altitude; apply `compute_refraction` separately
+/// once the apparent altitude is known.
+pub fn apply_corrections(coord: &CartesianCoord, jde: f64, flags: CorrectionFlags) -> CartesianCoord {
+    let mut result = *coord;
+
+    if flags.precession {
+        result = precess_j2000_to_date(&result, jde);
+    }
+
+    if flags.nutation || flags.aberration {
+        let eps0 = mean_obliquity(jde);
+        let (ra, dec) = cartesian_to_ra_dec(&result);
+        let (mut lon, lat) = equatorial_to_ecliptic(ra, dec, eps0);
+        let mut eps = eps0;
+
+        if flags.aberration {
+            let sun_lon = low_precision_sun_longitude(jde);
+            let aberration = compute_aberration(sun_lon, lon, lat, jde);
+            lon += aberration.delta_longitude;
+        }
+
+        if flags.nutation {
+            let nutation = compute_nutation(jde);
+            lon += nutation.delta_psi;
+            eps += nutation.delta_epsilon;
+        }
+
+        result = ecliptic_to_equatorial(lon, lat, eps);
+    }
+
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -658,4 +1008,239 @@ mod tests {
             "Aberration should vary with angular position"
         );
     }
+
+    #[test]
+    fn test_ecliptic_equatorial_roundtrip() {
+        let ra = 1.1;
+        let dec = 0.3;
+        let eps = OBLIQUITY_J2000;
+        let (lon, lat) = equatorial_to_ecliptic(ra, dec, eps);
+        let coord = ecliptic_to_equatorial(lon, lat, eps);
+        let (ra2, dec2) = cartesian_to_ra_dec(&coord);
+        assert!((ra - ra2).abs() < 1e-9);
+        assert!((dec - dec2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_precession_identity_at_j2000() {
+        // Precessing from J2000.0 to J2000.0 should be a no-op.
+        let coord = ra_dec_to_cartesian(1.0, 0.5);
+        let precessed = precess_j2000_to_date(&coord, 2451545.0);
+        assert!((coord.x - precessed.x).abs() < 1e-9);
+        assert!((coord.y - precessed.y).abs() < 1e-9);
+        assert!((coord.z - precessed.z).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_precession_matrix_is_identity_at_j2000() {
+        // At jde=J2000.0, T=0 so all three precession angles vanish and the
+        // matrix should reduce to the identity.
+        let m = precession_matrix(2451545.0);
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!(
+                    (m[i][j] - expected).abs() < 1e-9,
+                    "m[{i}][{j}] should be {expected} at J2000, got {}",
+                    m[i][j]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_precession_matrix_is_orthogonal() {
+        // A rotation matrix's rows should be unit length and mutually
+        // perpendicular -- this also implicitly checks `precess_j2000_to_date`
+        // preserves vector length (precession shouldn't change a direction's
+        // magnitude, only its orientation).
+        let m = precession_matrix(2451545.0 + 50.0 * 365.25);
+        for row in &m {
+            let len = (row[0] * row[0] + row[1] * row[1] + row[2] * row[2]).sqrt();
+            assert!((len - 1.0).abs() < 1e-9, "row {row:?} should be unit length, got {len}");
+        }
+        let dot01 = m[0][0] * m[1][0] + m[0][1] * m[1][1] + m[0][2] * m[1][2];
+        assert!(dot01.abs() < 1e-9, "rows 0 and 1 should be orthogonal, got dot {dot01}");
+    }
+
+    #[test]
+    fn test_precession_magnitude_50_years() {
+        // General precession is about 50.3"/year; 50 years should shift a
+        // position near the equator by roughly 0.7 degrees.
+        let coord = ra_dec_to_cartesian(0.0, 0.0);
+        let jde_2050 = 2451545.0 + 50.0 * 365.25;
+        let precessed = precess_j2000_to_date(&coord, jde_2050);
+
+        let dot = coord.x * precessed.x + coord.y * precessed.y + coord.z * precessed.z;
+        let sep_deg = dot.clamp(-1.0, 1.0).acos().to_degrees();
+        assert!(
+            sep_deg > 0.4 && sep_deg < 1.2,
+            "50-year precession shift should be within a degree or so, got {sep_deg}"
+        );
+    }
+
+    #[test]
+    fn test_refraction_near_horizon_exceeds_zenith() {
+        let at_horizon = compute_refraction(0.0_f64.to_radians());
+        let near_zenith = compute_refraction(89.0_f64.to_radians());
+        assert!(at_horizon > near_zenith);
+        // Refraction at the horizon is classically about 34 arcminutes.
+        let horizon_arcmin = at_horizon * 180.0 * 60.0 / PI;
+        assert!(
+            (horizon_arcmin - 34.0).abs() < 5.0,
+            "horizon refraction should be ~34', got {horizon_arcmin}'"
+        );
+    }
+
+    #[test]
+    fn test_apply_corrections_small_shift() {
+        let coord = ra_dec_to_cartesian(2.0, 0.4);
+        let jde = 2451545.0 + 365.25 * 10.0; // 10 years after J2000
+        let corrected = apply_corrections(&coord, jde, CorrectionFlags::ALL);
+
+        let dot = coord.x * corrected.x + coord.y * corrected.y + coord.z * corrected.z;
+        let sep_arcsec = dot.clamp(-1.0, 1.0).acos() * 180.0 * 3600.0 / PI;
+        // Over a decade, precession/nutation/aberration should shift the
+        // apparent position by arcseconds to a few arcminutes, not degrees.
+        assert!(
+            sep_arcsec > 1.0 && sep_arcsec < 1000.0,
+            "apparent-place shift should be modest, got {sep_arcsec}\""
+        );
+    }
+
+    #[test]
+    fn test_sun_position_distance_within_earth_orbit_eccentricity() {
+        // Earth's orbit is nearly circular; the Sun's distance should stay
+        // within its ~0.983-1.017 AU perihelion/aphelion range year-round.
+        for day_offset in [0.0, 90.0, 180.0, 270.0] {
+            let jde = 2451545.0 + day_offset;
+            let (_, lat, distance_au) = sun_position(jde);
+            assert_eq!(lat, 0.0, "Sun's low-precision ecliptic latitude should be exactly 0");
+            assert!(
+                distance_au > 0.983 && distance_au < 1.017,
+                "Sun distance at jde={jde} should be within Earth's orbital range, got {distance_au} AU"
+            );
+        }
+    }
+
+    #[test]
+    fn test_sun_position_longitude_advances_over_a_month() {
+        let (lon_start, _, _) = sun_position(2451545.0);
+        let (lon_later, _, _) = sun_position(2451545.0 + 30.0);
+        // The Sun's ecliptic longitude advances by about 1 deg/day (~30 deg/month).
+        let advance_deg = (lon_later - lon_start).rem_euclid(2.0 * PI).to_degrees();
+        assert!(
+            advance_deg > 25.0 && advance_deg < 35.0,
+            "Sun's longitude should advance ~30 degrees in 30 days, got {advance_deg}"
+        );
+    }
+
+    #[test]
+    fn test_moon_position_distance_within_orbit_range() {
+        // The Moon's distance ranges from about 356,500 km (perigee) to
+        // 406,700 km (apogee); sample several dates across a month.
+        for day_offset in [0.0, 7.0, 14.0, 21.0, 28.0] {
+            let jde = 2451545.0 + day_offset;
+            let (_, lat, distance_au) = moon_position(jde);
+            let distance_km = distance_au * AU_KM;
+            assert!(
+                distance_km > 350_000.0 && distance_km < 410_000.0,
+                "Moon distance at jde={jde} should be near its known orbital range, got {distance_km} km"
+            );
+            assert!(lat.abs() < 0.1, "Moon's ecliptic latitude should stay within ~5 degrees, got {lat} rad");
+        }
+    }
+
+    #[test]
+    fn test_moon_position_longitude_advances_quickly() {
+        let (lon_start, _, _) = moon_position(2451545.0);
+        let (lon_later, _, _) = moon_position(2451545.0 + 1.0);
+        // The Moon moves ~13 deg/day in ecliptic longitude.
+        let advance_deg = (lon_later - lon_start).rem_euclid(2.0 * PI).to_degrees();
+        assert!(
+            advance_deg > 10.0 && advance_deg < 16.0,
+            "Moon's longitude should advance ~13 degrees in a day, got {advance_deg}"
+        );
+    }
+
+    #[test]
+    fn test_gravitational_deflection_vanishes_in_opposition() {
+        // An object opposite the Sun in the sky (elongation 180 deg) sees no
+        // deflection at all -- light from it never passes near the Sun.
+        let obj = CartesianCoord::new(-1.0, 0.0, 0.0);
+        let sun = CartesianCoord::new(1.0, 0.0, 0.0);
+        let deflected = compute_gravitational_deflection(&obj, &sun, 1.0);
+        assert!((deflected.x - obj.x).abs() < 1e-12, "opposition should leave the direction unchanged");
+        assert!((deflected.y - obj.y).abs() < 1e-12);
+        assert!((deflected.z - obj.z).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_gravitational_deflection_grows_toward_limb() {
+        // At 90 deg elongation the classical formula gives exactly
+        // 2GM☉/c² / |E| radians; at 1 AU that's the textbook order of
+        // magnitude for deflection well away from the limb (a few mas), and
+        // it should grow sharply as the object approaches the Sun's
+        // direction (the regime that produces the famous 1.75" limb value).
+        let sun = CartesianCoord::new(0.0, 0.0, 1.0);
+        let obj_90deg = CartesianCoord::new(1.0, 0.0, 0.0);
+        let obj_near_limb = CartesianCoord::new((0.01_f64).sin(), 0.0, (0.01_f64).cos());
+
+        let deflected_90 = compute_gravitational_deflection(&obj_90deg, &sun, 1.0);
+        let shift_90 =
+            (obj_90deg.x * deflected_90.x + obj_90deg.y * deflected_90.y + obj_90deg.z * deflected_90.z).clamp(-1.0, 1.0).acos();
+
+        let deflected_limb = compute_gravitational_deflection(&obj_near_limb, &sun, 1.0);
+        let shift_limb = (obj_near_limb.x * deflected_limb.x
+            + obj_near_limb.y * deflected_limb.y
+            + obj_near_limb.z * deflected_limb.z)
+            .clamp(-1.0, 1.0)
+            .acos();
+
+        assert!(
+            shift_limb > shift_90,
+            "deflection near the limb ({shift_limb} rad) should exceed deflection at 90 degrees ({shift_90} rad)"
+        );
+    }
+
+    #[test]
+    fn test_light_time_correction_converges_for_constant_distance() {
+        // A body sitting still at a fixed distance has no actual light-time
+        // effect to converge on -- `dt` should settle at exactly
+        // distance_au * LIGHT_TIME_DAYS_PER_AU, and stay there regardless of
+        // how many iterations run.
+        let (position, dt) = apply_light_time_correction(
+            |_jde| (CartesianCoord::new(2.0, 0.0, 0.0), 2.0),
+            2451545.0,
+        );
+
+        assert_eq!(position.x, 2.0);
+        let expected_dt = 2.0 * 0.0057755183;
+        assert!(
+            (dt - expected_dt).abs() < 1e-9,
+            "expected dt {expected_dt}, got {dt}"
+        );
+    }
+
+    #[test]
+    fn test_light_time_correction_retards_a_moving_body() {
+        // A body receding from Earth at a constant rate should be "seen"
+        // closer than its instantaneous distance, since the light left it
+        // when it was nearer.
+        let jde = 2451545.0;
+        let (position, dt) = apply_light_time_correction(
+            |t| {
+                let distance_au = 1.0 + 0.01 * (t - jde);
+                (CartesianCoord::new(distance_au, 0.0, 0.0), distance_au)
+            },
+            jde,
+        );
+
+        assert!(dt > 0.0, "a body at nonzero distance should have nonzero light-time delay");
+        assert!(
+            position.x < 1.0,
+            "the retarded position should be nearer than the instantaneous distance, got {}",
+            position.x
+        );
+    }
 }
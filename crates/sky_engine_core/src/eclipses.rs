@@ -0,0 +1,525 @@
+//! Solar eclipse local circumstances via Besselian elements (Meeus ch. 54).
+//!
+//! Besselian elements describe a solar eclipse in a frame tied to the
+//! Sun-Moon shadow axis rather than any one observer: the axis pierces a
+//! "fundamental plane" through Earth's center at `(x, y)` (in Earth radii),
+//! and the penumbral/umbral shadow cones have radii `l1`/`l2` at that plane.
+//! An observer's local circumstances then reduce to projecting their
+//! geocentric position into the same plane and comparing the separation
+//! against those cone radii -- the same kind of rotation
+//! [`crate::coords::compute_topocentric_correction`] already does for the
+//! Moon's ~1° parallax, just expressed in the shadow-axis frame instead of
+//! RA/Dec.
+//!
+//! [`compute_besselian_elements`] builds the elements from geocentric
+//! Sun/Moon directions and distances at one instant; [`local_circumstances`]
+//! walks a time-varying supply of elements to find maximum eclipse, its
+//! magnitude, and the four contact times for a given [`Observer`].
+
+use crate::coords::{compute_gmst, CartesianCoord};
+use crate::rise_set::Observer;
+
+/// Mean radius of the Sun, km.
+const SUN_RADIUS_KM: f64 = 696_000.0;
+/// Mean radius of the Moon, km.
+const MOON_RADIUS_KM: f64 = 1_737.4;
+/// Earth's equatorial radius, km -- the unit `x`, `y`, `l1`, `l2` are
+/// expressed in, matching the classical Besselian-element convention.
+const EARTH_RADIUS_KM: f64 = 6378.137;
+/// 1 AU in kilometers. Kept local rather than shared with `crate::planets`'s
+/// `AU_TO_KM` -- see the equivalent note on `coords::AU_KM`.
+const AU_KM: f64 = 149_597_870.7;
+
+/// Besselian elements of a solar eclipse at a single instant: the geometry
+/// of the Sun-Moon shadow axis relative to Earth, projected onto the
+/// fundamental plane (the plane through Earth's center perpendicular to the
+/// axis).
+#[derive(Debug, Clone, Copy)]
+pub struct BesselianElements {
+    /// Shadow axis's intersection with the fundamental plane, Earth radii
+    /// toward the plane's local east.
+    pub x: f64,
+    /// Shadow axis's intersection with the fundamental plane, Earth radii
+    /// toward the plane's local north.
+    pub y: f64,
+    /// Declination of the point the shadow axis points to, radians.
+    pub declination_rad: f64,
+    /// Greenwich hour angle of the shadow axis, radians.
+    pub hour_angle_rad: f64,
+    /// Penumbral cone radius at the fundamental plane, Earth radii. Always positive.
+    pub l1: f64,
+    /// Umbral (total) or antumbral (annular) cone radius at the fundamental
+    /// plane, Earth radii. Positive means the umbral apex is still beyond
+    /// Earth (a total eclipse is possible there); negative means the apex
+    /// fell short and the cone has crossed over into its antumbral
+    /// continuation (annular).
+    pub l2: f64,
+    /// Apparent angular radius of the Sun as seen from Earth's center, radians.
+    pub sun_angular_radius_rad: f64,
+    /// Apparent angular radius of the Moon as seen from Earth's center, radians.
+    pub moon_angular_radius_rad: f64,
+}
+
+/// Compute the Besselian elements of a solar eclipse at `jde`, from the
+/// geocentric equatorial J2000-of-date directions and distances of the Sun
+/// and Moon.
+///
+/// # Arguments
+/// * `jde` - Julian Date (Ephemeris), used only for the shadow axis's
+///   Greenwich hour angle via [`compute_gmst`].
+/// * `sun_direction` - Geocentric unit direction to the Sun.
+/// * `sun_distance_au` - Geocentric distance to the Sun, AU.
+/// * `moon_direction` - Geocentric unit direction to the Moon.
+/// * `moon_distance_km` - Geocentric distance to the Moon, km.
+pub fn compute_besselian_elements(
+    jde: f64,
+    sun_direction: CartesianCoord,
+    sun_distance_au: f64,
+    moon_direction: CartesianCoord,
+    moon_distance_km: f64,
+) -> BesselianElements {
+    let sun_distance_km = sun_distance_au * AU_KM;
+    let sun_pos = (
+        sun_direction.x * sun_distance_km,
+        sun_direction.y * sun_distance_km,
+        sun_direction.z * sun_distance_km,
+    );
+    let moon_pos = (
+        moon_direction.x * moon_distance_km,
+        moon_direction.y * moon_distance_km,
+        moon_direction.z * moon_distance_km,
+    );
+
+    // Shadow axis: unit vector from the Sun through the Moon, continuing
+    // outward toward wherever on Earth the shadow actually falls.
+    let axis = normalize((
+        moon_pos.0 - sun_pos.0,
+        moon_pos.1 - sun_pos.1,
+        moon_pos.2 - sun_pos.2,
+    ));
+
+    // Fundamental-plane basis, perpendicular to the axis: `east_hat` toward
+    // celestial east, `north_hat` completing a right-handed frame. Degenerates
+    // only if the axis is exactly aligned with the celestial pole, which
+    // never happens for the Sun-Moon line.
+    let pole = (0.0, 0.0, 1.0);
+    let east_hat = normalize(cross(pole, axis));
+    let north_hat = normalize(cross(axis, east_hat));
+
+    // Distance along the axis from the Moon to where it crosses the
+    // fundamental plane (which passes through Earth's center, the origin).
+    let axis_to_plane = -dot(moon_pos, axis);
+    let pierce = (
+        moon_pos.0 + axis_to_plane * axis.0,
+        moon_pos.1 + axis_to_plane * axis.1,
+        moon_pos.2 + axis_to_plane * axis.2,
+    );
+    let x = dot(pierce, east_hat) / EARTH_RADIUS_KM;
+    let y = dot(pierce, north_hat) / EARTH_RADIUS_KM;
+
+    // The axis points Sun -> Moon -> (shadow), so the direction from Earth
+    // back toward the Sun/Moon is `-axis`.
+    let towards_sun = (-axis.0, -axis.1, -axis.2);
+    let declination_rad = towards_sun.2.asin();
+    let ra = towards_sun.1.atan2(towards_sun.0);
+    let hour_angle_rad = (compute_gmst(jde) - ra).rem_euclid(2.0 * std::f64::consts::PI);
+
+    // Cone half-angles from similar triangles on the Sun-Moon line: the
+    // penumbra is the external tangent cone (diverging past the Moon), the
+    // umbra/antumbra the internal tangent cone (converging past the Moon).
+    let sun_moon_distance = dist(sun_pos, moon_pos);
+    let tan_f1 = (SUN_RADIUS_KM + MOON_RADIUS_KM) / sun_moon_distance;
+    let tan_f2 = (SUN_RADIUS_KM - MOON_RADIUS_KM) / sun_moon_distance;
+    let l1 = (MOON_RADIUS_KM + axis_to_plane * tan_f1) / EARTH_RADIUS_KM;
+    let l2 = (MOON_RADIUS_KM - axis_to_plane * tan_f2) / EARTH_RADIUS_KM;
+
+    let sun_angular_radius_rad = (SUN_RADIUS_KM / sun_distance_km).asin();
+    let moon_angular_radius_rad = (MOON_RADIUS_KM / moon_distance_km).asin();
+
+    BesselianElements {
+        x,
+        y,
+        declination_rad,
+        hour_angle_rad,
+        l1,
+        l2,
+        sun_angular_radius_rad,
+        moon_angular_radius_rad,
+    }
+}
+
+fn cross(a: (f64, f64, f64), b: (f64, f64, f64)) -> (f64, f64, f64) {
+    (
+        a.1 * b.2 - a.2 * b.1,
+        a.2 * b.0 - a.0 * b.2,
+        a.0 * b.1 - a.1 * b.0,
+    )
+}
+
+fn dot(a: (f64, f64, f64), b: (f64, f64, f64)) -> f64 {
+    a.0 * b.0 + a.1 * b.1 + a.2 * b.2
+}
+
+fn dist(a: (f64, f64, f64), b: (f64, f64, f64)) -> f64 {
+    ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2) + (a.2 - b.2).powi(2)).sqrt()
+}
+
+fn normalize(v: (f64, f64, f64)) -> (f64, f64, f64) {
+    let len = (v.0 * v.0 + v.1 * v.1 + v.2 * v.2).sqrt();
+    (v.0 / len, v.1 / len, v.2 / len)
+}
+
+/// What kind of solar eclipse, if any, an observer sees at maximum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EclipseType {
+    /// The Moon's penumbra never reaches the observer.
+    None,
+    Partial,
+    Total,
+    Annular,
+}
+
+/// An observer's local circumstances for a solar eclipse.
+#[derive(Debug, Clone, Copy)]
+pub struct LocalCircumstances {
+    pub eclipse_type: EclipseType,
+    /// Julian Date (Ephemeris) of maximum eclipse as seen by this observer.
+    pub max_eclipse_jde: f64,
+    /// Eclipse magnitude at maximum: fraction of the Sun's diameter covered
+    /// by the Moon. Can exceed 1 during totality.
+    pub magnitude: f64,
+    /// Fraction of the Sun's disk *area* covered at maximum, in `[0, 1]`.
+    pub obscuration: f64,
+    /// First contact (penumbra first touches the observer), if any.
+    pub first_contact_jde: Option<f64>,
+    /// Second contact (umbra/antumbra first touches -- totality/annularity
+    /// begins), if the eclipse is total or annular there.
+    pub second_contact_jde: Option<f64>,
+    /// Third contact (umbra/antumbra leaves -- totality/annularity ends).
+    pub third_contact_jde: Option<f64>,
+    /// Fourth contact (penumbra leaves, eclipse ends for this observer).
+    pub fourth_contact_jde: Option<f64>,
+}
+
+/// Distance, in the fundamental plane (Earth radii), between an observer and
+/// the shadow axis at `jde`.
+fn axis_separation(observer: &Observer, besselian: &BesselianElements) -> f64 {
+    let (dx, dy) = observer_plane_offset(observer, besselian);
+    ((besselian.x - dx).powi(2) + (besselian.y - dy).powi(2)).sqrt()
+}
+
+/// Project the observer's geocentric position into the fundamental plane,
+/// following the standard `(ξ, η, ζ)` rotation (Meeus ch. 54): rotate by the
+/// shadow axis's hour angle, then by its declination. `ζ` (the coordinate
+/// along the axis) isn't needed for the in-plane separation, so only `(ξ, η)`
+/// are returned.
+fn observer_plane_offset(observer: &Observer, besselian: &BesselianElements) -> (f64, f64) {
+    let h = besselian.hour_angle_rad + observer.lon_rad;
+    let (sin_h, cos_h) = h.sin_cos();
+    let (sin_lat, cos_lat) = observer.lat_rad.sin_cos();
+    let (sin_d, cos_d) = besselian.declination_rad.sin_cos();
+
+    let xi = cos_lat * sin_h;
+    let eta = sin_lat * cos_d - cos_lat * cos_h * sin_d;
+    (xi, eta)
+}
+
+/// Fraction of the Sun's disk area covered by the Moon, given their angular
+/// radii and the angular separation of their centers (all radians), via the
+/// standard circle-circle overlap area.
+fn disk_overlap_fraction(moon_radius: f64, sun_radius: f64, separation: f64) -> f64 {
+    if separation >= moon_radius + sun_radius {
+        return 0.0;
+    }
+    if separation <= (moon_radius - sun_radius).abs() {
+        return if moon_radius >= sun_radius {
+            1.0
+        } else {
+            (moon_radius / sun_radius).powi(2)
+        };
+    }
+
+    let d = separation;
+    let (r1, r2) = (moon_radius, sun_radius);
+    let part1 = r1 * r1 * ((d * d + r1 * r1 - r2 * r2) / (2.0 * d * r1)).acos();
+    let part2 = r2 * r2 * ((d * d + r2 * r2 - r1 * r1) / (2.0 * d * r2)).acos();
+    let part3 =
+        0.5 * ((-d + r1 + r2) * (d + r1 - r2) * (d - r1 + r2) * (d + r1 + r2)).max(0.0).sqrt();
+    let overlap_area = part1 + part2 - part3;
+
+    (overlap_area / (std::f64::consts::PI * r2 * r2)).clamp(0.0, 1.0)
+}
+
+/// Refine the time of minimum `f` within `[jde_guess - half_width, jde_guess +
+/// half_width]` using golden-section search, the same minimum-finding idiom
+/// [`crate::conjunctions::find_close_approaches`] uses for conjunctions --
+/// robust regardless of how narrow a feature (e.g. a total eclipse's path)
+/// is relative to the bracket, unlike a finite-difference Newton step.
+fn golden_section_minimize(f: impl Fn(f64) -> f64, jde_guess: f64, half_width: f64) -> f64 {
+    const GOLDEN: f64 = 0.6180339887498949; // (sqrt(5) - 1) / 2
+
+    let mut lo = jde_guess - half_width;
+    let mut hi = jde_guess + half_width;
+    let mut c = hi - GOLDEN * (hi - lo);
+    let mut d = lo + GOLDEN * (hi - lo);
+    let mut f_c = f(c);
+    let mut f_d = f(d);
+
+    for _ in 0..60 {
+        if f_c < f_d {
+            hi = d;
+            d = c;
+            f_d = f_c;
+            c = hi - GOLDEN * (hi - lo);
+            f_c = f(c);
+        } else {
+            lo = c;
+            c = d;
+            f_c = f_d;
+            d = lo + GOLDEN * (hi - lo);
+            f_d = f(d);
+        }
+    }
+
+    0.5 * (lo + hi)
+}
+
+/// Differencing/expansion step (days) used to bracket a contact time before
+/// bisecting.
+const CONTACT_SEARCH_STEP_DAYS: f64 = 1.0 / 1440.0; // 1 minute
+/// How many times the bracket step may grow (geometrically, by 1.6x) while
+/// searching for a sign change before giving up.
+const CONTACT_SEARCH_MAX_EXPANSIONS: u32 = 40;
+/// Bisection iterations once a sign-changing bracket is found -- enough for
+/// sub-second precision on a bracket many hours wide.
+const CONTACT_BISECTION_ITERATIONS: u32 = 60;
+
+/// Find where `g(t) = separation(t) - radius(t)` crosses zero moving away
+/// from `t_max` (known to have `g < 0`, i.e. inside the shadow) in the given
+/// `direction` (`-1.0` for before, `1.0` for after). Expands the search step
+/// geometrically until `g` goes non-negative, then bisects. Returns `None`
+/// if `t_max` itself isn't inside the shadow, or no crossing is found.
+fn find_contact(g: impl Fn(f64) -> f64, t_max: f64, direction: f64) -> Option<f64> {
+    if g(t_max) >= 0.0 {
+        return None;
+    }
+
+    let mut inside = t_max;
+    let mut outside = None;
+    let mut step = CONTACT_SEARCH_STEP_DAYS;
+    for _ in 0..CONTACT_SEARCH_MAX_EXPANSIONS {
+        let candidate = t_max + direction * step;
+        if g(candidate) >= 0.0 {
+            outside = Some(candidate);
+            break;
+        }
+        inside = candidate;
+        step *= 1.6;
+    }
+    let mut outside = outside?;
+    let mut inside = inside;
+
+    for _ in 0..CONTACT_BISECTION_ITERATIONS {
+        let mid = 0.5 * (inside + outside);
+        if g(mid) < 0.0 {
+            inside = mid;
+        } else {
+            outside = mid;
+        }
+    }
+    Some(0.5 * (inside + outside))
+}
+
+/// Half-width (days) of the bracket searched around `jde_guess` for the true
+/// moment of maximum eclipse -- generous enough to contain the whole partial
+/// phase of any solar eclipse, yet narrow enough that the separation is
+/// still comfortably unimodal across it.
+const MAX_ECLIPSE_SEARCH_HALF_WIDTH_DAYS: f64 = 0.25; // 6 hours
+
+/// Compute an observer's local circumstances for a solar eclipse whose
+/// Besselian elements vary over time as `besselian_at(jde)`.
+///
+/// `jde_guess` should be within a few hours of maximum eclipse at this
+/// location (e.g. local solar noon, or a coarse conjunction search) --
+/// [`crate::conjunctions::find_close_approaches`] between the Sun and Moon
+/// is a natural source of that guess.
+pub fn local_circumstances(
+    observer: &Observer,
+    jde_guess: f64,
+    besselian_at: impl Fn(f64) -> BesselianElements,
+) -> LocalCircumstances {
+    let separation_at = |t: f64| axis_separation(observer, &besselian_at(t));
+
+    let max_eclipse_jde =
+        golden_section_minimize(separation_at, jde_guess, MAX_ECLIPSE_SEARCH_HALF_WIDTH_DAYS);
+    let besselian = besselian_at(max_eclipse_jde);
+    let m = separation_at(max_eclipse_jde);
+
+    // Angular separation of the Sun/Moon centers as seen from the observer,
+    // approximated from the linear fundamental-plane offset `m` (Earth
+    // radii) via the same Earth-radius/physical-radius ratio used to derive
+    // the Moon's own angular radius: `m` Earth radii of linear miss at the
+    // Moon's distance subtends `m * moon_angular_radius / (moon_radius / earth_radius)`.
+    let angular_separation_rad =
+        m * EARTH_RADIUS_KM * besselian.moon_angular_radius_rad.sin() / MOON_RADIUS_KM;
+
+    let magnitude = (besselian.sun_angular_radius_rad + besselian.moon_angular_radius_rad
+        - angular_separation_rad)
+        / (2.0 * besselian.sun_angular_radius_rad);
+
+    let obscuration = disk_overlap_fraction(
+        besselian.moon_angular_radius_rad,
+        besselian.sun_angular_radius_rad,
+        angular_separation_rad,
+    );
+
+    let eclipse_type = if m > besselian.l1 {
+        EclipseType::None
+    } else if m <= besselian.l2.abs() {
+        if besselian.l2 > 0.0 {
+            EclipseType::Total
+        } else {
+            EclipseType::Annular
+        }
+    } else {
+        EclipseType::Partial
+    };
+
+    let (first_contact_jde, fourth_contact_jde) = if eclipse_type == EclipseType::None {
+        (None, None)
+    } else {
+        let penumbral_edge = |t: f64| separation_at(t) - besselian_at(t).l1;
+        (
+            find_contact(&penumbral_edge, max_eclipse_jde, -1.0),
+            find_contact(&penumbral_edge, max_eclipse_jde, 1.0),
+        )
+    };
+
+    let (second_contact_jde, third_contact_jde) =
+        if matches!(eclipse_type, EclipseType::Total | EclipseType::Annular) {
+            let umbral_edge = |t: f64| separation_at(t) - besselian_at(t).l2.abs();
+            (
+                find_contact(&umbral_edge, max_eclipse_jde, -1.0),
+                find_contact(&umbral_edge, max_eclipse_jde, 1.0),
+            )
+        } else {
+            (None, None)
+        };
+
+    LocalCircumstances {
+        eclipse_type,
+        max_eclipse_jde,
+        magnitude,
+        obscuration,
+        first_contact_jde,
+        second_contact_jde,
+        third_contact_jde,
+        fourth_contact_jde,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sun and Moon exactly aligned at 10° declination (a realistic
+    /// declination, well away from the pole where the fundamental-plane basis
+    /// degenerates) -- the Moon at perigee distance, close enough for a
+    /// (narrow) total eclipse to be geometrically possible.
+    fn aligned_direction() -> CartesianCoord {
+        let dec = 10.0_f64.to_radians();
+        CartesianCoord::new(dec.cos(), 0.0, dec.sin())
+    }
+
+    #[test]
+    fn test_besselian_elements_aligned_bodies_pierce_near_origin() {
+        let dir = aligned_direction();
+        let b = compute_besselian_elements(0.0, dir, 1.0, dir, 370_000.0);
+
+        assert!(b.x.abs() < 1e-9, "x should be ~0 for exactly aligned bodies: {}", b.x);
+        assert!(b.y.abs() < 1e-9, "y should be ~0 for exactly aligned bodies: {}", b.y);
+        assert!(
+            (b.declination_rad - 10.0_f64.to_radians()).abs() < 1e-9,
+            "declination should match the aligned direction: {}",
+            b.declination_rad
+        );
+    }
+
+    #[test]
+    fn test_besselian_elements_near_perigee_allows_total_eclipse() {
+        let dir = aligned_direction();
+        let b = compute_besselian_elements(0.0, dir, 1.0, dir, 370_000.0);
+
+        assert!(b.l2 > 0.0, "a near-perigee Moon should leave the umbral apex beyond Earth: {}", b.l2);
+        assert!(b.l1 > b.l2, "the penumbral cone must be wider than the umbral one: {} vs {}", b.l1, b.l2);
+    }
+
+    #[test]
+    fn test_besselian_elements_near_apogee_forces_annular_eclipse() {
+        let dir = aligned_direction();
+        let b = compute_besselian_elements(0.0, dir, 1.0, dir, 405_000.0);
+
+        assert!(b.l2 < 0.0, "a near-apogee Moon's umbral apex should fall short of Earth: {}", b.l2);
+    }
+
+    /// A synthetic eclipse track: the shadow axis sweeps east-west at a
+    /// constant rate `v` (earth radii/day) past a fixed observer offset
+    /// `miss` (earth radii) perpendicular to the track, with `l1`/`l2` fixed
+    /// at the near-perigee total-eclipse values computed above. This isolates
+    /// [`local_circumstances`]'s contact-search machinery from the geometric
+    /// derivation already covered by the tests above.
+    fn synthetic_total_track(v: f64, miss: f64, t0: f64) -> impl Fn(f64) -> BesselianElements {
+        move |t: f64| BesselianElements {
+            x: v * (t - t0),
+            y: miss,
+            declination_rad: 0.0,
+            hour_angle_rad: 0.0,
+            l1: 0.5436,
+            l2: 0.0025,
+            sun_angular_radius_rad: 0.0046525,
+            moon_angular_radius_rad: 0.0046957,
+        }
+    }
+
+    #[test]
+    fn test_local_circumstances_totality_under_the_track() {
+        let observer = Observer::new(0.0, 0.0, 0.0);
+        let besselian_at = synthetic_total_track(50.0, 0.001, 100.0);
+
+        let lc = local_circumstances(&observer, 100.01, besselian_at);
+
+        assert_eq!(lc.eclipse_type, EclipseType::Total);
+        assert!((lc.max_eclipse_jde - 100.0).abs() < 1e-4, "max eclipse should land near t0: {}", lc.max_eclipse_jde);
+        assert!(lc.magnitude > 1.0, "totality should give magnitude > 1: {}", lc.magnitude);
+        assert!((lc.obscuration - 1.0).abs() < 1e-6, "totality should fully obscure the Sun: {}", lc.obscuration);
+
+        let first = lc.first_contact_jde.expect("partial phase should start");
+        let second = lc.second_contact_jde.expect("totality should begin");
+        let third = lc.third_contact_jde.expect("totality should end");
+        let fourth = lc.fourth_contact_jde.expect("partial phase should end");
+        assert!(first < second && second < third && third < fourth, "contacts out of order: {first} {second} {third} {fourth}");
+        assert!((fourth - first) > (third - second), "totality should be shorter than the full partial span");
+    }
+
+    #[test]
+    fn test_local_circumstances_far_miss_sees_no_eclipse() {
+        let observer = Observer::new(0.0, 0.0, 0.0);
+        let besselian_at = synthetic_total_track(50.0, 5.0, 100.0);
+
+        let lc = local_circumstances(&observer, 100.0, besselian_at);
+
+        assert_eq!(lc.eclipse_type, EclipseType::None);
+        assert!(lc.magnitude < 0.0, "a total miss should give negative magnitude: {}", lc.magnitude);
+        assert_eq!(lc.obscuration, 0.0);
+        assert!(lc.first_contact_jde.is_none());
+        assert!(lc.second_contact_jde.is_none());
+    }
+
+    #[test]
+    fn test_disk_overlap_fraction_matches_boundary_cases() {
+        assert_eq!(disk_overlap_fraction(0.01, 0.01, 0.021), 0.0);
+        assert_eq!(disk_overlap_fraction(0.02, 0.01, 0.0), 1.0);
+        let partial = disk_overlap_fraction(0.01, 0.01, 0.01);
+        assert!(partial > 0.0 && partial < 1.0, "equal-size partial overlap should be strictly between 0 and 1: {partial}");
+    }
+}
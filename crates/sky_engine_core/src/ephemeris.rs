@@ -0,0 +1,263 @@
+//! Top-level ephemeris aggregator.
+//!
+//! Stitches together the Sun, Moon, planets, comets, minor bodies, planetary
+//! moons, and (optionally) satellites into a single object with one
+//! `update(time)` call, so a renderer or simulation can step the whole solar
+//! system forward without invoking each subsystem's `compute_all_*` function
+//! separately.
+
+use crate::comets::{compute_comet_position, Comet, CometPosition};
+use crate::minor_bodies::{compute_minor_body_position, MinorBody, MinorBodyPosition};
+use crate::planetary_moons::{compute_all_planetary_moon_positions, PlanetaryMoon, PlanetaryMoonPosition};
+use crate::planets::{compute_all_body_positions_full, CelestialBody, CelestialBodyPosition, Planet};
+use crate::rise_set::Observer;
+use crate::satellites::{compute_satellite_position, SatelliteEphemeris, SatelliteId, SatellitePosition};
+use crate::time::SkyTime;
+
+/// A read-only view over every cached position an [`Ephemeris`] holds,
+/// returned by [`Ephemeris::all_positions`] for callers that want to iterate
+/// uniformly (e.g. a renderer building a draw list) rather than query each
+/// body category individually.
+#[derive(Debug, Clone, Copy)]
+pub struct EphemerisSnapshot<'a> {
+    pub bodies: &'a [CelestialBodyPosition],
+    pub comets: &'a [CometPosition],
+    pub minor_bodies: &'a [MinorBodyPosition],
+    pub planetary_moons: &'a [PlanetaryMoonPosition],
+    pub satellites: &'a [SatellitePosition],
+}
+
+/// Owns the Sun, Moon, all planets, a configured set of comets and minor
+/// bodies, all bundled planetary moons, and (if an observer is set) a
+/// configured set of satellites. `update` recomputes every configured body
+/// at a new time and caches the results; accessors like [`Ephemeris::planet`]
+/// and [`Ephemeris::all_positions`] read the cache without recomputing.
+pub struct Ephemeris {
+    comets: Vec<Comet>,
+    minor_bodies: Vec<MinorBody>,
+    satellites: Vec<SatelliteEphemeris>,
+    observer: Option<Observer>,
+
+    time: SkyTime,
+    bodies: [CelestialBodyPosition; 9],
+    comet_positions: Vec<CometPosition>,
+    minor_body_positions: Vec<MinorBodyPosition>,
+    planetary_moon_positions: [PlanetaryMoonPosition; 10],
+    satellite_positions: Vec<SatellitePosition>,
+}
+
+impl Ephemeris {
+    /// Create a new ephemeris tracking all planets, the Sun and Moon, every
+    /// bundled comet, minor body, and planetary moon, and no satellites.
+    /// Computes an initial snapshot at `time`.
+    pub fn new(time: SkyTime) -> Self {
+        let mut ephemeris = Self {
+            comets: Comet::ALL.to_vec(),
+            minor_bodies: MinorBody::ALL.to_vec(),
+            satellites: Vec::new(),
+            observer: None,
+            time,
+            bodies: compute_all_body_positions_full(&time),
+            comet_positions: Vec::new(),
+            minor_body_positions: Vec::new(),
+            planetary_moon_positions: compute_all_planetary_moon_positions(&time),
+            satellite_positions: Vec::new(),
+        };
+        ephemeris.update(time);
+        ephemeris
+    }
+
+    /// Restrict the set of comets this ephemeris tracks (defaults to all
+    /// bundled comets). Takes effect on the next `update`.
+    pub fn set_comets(&mut self, comets: Vec<Comet>) {
+        self.comets = comets;
+    }
+
+    /// Restrict the set of minor bodies this ephemeris tracks (defaults to
+    /// all bundled ones). Takes effect on the next `update`.
+    pub fn set_minor_bodies(&mut self, minor_bodies: Vec<MinorBody>) {
+        self.minor_bodies = minor_bodies;
+    }
+
+    /// Add a satellite ephemeris to track. Satellite positions are only
+    /// computed once an observer is set via `set_observer`, since they're
+    /// inherently topocentric.
+    pub fn add_satellite(&mut self, satellite: SatelliteEphemeris) {
+        self.satellites.push(satellite);
+    }
+
+    /// Set the ground-based observer used to compute topocentric satellite
+    /// positions. Takes effect on the next `update`.
+    pub fn set_observer(&mut self, lat_rad: f64, lon_rad: f64, elevation_m: f64) {
+        self.observer = Some(Observer::new(lat_rad, lon_rad, elevation_m));
+    }
+
+    /// Recompute every configured body at `time`, caching the results.
+    pub fn update(&mut self, time: SkyTime) {
+        self.time = time;
+
+        self.bodies = compute_all_body_positions_full(&time);
+        self.planetary_moon_positions = compute_all_planetary_moon_positions(&time);
+
+        self.comet_positions = self
+            .comets
+            .iter()
+            .map(|&comet| compute_comet_position(comet, &time))
+            .collect();
+
+        self.minor_body_positions = self
+            .minor_bodies
+            .iter()
+            .map(|&body| compute_minor_body_position(body, &time))
+            .collect();
+
+        self.satellite_positions = match &self.observer {
+            Some(observer) => {
+                let height_km = observer.elevation_m / 1000.0;
+                self.satellites
+                    .iter()
+                    .filter_map(|ephemeris| {
+                        compute_satellite_position(
+                            ephemeris,
+                            &time,
+                            observer.lat_rad,
+                            observer.lon_rad,
+                            height_km,
+                        )
+                    })
+                    .collect()
+            }
+            None => Vec::new(),
+        };
+    }
+
+    /// The time of the current cached snapshot.
+    pub fn time(&self) -> SkyTime {
+        self.time
+    }
+
+    /// The cached position of a specific celestial body (Sun, Moon, or
+    /// planet), if tracked.
+    pub fn body(&self, body: CelestialBody) -> Option<&CelestialBodyPosition> {
+        self.bodies.iter().find(|p| p.body == body)
+    }
+
+    /// The cached position of a specific planet. Returns `None` for
+    /// `Planet::Earth`, which has no geocentric position of its own, and for
+    /// `Planet::Pluto`, which isn't part of this VSOP87A-driven snapshot
+    /// (see `compute_pluto_position_full`).
+    pub fn planet(&self, planet: Planet) -> Option<&CelestialBodyPosition> {
+        let body = match planet {
+            Planet::Mercury => CelestialBody::Mercury,
+            Planet::Venus => CelestialBody::Venus,
+            Planet::Earth => return None,
+            Planet::Mars => CelestialBody::Mars,
+            Planet::Jupiter => CelestialBody::Jupiter,
+            Planet::Saturn => CelestialBody::Saturn,
+            Planet::Uranus => CelestialBody::Uranus,
+            Planet::Neptune => CelestialBody::Neptune,
+            Planet::Pluto => return None,
+        };
+        self.body(body)
+    }
+
+    /// The cached position of a specific comet, if tracked (see `set_comets`).
+    pub fn comet(&self, comet: Comet) -> Option<&CometPosition> {
+        self.comet_positions.iter().find(|p| p.comet == comet)
+    }
+
+    /// The cached position of a specific minor body, if tracked (see
+    /// `set_minor_bodies`).
+    pub fn minor_body(&self, body: MinorBody) -> Option<&MinorBodyPosition> {
+        self.minor_body_positions.iter().find(|p| p.body == body)
+    }
+
+    /// The cached position of a specific planetary moon.
+    pub fn planetary_moon(&self, moon: PlanetaryMoon) -> Option<&PlanetaryMoonPosition> {
+        self.planetary_moon_positions.iter().find(|p| p.moon == moon)
+    }
+
+    /// The cached position of a specific tracked satellite, if an observer
+    /// is set and that satellite's ephemeris covers `time`.
+    pub fn satellite(&self, id: SatelliteId) -> Option<&SatellitePosition> {
+        self.satellite_positions.iter().find(|p| p.id == id)
+    }
+
+    /// A uniform view over every cached position, for renderers or
+    /// simulation loops that want to iterate across all body categories at
+    /// once instead of querying each accessor.
+    pub fn all_positions(&self) -> EphemerisSnapshot<'_> {
+        EphemerisSnapshot {
+            bodies: &self.bodies,
+            comets: &self.comet_positions,
+            minor_bodies: &self.minor_body_positions,
+            planetary_moons: &self.planetary_moon_positions,
+            satellites: &self.satellite_positions,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_populates_all_default_categories() {
+        let time = SkyTime::from_utc(2024, 6, 1, 0, 0, 0.0);
+        let ephemeris = Ephemeris::new(time);
+
+        let snapshot = ephemeris.all_positions();
+        assert_eq!(snapshot.bodies.len(), 9);
+        assert_eq!(snapshot.comets.len(), Comet::ALL.len());
+        assert_eq!(snapshot.minor_bodies.len(), MinorBody::ALL.len());
+        assert_eq!(snapshot.planetary_moons.len(), 10);
+        assert!(snapshot.satellites.is_empty());
+    }
+
+    #[test]
+    fn test_planet_accessor_matches_direct_computation() {
+        let time = SkyTime::from_utc(2024, 6, 1, 0, 0, 0.0);
+        let ephemeris = Ephemeris::new(time);
+
+        let mars = ephemeris.planet(Planet::Mars).expect("Mars should be tracked");
+        assert_eq!(mars.body, CelestialBody::Mars);
+        assert!(mars.distance_km > 0.0);
+
+        assert!(ephemeris.planet(Planet::Earth).is_none());
+    }
+
+    #[test]
+    fn test_update_advances_cached_time_and_positions() {
+        let t0 = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0);
+        let t1 = SkyTime::from_utc(2024, 7, 1, 0, 0, 0.0);
+
+        let mut ephemeris = Ephemeris::new(t0);
+        let mars_jan = ephemeris.planet(Planet::Mars).unwrap().direction;
+
+        ephemeris.update(t1);
+        let mars_jul = ephemeris.planet(Planet::Mars).unwrap().direction;
+
+        assert!((ephemeris.time().julian_date_utc() - t1.julian_date_utc()).abs() < 1e-6);
+        // Mars should have moved noticeably over six months.
+        let dot = mars_jan.x * mars_jul.x + mars_jan.y * mars_jul.y + mars_jan.z * mars_jul.z;
+        assert!(dot < 0.999, "Mars direction should change materially over 6 months");
+    }
+
+    #[test]
+    fn test_restricting_comets_and_minor_bodies() {
+        let time = SkyTime::from_utc(2024, 6, 1, 0, 0, 0.0);
+        let mut ephemeris = Ephemeris::new(time);
+
+        ephemeris.set_comets(vec![Comet::Halley]);
+        ephemeris.set_minor_bodies(vec![MinorBody::Pluto, MinorBody::Ceres]);
+        ephemeris.update(time);
+
+        assert_eq!(ephemeris.all_positions().comets.len(), 1);
+        assert!(ephemeris.comet(Comet::Halley).is_some());
+        assert!(ephemeris.comet(Comet::Encke).is_none());
+
+        assert_eq!(ephemeris.all_positions().minor_bodies.len(), 2);
+        assert!(ephemeris.minor_body(MinorBody::Pluto).is_some());
+        assert!(ephemeris.minor_body(MinorBody::Sedna).is_none());
+    }
+}
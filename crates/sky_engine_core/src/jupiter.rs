@@ -0,0 +1,190 @@
+//! Jupiter's physical ephemeris: System I/II central-meridian longitudes and
+//! the geocentric position angle of its north rotation pole (Meeus,
+//! *Astronomical Algorithms*, ch. 43) -- the quantities needed to label the
+//! Great Red Spot and cloud belts on a rendered disk of the planet.
+//!
+//! This is a Jupiter-specific companion to [`crate::rotation`]'s generic
+//! WGCCRE machinery: System I/II use a different (older, pre-WGCCRE) pole
+//! and rotation model than System III (`rotation::JUPITER_ROTATION`), so
+//! they're computed here with their own [`RotationalElements`] rather than
+//! by looking one up.
+
+use crate::coords::{cartesian_to_ra_dec, CartesianCoord, OBLIQUITY_J2000};
+use crate::planets::{
+    compute_planet_position_apparent, heliocentric_position, light_time_corrected_geocentric,
+    rotate_ecliptic_vector_to_equatorial, Planet,
+};
+use crate::rotation::{sub_point, RotationalElements};
+use crate::time::SkyTime;
+use std::f64::consts::PI;
+
+/// Jupiter's System I pole/rotation model (low-precision, Meeus ch. 43):
+/// equatorial belts, rotating with the atmosphere near the equator.
+const JUPITER_SYSTEM_1: RotationalElements = RotationalElements {
+    alpha0_deg: 268.00,
+    alpha0_rate_deg_per_century: 0.1061,
+    delta0_deg: 64.50,
+    delta0_rate_deg_per_century: -0.0164,
+    w0_deg: 17.710,
+    w_rate_deg_per_day: 877.90003539,
+};
+
+/// Jupiter's System II pole/rotation model (low-precision, Meeus ch. 43):
+/// everything outside the equatorial zone, including the Great Red Spot's
+/// long-term drift frame.
+const JUPITER_SYSTEM_2: RotationalElements = RotationalElements {
+    alpha0_deg: 268.00,
+    alpha0_rate_deg_per_century: 0.1061,
+    delta0_deg: 64.50,
+    delta0_rate_deg_per_century: -0.0164,
+    w0_deg: 16.838,
+    w_rate_deg_per_day: 870.27003539,
+};
+
+/// Physical ephemeris of Jupiter at a given instant.
+#[derive(Debug, Clone, Copy)]
+pub struct JupiterPhysical {
+    /// Planetocentric declination of the Sun, radians (`D_S`): which
+    /// hemisphere of Jupiter the Sun illuminates.
+    pub sun_declination_rad: f64,
+    /// Planetocentric declination of Earth, radians (`D_E`): which
+    /// hemisphere of Jupiter faces Earth.
+    pub earth_declination_rad: f64,
+    /// System I central-meridian longitude of the illuminated disk as seen
+    /// from Earth, radians (`ω1`).
+    pub system_1_longitude_rad: f64,
+    /// System II central-meridian longitude of the illuminated disk as seen
+    /// from Earth, radians (`ω2`).
+    pub system_2_longitude_rad: f64,
+    /// Geocentric position angle of Jupiter's north rotation pole, measured
+    /// eastward from celestial north, radians (`P`).
+    pub pole_position_angle_rad: f64,
+}
+
+/// Compute Jupiter's physical ephemeris (System I/II central-meridian
+/// longitudes, planetocentric Sun/Earth declinations, and pole position
+/// angle) as seen from Earth at `time`.
+pub fn compute_jupiter_physical(time: &SkyTime) -> JupiterPhysical {
+    let jde = time.julian_date_tdb();
+
+    // d and T1 are referred to the epoch 1950 Jan 1 (JDE 2433282.5) that
+    // Meeus ch. 43 defines System I/II against -- distinct from the J2000.0
+    // epoch `rotation::RotationalElements` normally uses, but `pole()` and
+    // `prime_meridian_angle()` only need consistent (value-at-epoch, offset)
+    // pairs, not specifically J2000.0 ones.
+    let d = jde - 2433282.5;
+    let t1 = d / 36525.0;
+
+    let earth_pos = heliocentric_position(Planet::Earth, jde);
+    let (geo, _distance_au) =
+        light_time_corrected_geocentric(|t| heliocentric_position(Planet::Jupiter, t), earth_pos, jde);
+    let jupiter_pos = (
+        geo.0 + earth_pos.0,
+        geo.1 + earth_pos.1,
+        geo.2 + earth_pos.2,
+    );
+
+    // Jupiter-to-Earth and Jupiter-to-Sun vectors, ecliptic then equatorial
+    // (the Sun sits at the coordinate origin of this heliocentric model).
+    let jupiter_to_earth_ecl = (-geo.0, -geo.1, -geo.2);
+    let jupiter_to_sun_ecl = (-jupiter_pos.0, -jupiter_pos.1, -jupiter_pos.2);
+    let jupiter_to_earth_eq =
+        rotate_ecliptic_vector_to_equatorial(jupiter_to_earth_ecl, OBLIQUITY_J2000);
+    let jupiter_to_sun_eq =
+        rotate_ecliptic_vector_to_equatorial(jupiter_to_sun_ecl, OBLIQUITY_J2000);
+    let jupiter_to_earth_eq = CartesianCoord::new(
+        jupiter_to_earth_eq.0,
+        jupiter_to_earth_eq.1,
+        jupiter_to_earth_eq.2,
+    );
+    let jupiter_to_sun_eq = CartesianCoord::new(
+        jupiter_to_sun_eq.0,
+        jupiter_to_sun_eq.1,
+        jupiter_to_sun_eq.2,
+    );
+
+    // DE is the same regardless of which system's prime meridian we use
+    // (both share the same pole), so only System I needs to report it.
+    let (system_1_longitude_rad, earth_declination_rad) =
+        sub_point(&jupiter_to_earth_eq, &JUPITER_SYSTEM_1, t1, d);
+    let (system_2_longitude_rad, _de_again) =
+        sub_point(&jupiter_to_earth_eq, &JUPITER_SYSTEM_2, t1, d);
+    let (_sub_solar_lon, sun_declination_rad) =
+        sub_point(&jupiter_to_sun_eq, &JUPITER_SYSTEM_1, t1, d);
+
+    let pole_position_angle_rad = pole_position_angle(time, t1);
+
+    JupiterPhysical {
+        sun_declination_rad,
+        earth_declination_rad,
+        system_1_longitude_rad,
+        system_2_longitude_rad,
+        pole_position_angle_rad,
+    }
+}
+
+/// Geocentric position angle of Jupiter's north rotation pole, measured
+/// eastward from celestial north (the same construction Meeus uses for the
+/// Sun's and Mars's axis position angles, ch. 42): given the pole's apparent
+/// right ascension/declination `(α0, δ0)` and Jupiter's own apparent
+/// right ascension/declination `(α, δ)`,
+/// `tan P = cos δ0 sin(α0 − α) / (sin δ0 cos δ − cos δ0 sin δ cos(α0 − α))`.
+fn pole_position_angle(time: &SkyTime, t1_centuries: f64) -> f64 {
+    let (pole_ra, pole_dec) = JUPITER_SYSTEM_1.pole(t1_centuries);
+    let apparent = compute_planet_position_apparent(Planet::Jupiter, time);
+    let (jupiter_ra, jupiter_dec) = cartesian_to_ra_dec(&apparent.direction);
+
+    let delta_ra = pole_ra - jupiter_ra;
+    let numerator = pole_dec.cos() * delta_ra.sin();
+    let denominator =
+        pole_dec.sin() * jupiter_dec.cos() - pole_dec.cos() * jupiter_dec.sin() * delta_ra.cos();
+    numerator.atan2(denominator).rem_euclid(2.0 * PI)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_declinations_within_jupiter_orbital_tilt() {
+        // Jupiter's equator is tilted only ~3.1 degrees to its orbital
+        // plane, and its orbit is inclined ~1.3 degrees to the ecliptic, so
+        // DS and DE should stay within a few degrees of zero.
+        let time = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0);
+        let eph = compute_jupiter_physical(&time);
+        assert!(
+            eph.sun_declination_rad.abs() < 5f64.to_radians(),
+            "DS out of range: {}",
+            eph.sun_declination_rad.to_degrees()
+        );
+        assert!(
+            eph.earth_declination_rad.abs() < 5f64.to_radians(),
+            "DE out of range: {}",
+            eph.earth_declination_rad.to_degrees()
+        );
+    }
+
+    #[test]
+    fn test_system_longitudes_are_wrapped() {
+        let time = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0);
+        let eph = compute_jupiter_physical(&time);
+        assert!((0.0..2.0 * PI).contains(&eph.system_1_longitude_rad));
+        assert!((0.0..2.0 * PI).contains(&eph.system_2_longitude_rad));
+    }
+
+    #[test]
+    fn test_system_1_and_2_longitudes_differ() {
+        // Systems I and II rotate at different rates, so at a given instant
+        // (away from the rare moment they coincide) they should disagree.
+        let time = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0);
+        let eph = compute_jupiter_physical(&time);
+        assert!((eph.system_1_longitude_rad - eph.system_2_longitude_rad).abs() > 1e-6);
+    }
+
+    #[test]
+    fn test_pole_position_angle_is_wrapped() {
+        let time = SkyTime::from_utc(2024, 6, 1, 0, 0, 0.0);
+        let eph = compute_jupiter_physical(&time);
+        assert!((0.0..2.0 * PI).contains(&eph.pole_position_angle_rad));
+    }
+}
@@ -1,11 +1,20 @@
 pub mod catalog;
 pub mod comets;
+pub mod conjunctions;
 pub mod coords;
+pub mod eclipses;
+pub mod ephemeris;
+pub mod jupiter;
 pub mod minor_bodies;
+pub mod orbit;
 pub mod planetary_moons;
 pub mod planets;
+pub mod rise_set;
+pub mod rotation;
 pub mod satellites;
 pub mod time;
+pub mod visibility;
+pub mod vsop87b;
 
 // Legacy module alias for backwards compatibility
 pub mod iss {
@@ -15,31 +24,72 @@ pub mod iss {
     };
 }
 
-pub use catalog::{Star, StarCatalog};
+pub use catalog::{
+    apply_pm, load_versioned_catalog, write_indexed_catalog, write_versioned_catalog, Catalog,
+    CatalogHeaderError, Disposition, IndexedStarCatalog, MergeStats, SourceCatalog, Star,
+    StarCatalog, VersionedCatalog,
+};
+#[cfg(feature = "xz")]
+pub use catalog::write_versioned_catalog_compressed;
 pub use comets::{
-    compute_all_comet_positions, compute_comet_position,
-    Comet, CometElements, CometPosition,
+    compute_all_comet_positions, compute_comet_position, compute_comet_position_astrometric,
+    compute_comet_position_from_elements, Comet, CometElements, CometPosition, CustomCometPosition,
+};
+pub use conjunctions::{find_close_approaches, CloseApproachEvent};
+pub use coords::{
+    apply_corrections, apply_light_time_correction, compute_gravitational_deflection,
+    compute_refraction, moon_position, precess_j2000_to_date, precession_matrix,
+    ra_dec_to_cartesian, sun_position, CartesianCoord, CorrectionFlags,
+};
+pub use eclipses::{
+    compute_besselian_elements, local_circumstances, BesselianElements, EclipseType,
+    LocalCircumstances,
 };
-pub use coords::{ra_dec_to_cartesian, CartesianCoord};
+pub use ephemeris::{Ephemeris, EphemerisSnapshot};
+pub use jupiter::{compute_jupiter_physical, JupiterPhysical};
 pub use minor_bodies::{
-    compute_all_minor_body_positions, compute_minor_body_position,
-    MinorBody, MinorBodyPosition,
+    compute_all_minor_body_positions, compute_minor_body_position, compute_minor_body_position_apparent,
+    compute_minor_body_position_from_elements, CustomMinorBodyPosition, MinorBody,
+    MinorBodyPosition, OrbitalElements,
 };
+pub use orbit::{compute_custom_body_position, CustomBodyPosition, KeplerianElements};
 pub use planetary_moons::{
-    compute_all_planetary_moon_positions, compute_planetary_moon_position,
-    PlanetaryMoon, PlanetaryMoonPosition,
+    compute_all_planetary_moon_positions, compute_galilean_position_perturbed,
+    compute_moon_phenomena, compute_planetary_moon_position,
+    compute_planetary_moon_position_apparent, compute_planetary_moon_position_with_theory,
+    compute_satellites, compute_sun_direction, observer_on, pole_at, EventPhase, LineOfSight,
+    MoonPhenomenon, MoonPhenomenonKind, MoonTheory, PlanetaryMoon, PlanetaryMoonPosition,
+    PlanetocentricPosition, SatelliteOffset,
 };
 pub use planets::{
-    compute_all_body_positions, compute_moon_position_full, compute_planet_position,
-    CelestialBody, MoonPosition, Planet,
+    apparent_position, compute_all_body_positions, compute_all_body_positions_full,
+    compute_moon_position_full, compute_planet_position, compute_planet_position_apparent,
+    compute_planet_position_with_precision, compute_pluto_position_full,
+    compute_sun_position_apparent, saturn_ring_ephemeris, ApparentPosition, CelestialBody,
+    CelestialBodyPosition, MoonPhaseName, MoonPosition, Planet, PositionPrecision, RingEphemeris,
+};
+pub use rise_set::{
+    equatorial_to_horizontal, rise_set_transit, rise_set_transit_for_body, HorizontalCoord,
+    Observer, RiseSetTransit, TwilightMode,
+};
+pub use rotation::{
+    body_fixed_to_j2000, hg_magnitude, j2000_to_body_fixed, physical_ephemeris,
+    rotational_elements_for, PhysicalEphemeris, RotationalElements,
+};
+pub use time::{calendar_system_for, CalendarSystem, LocalSolarTime, SkyTime};
+pub use vsop87b::{heliocentric_ecliptic, heliocentric_to_j2000_equatorial};
+pub use visibility::{
+    line_of_sight_clear, link_available, next_pass, predict_passes, predict_transits,
+    sun_background, BackgroundBody, IssPass, SatellitePass, TransitEvent,
 };
-pub use time::SkyTime;
 
 // Legacy ISS exports for backwards compatibility
 pub use iss::{compute_iss_position, IssEphemeris, IssEphemerisPoint, IssPosition};
 
 // New satellite exports
 pub use satellites::{
-    compute_satellite_position, SatelliteEphemeris, SatelliteEphemerisPoint,
-    SatelliteId, SatellitePosition,
+    compute_satellite_position, compute_satellite_position_from_source,
+    compute_satellite_position_from_tle, predict_satellite_passes, propagate_tle,
+    SatelliteEphemeris, SatelliteEphemerisPoint, SatelliteId, SatellitePassEvent, SatellitePosition,
+    SatelliteSource, ShadowState, Tle,
 };
@@ -1,10 +1,21 @@
-//! Minor body ephemeris calculations (dwarf planets, asteroids, comets).
+//! Minor body ephemeris calculations (dwarf planets, asteroids).
 //!
 //! Implements heliocentric Keplerian orbits for solar system bodies not covered
 //! by VSOP87. Uses JPL orbital elements with proper 3D orbital plane orientation.
+//!
+//! [`OrbitalElements`] is parametrized by mean anomaly at a fixed epoch and
+//! only covers bound elliptical orbits (e < 1), which is what every
+//! catalogued asteroid and dwarf planet needs. Comets -- including
+//! near-parabolic and hyperbolic ones, parametrized by perihelion distance
+//! and time of perihelion passage instead -- are handled by the sibling
+//! [`crate::comets`] module, which keeps its own element type and solvers
+//! rather than overloading this one.
 
 use crate::coords::{ecliptic_to_equatorial, true_obliquity, CartesianCoord};
-use crate::planets::{Planet, AU_TO_KM};
+use crate::planets::{
+    apply_stellar_aberration, earth_heliocentric_velocity_au_per_day, light_time_corrected_geocentric,
+    rotate_ecliptic_vector_to_equatorial, Planet, AU_TO_KM,
+};
 use crate::time::SkyTime;
 use std::f64::consts::PI;
 
@@ -30,10 +41,29 @@ pub struct OrbitalElements {
     pub mean_motion_rad_per_day: f64,
     /// Body radius in km (for angular diameter calculation)
     pub radius_km: f64,
+    /// IAU H-G absolute magnitude (magnitude at zero phase angle, 1 AU from
+    /// both the Sun and the observer).
+    pub abs_mag_h: f64,
+    /// IAU H-G slope parameter G, typically 0.0-0.5 (0.15 is the assumed
+    /// default for asteroids with no measured phase curve).
+    pub slope_g: f64,
+    /// Rate of change of semi-major axis, AU per Julian century. Zero unless
+    /// set via [`Self::with_element_rates`].
+    pub d_semi_major_axis: f64,
+    /// Rate of change of eccentricity, per Julian century.
+    pub d_eccentricity: f64,
+    /// Rate of change of inclination, radians per Julian century.
+    pub d_inclination: f64,
+    /// Rate of change of longitude of ascending node, radians per Julian
+    /// century.
+    pub d_ascending_node: f64,
+    /// Rate of change of argument of perihelion, radians per Julian century.
+    pub d_arg_perihelion: f64,
 }
 
 impl OrbitalElements {
     /// Create orbital elements from degrees (convenience constructor).
+    #[allow(clippy::too_many_arguments)]
     pub const fn from_degrees(
         name: &'static str,
         semi_major_axis_au: f64,
@@ -44,6 +74,8 @@ impl OrbitalElements {
         mean_anomaly_j2000_deg: f64,
         orbital_period_years: f64,
         radius_km: f64,
+        abs_mag_h: f64,
+        slope_g: f64,
     ) -> Self {
         let deg_to_rad = PI / 180.0;
         Self {
@@ -57,8 +89,276 @@ impl OrbitalElements {
             // Mean motion = 2π / period (in days)
             mean_motion_rad_per_day: 2.0 * PI / (orbital_period_years * 365.25),
             radius_km,
+            abs_mag_h,
+            slope_g,
+            d_semi_major_axis: 0.0,
+            d_eccentricity: 0.0,
+            d_inclination: 0.0,
+            d_ascending_node: 0.0,
+            d_arg_perihelion: 0.0,
         }
     }
+
+    /// Attach JPL-style linear element rates (per Julian century) to these
+    /// elements, so [`compute_heliocentric_ecliptic`] advances `a`, `e`,
+    /// `i`, `Ω`, and `ω` linearly in time instead of holding them fixed at
+    /// their J2000 values -- useful for TNOs and multi-decade spans, where
+    /// node/perihelion precession is otherwise ignored. Chain onto
+    /// [`Self::from_degrees`] or [`Self::from_mpc_elements`]; bodies that
+    /// never call this keep all rates at zero and propagate exactly as
+    /// before.
+    pub const fn with_element_rates(
+        mut self,
+        d_semi_major_axis: f64,
+        d_eccentricity: f64,
+        d_inclination_deg: f64,
+        d_ascending_node_deg: f64,
+        d_arg_perihelion_deg: f64,
+    ) -> Self {
+        let deg_to_rad = PI / 180.0;
+        self.d_semi_major_axis = d_semi_major_axis;
+        self.d_eccentricity = d_eccentricity;
+        self.d_inclination = d_inclination_deg * deg_to_rad;
+        self.d_ascending_node = d_ascending_node_deg * deg_to_rad;
+        self.d_arg_perihelion = d_arg_perihelion_deg * deg_to_rad;
+        self
+    }
+
+    /// Build elements from an MPC/JPL-style osculating element set supplied
+    /// at runtime -- e.g. a newly numbered asteroid -- rather than one of
+    /// the `pub const` definitions above. `name` is leaked to a `&'static
+    /// str` so a runtime-ingested body can share this same representation;
+    /// that's fine for the handful of objects a caller registers this way,
+    /// but don't call this in a hot loop.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_mpc_elements(
+        name: String,
+        semi_major_axis_au: f64,
+        eccentricity: f64,
+        inclination_deg: f64,
+        ascending_node_deg: f64,
+        arg_perihelion_deg: f64,
+        mean_anomaly_j2000_deg: f64,
+        orbital_period_years: f64,
+        radius_km: f64,
+        abs_mag_h: f64,
+        slope_g: f64,
+    ) -> Self {
+        Self::from_degrees(
+            Box::leak(name.into_boxed_str()),
+            semi_major_axis_au,
+            eccentricity,
+            inclination_deg,
+            ascending_node_deg,
+            arg_perihelion_deg,
+            mean_anomaly_j2000_deg,
+            orbital_period_years,
+            radius_km,
+            abs_mag_h,
+            slope_g,
+        )
+    }
+
+    /// Parse a single line of either a comma-separated JPL Small-Body
+    /// Database element export or a fixed-width 80-column MPC orbit-export
+    /// line -- the two are told apart by whether the line contains a comma,
+    /// same convention as [`crate::comets::CometElements::from_mpc_line`].
+    /// Returns an owned element set built through [`Self::from_mpc_elements`],
+    /// so the same `Box::leak` tradeoff applies: fine for a handful of
+    /// newly-discovered objects, not for a hot loop. Feed the result into
+    /// [`compute_position_for_elements`] to get a position.
+    pub fn from_mpc_line(line: &str) -> Result<OrbitalElements, &'static str> {
+        if line.contains(',') {
+            Self::from_sbdb_csv_line(line)
+        } else {
+            Self::from_mpc_fixed_width_line(line)
+        }
+    }
+
+    /// Parse a comma-separated JPL Small-Body Database element export line.
+    /// Expects exactly this column order: designation, epoch (JD, TDB),
+    /// eccentricity, semi-major axis (AU), inclination (deg), longitude of
+    /// ascending node (deg), argument of perihelion (deg), mean anomaly at
+    /// epoch (deg), absolute magnitude H, slope parameter G. Mean anomaly is
+    /// advanced (or rewound) from the quoted epoch to J2000 using the mean
+    /// motion derived from `a` via Kepler's third law.
+    fn from_sbdb_csv_line(line: &str) -> Result<OrbitalElements, &'static str> {
+        let fields: Vec<&str> = line.trim().split(',').map(str::trim).collect();
+        if fields.len() < 10 {
+            return Err("SBDB element line must have at least 10 comma-separated fields");
+        }
+
+        let name = fields[0].to_string();
+        let epoch_jd: f64 = fields[1].parse().map_err(|_| "invalid epoch field")?;
+        let eccentricity: f64 = fields[2].parse().map_err(|_| "invalid eccentricity field")?;
+        let semi_major_axis_au: f64 =
+            fields[3].parse().map_err(|_| "invalid semi-major axis field")?;
+        let inclination_deg: f64 = fields[4].parse().map_err(|_| "invalid inclination field")?;
+        let ascending_node_deg: f64 =
+            fields[5].parse().map_err(|_| "invalid ascending node field")?;
+        let arg_perihelion_deg: f64 =
+            fields[6].parse().map_err(|_| "invalid argument of perihelion field")?;
+        let mean_anomaly_epoch_deg: f64 =
+            fields[7].parse().map_err(|_| "invalid mean anomaly field")?;
+        let abs_mag_h: f64 = fields[8].parse().map_err(|_| "invalid absolute magnitude field")?;
+        let slope_g: f64 = fields[9].parse().map_err(|_| "invalid slope parameter field")?;
+
+        let orbital_period_years = orbital_period_years_from_semi_major_axis(semi_major_axis_au);
+        let mean_anomaly_j2000_deg = advance_mean_anomaly_to_j2000(
+            mean_anomaly_epoch_deg,
+            epoch_jd,
+            orbital_period_years,
+        );
+
+        Ok(Self::from_mpc_elements(
+            name,
+            semi_major_axis_au,
+            eccentricity,
+            inclination_deg,
+            ascending_node_deg,
+            arg_perihelion_deg,
+            mean_anomaly_j2000_deg,
+            orbital_period_years,
+            0.0, // Radius unknown from this export; caller can set separately.
+            abs_mag_h,
+            slope_g,
+        ))
+    }
+
+    /// Parse a fixed-width 80-column MPC orbit-export line (the MPCORB
+    /// format documented at minorplanetcenter.net). Reads H, G, the packed
+    /// epoch, M, Peri, Node, Incl, e, mean daily motion n, semi-major axis
+    /// a, and the readable designation; mean anomaly is advanced (or
+    /// rewound) from the packed epoch to J2000 using the quoted `n`.
+    fn from_mpc_fixed_width_line(line: &str) -> Result<OrbitalElements, &'static str> {
+        if !line.is_ascii() {
+            return Err("MPC orbit line must be ASCII");
+        }
+
+        // 1-indexed, inclusive column ranges, matching the documented
+        // format; short or missing trailing columns just read as empty.
+        let field = |start: usize, end: usize| -> String {
+            let len = line.len();
+            if start > len {
+                return String::new();
+            }
+            line[start - 1..end.min(len)].trim().to_string()
+        };
+
+        let abs_mag_h: f64 = field(9, 13).parse().map_err(|_| "invalid absolute magnitude field")?;
+        let slope_g: f64 = field(15, 19).parse().unwrap_or(0.15);
+        let epoch_packed = field(21, 25);
+        let mean_anomaly_epoch_deg: f64 =
+            field(27, 35).parse().map_err(|_| "invalid mean anomaly field")?;
+        let arg_perihelion_deg: f64 =
+            field(38, 46).parse().map_err(|_| "invalid argument of perihelion field")?;
+        let ascending_node_deg: f64 =
+            field(49, 57).parse().map_err(|_| "invalid ascending node field")?;
+        let inclination_deg: f64 = field(60, 68).parse().map_err(|_| "invalid inclination field")?;
+        let eccentricity: f64 = field(71, 79).parse().map_err(|_| "invalid eccentricity field")?;
+        let mean_daily_motion_deg: f64 =
+            field(81, 91).parse().map_err(|_| "invalid mean daily motion field")?;
+        let semi_major_axis_au: f64 =
+            field(93, 103).parse().map_err(|_| "invalid semi-major axis field")?;
+        let readable_designation = field(167, 194);
+
+        let name = if readable_designation.is_empty() {
+            field(1, 7)
+        } else {
+            readable_designation
+        };
+
+        let epoch_jd = decode_packed_epoch(&epoch_packed)?;
+        let orbital_period_years = 360.0 / mean_daily_motion_deg / 365.25;
+        let mean_anomaly_j2000_deg = advance_mean_anomaly_to_j2000(
+            mean_anomaly_epoch_deg,
+            epoch_jd,
+            orbital_period_years,
+        );
+
+        Ok(Self::from_mpc_elements(
+            name,
+            semi_major_axis_au,
+            eccentricity,
+            inclination_deg,
+            ascending_node_deg,
+            arg_perihelion_deg,
+            mean_anomaly_j2000_deg,
+            orbital_period_years,
+            0.0, // Radius isn't part of the orbit-export format.
+            abs_mag_h,
+            slope_g,
+        ))
+    }
+}
+
+/// Orbital period in years from semi-major axis via Kepler's third law,
+/// `P = 365.2568984 * a^1.5` days (Gaussian gravitational constant, Sun-only
+/// two-body approximation).
+fn orbital_period_years_from_semi_major_axis(semi_major_axis_au: f64) -> f64 {
+    365.2568984 * semi_major_axis_au.powf(1.5) / 365.25
+}
+
+/// Advance (or rewind) a mean anomaly quoted at `epoch_jd` to its equivalent
+/// value at the J2000.0 epoch (JD 2451545.0), using the mean motion implied
+/// by `orbital_period_years`. Normalizes the result to `[0, 360)`.
+fn advance_mean_anomaly_to_j2000(
+    mean_anomaly_epoch_deg: f64,
+    epoch_jd: f64,
+    orbital_period_years: f64,
+) -> f64 {
+    let mean_motion_deg_per_day = 360.0 / (orbital_period_years * 365.25);
+    let advanced = mean_anomaly_epoch_deg + mean_motion_deg_per_day * (2451545.0 - epoch_jd);
+    advanced.rem_euclid(360.0)
+}
+
+/// Decode a 5-character MPC packed epoch (e.g. `"K239L"`) into a Julian
+/// Date (TDB, midnight UT): a century letter (I/J/K = 1800/1900/2000), a
+/// 2-digit year within that century, a month character (1-9 for Jan-Sep,
+/// A/B/C for Oct/Nov/Dec), and a day character (1-9 for the 1st-9th, A-V
+/// for the 10th-31st).
+fn decode_packed_epoch(packed: &str) -> Result<f64, &'static str> {
+    let chars: Vec<char> = packed.trim().chars().collect();
+    if chars.len() != 5 {
+        return Err("packed epoch must be exactly 5 characters");
+    }
+
+    let century = match chars[0] {
+        'I' => 1800,
+        'J' => 1900,
+        'K' => 2000,
+        _ => return Err("packed epoch has an unrecognized century letter"),
+    };
+    let year_in_century: i32 = packed[1..3]
+        .parse()
+        .map_err(|_| "packed epoch has a non-numeric year")?;
+    let year = century + year_in_century;
+
+    let month = match chars[3] {
+        '1'..='9' => chars[3] as u8 - b'1' + 1,
+        'A' => 10,
+        'B' => 11,
+        'C' => 12,
+        _ => return Err("packed epoch has an invalid month character"),
+    };
+
+    let day = match chars[4] {
+        '1'..='9' => chars[4] as u8 - b'1' + 1,
+        'A'..='V' => chars[4] as u8 - b'A' + 10,
+        _ => return Err("packed epoch has an invalid day character"),
+    };
+
+    Ok(SkyTime::from_utc(year, month, day, 0, 0, 0.0).julian_date_tdb())
+}
+
+/// Compute the position of an arbitrary, runtime-supplied set of orbital
+/// elements -- e.g. one just parsed with [`OrbitalElements::from_mpc_line`]
+/// -- through the same geocentric pipeline as [`compute_minor_body_position`],
+/// rather than requiring a bundled [`MinorBody`] variant. Alias for
+/// [`compute_minor_body_position_from_elements`], named to match the
+/// element-parsing entry points above.
+pub fn compute_position_for_elements(elem: &OrbitalElements, time: &SkyTime) -> CustomMinorBodyPosition {
+    compute_minor_body_position_from_elements(elem, time)
 }
 
 // =============================================================================
@@ -77,6 +377,8 @@ pub const PLUTO: OrbitalElements = OrbitalElements::from_degrees(
     14.86205,           // Mean anomaly at J2000 (degrees)
     247.94,             // Orbital period (years)
     1188.3,             // Mean radius (km)
+    -0.7,               // Absolute magnitude H (IAU H-G system)
+    0.15,               // Slope parameter G - assumed default
 );
 
 /// Ceres - largest object in asteroid belt, dwarf planet
@@ -91,6 +393,8 @@ pub const CERES: OrbitalElements = OrbitalElements::from_degrees(
     341.0,              // Mean anomaly at J2000 (degrees) - estimated
     4.60,               // Orbital period (years)
     473.0,              // Mean radius (km)
+    3.34,               // Absolute magnitude H (IAU H-G system)
+    0.12,               // Slope parameter G
 );
 
 /// Eris - most massive known dwarf planet
@@ -105,6 +409,8 @@ pub const ERIS: OrbitalElements = OrbitalElements::from_degrees(
     205.0,              // Mean anomaly at J2000 (degrees) - estimated from 2257 perihelion
     559.0,              // Orbital period (years)
     1163.0,             // Mean radius (km)
+    -1.21,              // Absolute magnitude H (IAU H-G system)
+    0.15,               // Slope parameter G - assumed default
 );
 
 /// Makemake - Kuiper belt dwarf planet
@@ -119,6 +425,8 @@ pub const MAKEMAKE: OrbitalElements = OrbitalElements::from_degrees(
     151.0,              // Mean anomaly at J2000 (degrees) - estimated
     306.2,              // Orbital period (years)
     715.0,              // Mean radius (km)
+    -0.48,              // Absolute magnitude H (IAU H-G system)
+    0.15,               // Slope parameter G - assumed default
 );
 
 /// Haumea - elongated dwarf planet with ring
@@ -133,6 +441,8 @@ pub const HAUMEA: OrbitalElements = OrbitalElements::from_degrees(
     219.0,              // Mean anomaly at J2000 (degrees) - estimated from 2133 perihelion
     284.0,              // Orbital period (years)
     780.0,              // Mean radius (km) - average of ellipsoid
+    0.2,                // Absolute magnitude H (IAU H-G system)
+    0.15,               // Slope parameter G - assumed default
 );
 
 /// Sedna - extreme trans-Neptunian object
@@ -147,6 +457,8 @@ pub const SEDNA: OrbitalElements = OrbitalElements::from_degrees(
     358.0,              // Mean anomaly at J2000 (degrees)
     11400.0,            // Orbital period (years)
     497.5,              // Mean radius (km)
+    1.83,               // Absolute magnitude H (IAU H-G system)
+    0.15,               // Slope parameter G - assumed default
 );
 
 /// Quaoar - classical Kuiper belt object
@@ -161,6 +473,8 @@ pub const QUAOAR: OrbitalElements = OrbitalElements::from_degrees(
     283.0,              // Mean anomaly at J2000 (degrees) - estimated from 2075 perihelion
     288.8,              // Orbital period (years)
     545.0,              // Mean radius (km)
+    2.6,                // Absolute magnitude H (IAU H-G system)
+    0.15,               // Slope parameter G - assumed default
 );
 
 /// Gonggong - scattered disc dwarf planet (225088)
@@ -175,6 +489,8 @@ pub const GONGGONG: OrbitalElements = OrbitalElements::from_degrees(
     106.0,              // Mean anomaly at J2000 (degrees) - estimated from 1857 perihelion
     550.0,              // Orbital period (years)
     615.0,              // Mean radius (km)
+    1.9,                // Absolute magnitude H (IAU H-G system)
+    0.15,               // Slope parameter G - assumed default
 );
 
 /// Orcus - plutino (2:3 Neptune resonance)
@@ -189,6 +505,8 @@ pub const ORCUS: OrbitalElements = OrbitalElements::from_degrees(
     167.0,              // Mean anomaly at J2000 (degrees)
     247.9,              // Orbital period (years)
     458.0,              // Mean radius (km)
+    2.3,                // Absolute magnitude H (IAU H-G system)
+    0.15,               // Slope parameter G - assumed default
 );
 
 /// Varuna - large classical Kuiper belt object
@@ -203,6 +521,8 @@ pub const VARUNA: OrbitalElements = OrbitalElements::from_degrees(
     88.0,               // Mean anomaly at J2000 (degrees) - estimated from 1928 perihelion
     282.0,              // Orbital period (years)
     334.0,              // Mean radius (km)
+    3.2,                // Absolute magnitude H (IAU H-G system)
+    0.15,               // Slope parameter G - assumed default
 );
 
 // =============================================================================
@@ -221,6 +541,8 @@ pub const VESTA: OrbitalElements = OrbitalElements::from_degrees(
     20.0,               // Mean anomaly at J2000 (degrees) - estimated
     3.63,               // Orbital period (years)
     262.7,              // Mean radius (km)
+    3.2,                // Absolute magnitude H (IAU H-G system)
+    0.32,               // Slope parameter G
 );
 
 /// Pallas (2) - third-largest asteroid, highly inclined orbit
@@ -235,6 +557,8 @@ pub const PALLAS: OrbitalElements = OrbitalElements::from_degrees(
     40.6,               // Mean anomaly at J2000 (degrees)
     4.62,               // Orbital period (years)
     256.0,              // Mean radius (km)
+    4.13,               // Absolute magnitude H (IAU H-G system)
+    0.11,               // Slope parameter G
 );
 
 /// Hygiea (10) - fourth-largest asteroid, nearly spherical
@@ -249,6 +573,8 @@ pub const HYGIEA: OrbitalElements = OrbitalElements::from_degrees(
     75.0,               // Mean anomaly at J2000 (degrees) - estimated
     5.57,               // Orbital period (years)
     217.0,              // Mean radius (km)
+    5.43,               // Absolute magnitude H (IAU H-G system)
+    0.15,               // Slope parameter G - assumed default
 );
 
 // =============================================================================
@@ -267,6 +593,8 @@ pub const APOPHIS: OrbitalElements = OrbitalElements::from_degrees(
     180.0,              // Mean anomaly at J2000 (degrees) - estimated
     0.89,               // Orbital period (years) - less than 1 year!
     0.17,               // Mean radius (km) - ~340m diameter
+    19.7,               // Absolute magnitude H (IAU H-G system)
+    0.15,               // Slope parameter G - assumed default
 );
 
 /// Bennu (101955) - OSIRIS-REx sample return target
@@ -281,6 +609,8 @@ pub const BENNU: OrbitalElements = OrbitalElements::from_degrees(
     101.7,              // Mean anomaly at J2000 (degrees)
     1.20,               // Orbital period (years)
     0.245,              // Mean radius (km) - ~490m diameter
+    20.2,               // Absolute magnitude H (IAU H-G system)
+    0.15,               // Slope parameter G - assumed default
 );
 
 /// Minor body identifier
@@ -356,6 +686,9 @@ impl MinorBody {
     }
 }
 
+/// AU/day -> km/s: 1 AU in km, divided by the number of seconds in a day.
+const AU_PER_DAY_TO_KM_PER_S: f64 = AU_TO_KM / 86_400.0;
+
 /// Result of minor body position calculation
 pub struct MinorBodyPosition {
     pub body: MinorBody,
@@ -367,18 +700,105 @@ pub struct MinorBodyPosition {
     pub helio_distance_km: f64,
     /// Angular diameter as seen from Earth (radians)
     pub angular_diameter_rad: f64,
+    /// Apparent visual magnitude, from the IAU H-G system ([`OrbitalElements::abs_mag_h`]/[`OrbitalElements::slope_g`])
+    pub visual_magnitude: f64,
+    /// Velocity relative to Earth (same equatorial J2000 frame as
+    /// [`MinorBodyPosition::direction`]), km/s
+    pub velocity_kms: CartesianCoord,
+}
+
+/// Geocentric radial velocity (positive = receding) of a body with
+/// heliocentric velocity `body_velocity_au_per_day` relative to Earth's own
+/// heliocentric velocity, projected onto the Earth-body line of sight.
+/// Useful for Doppler-shifting a spectrum or sanity-checking an ephemeris
+/// against observed radial velocity.
+pub fn geocentric_range_rate_km_s(
+    geo_position_au: (f64, f64, f64),
+    body_velocity_au_per_day: (f64, f64, f64),
+    earth_velocity_au_per_day: (f64, f64, f64),
+) -> f64 {
+    let distance_au = (geo_position_au.0 * geo_position_au.0
+        + geo_position_au.1 * geo_position_au.1
+        + geo_position_au.2 * geo_position_au.2)
+        .sqrt();
+    let los = (
+        geo_position_au.0 / distance_au,
+        geo_position_au.1 / distance_au,
+        geo_position_au.2 / distance_au,
+    );
+    let geo_velocity_au_per_day = (
+        body_velocity_au_per_day.0 - earth_velocity_au_per_day.0,
+        body_velocity_au_per_day.1 - earth_velocity_au_per_day.1,
+        body_velocity_au_per_day.2 - earth_velocity_au_per_day.2,
+    );
+    let range_rate_au_per_day =
+        los.0 * geo_velocity_au_per_day.0 + los.1 * geo_velocity_au_per_day.1 + los.2 * geo_velocity_au_per_day.2;
+    range_rate_au_per_day * AU_PER_DAY_TO_KM_PER_S
 }
 
+/// Compute apparent visual magnitude from the IAU H-G system:
+/// `m = H + 5*log10(r*Δ) - 2.5*log10[(1-G)*Φ1 + G*Φ2]`, where r is the
+/// heliocentric distance, Δ is the geocentric distance (both AU), and Φ1/Φ2
+/// are the two phase functions evaluated at the Sun-body-Earth phase angle α.
+fn compute_hg_magnitude(
+    elem: &OrbitalElements,
+    helio_distance_au: f64,
+    geo_distance_au: f64,
+    earth_helio_distance_au: f64,
+) -> f64 {
+    // Law of cosines in the Sun-Earth-body triangle.
+    let cos_alpha = ((helio_distance_au * helio_distance_au + geo_distance_au * geo_distance_au
+        - earth_helio_distance_au * earth_helio_distance_au)
+        / (2.0 * helio_distance_au * geo_distance_au))
+        .clamp(-1.0, 1.0);
+    let phase_angle_rad = cos_alpha.acos();
+
+    crate::rotation::hg_magnitude(
+        elem.abs_mag_h,
+        elem.slope_g,
+        helio_distance_au,
+        geo_distance_au,
+        phase_angle_rad,
+    )
+}
+
+/// Above this eccentricity, plain Newton-Raphson's derivative `1 - e·cos E`
+/// gets too close to zero near perihelion to trust a bare Newton step --
+/// Sedna (e=0.86) and any near-parabolic comet elements fall in this range.
+const HIGH_ECCENTRICITY_THRESHOLD: f64 = 0.8;
+
 /// Solve Kepler's equation: M = E - e*sin(E)
 /// Returns eccentric anomaly E for given mean anomaly M and eccentricity e.
 fn solve_kepler(mean_anomaly: f64, eccentricity: f64) -> f64 {
-    let m = mean_anomaly % (2.0 * PI);
-    let mut e_anomaly = m; // Initial guess
+    // Reduce to (-π, π] for a symmetric, well-conditioned starting point --
+    // `%` alone can leave M close to ±2π, where the guess below is a poor fit.
+    let mut m = mean_anomaly % (2.0 * PI);
+    if m > PI {
+        m -= 2.0 * PI;
+    } else if m <= -PI {
+        m += 2.0 * PI;
+    }
+
+    // Third-order-accurate initial guess (Danby), far closer to the root than
+    // E₀ = M alone and what cuts the iteration count for eccentric orbits.
+    let mut e_anomaly = m + eccentricity * m.sin() * (1.0 + eccentricity * m.cos());
 
-    // Newton-Raphson iteration
     for _ in 0..15 {
-        let delta = (e_anomaly - eccentricity * e_anomaly.sin() - m)
-            / (1.0 - eccentricity * e_anomaly.cos());
+        let sin_e = e_anomaly.sin();
+        let cos_e = e_anomaly.cos();
+        let f = e_anomaly - eccentricity * sin_e - m;
+        let f_prime = 1.0 - eccentricity * cos_e;
+
+        let delta = if eccentricity > HIGH_ECCENTRICITY_THRESHOLD {
+            // Halley's method: cubic convergence and well-behaved even as
+            // f_prime approaches zero near perihelion, where a bare Newton
+            // step would overshoot wildly.
+            let f_double_prime = eccentricity * sin_e;
+            f / (f_prime - f * f_double_prime / (2.0 * f_prime))
+        } else {
+            f / f_prime
+        };
+
         e_anomaly -= delta;
         if delta.abs() < 1e-12 {
             break;
@@ -388,35 +808,53 @@ fn solve_kepler(mean_anomaly: f64, eccentricity: f64) -> f64 {
     e_anomaly
 }
 
-/// Compute heliocentric position of a minor body in ecliptic coordinates.
-/// Returns (x, y, z) in AU, J2000 ecliptic frame.
-fn compute_heliocentric_ecliptic(elem: &OrbitalElements, jde: f64) -> (f64, f64, f64) {
+/// Heliocentric position and velocity of a minor body in ecliptic coordinates.
+/// Returns `((x, y, z), (ẋ, ẏ, ż))`, position in AU and velocity in AU/day,
+/// J2000 ecliptic frame.
+fn compute_heliocentric_state(elem: &OrbitalElements, jde: f64) -> ((f64, f64, f64), (f64, f64, f64)) {
     // Days since J2000.0
     let t = jde - 2451545.0;
 
-    // Mean anomaly at current time
+    // Julian centuries since J2000.0, for the linear element rates. Zero for
+    // any body that never called `with_element_rates`, so this is a no-op
+    // for the bundled catalog entries.
+    let centuries = t / 36525.0;
+
+    // Mean anomaly at current time. Mean motion isn't re-derived from the
+    // instantaneous semi-major axis below -- bodies with rate terms still
+    // quote mean motion directly, same as bodies without them.
     let mean_anomaly = elem.mean_anomaly_j2000_rad + elem.mean_motion_rad_per_day * t;
 
+    // Instantaneous elements, linearly advanced from their J2000 values.
+    let semi_major_axis_au = elem.semi_major_axis_au + elem.d_semi_major_axis * centuries;
+    let e = elem.eccentricity + elem.d_eccentricity * centuries;
+
     // Solve Kepler's equation for eccentric anomaly
-    let e_anomaly = solve_kepler(mean_anomaly, elem.eccentricity);
+    let e_anomaly = solve_kepler(mean_anomaly, e);
 
     // True anomaly
     let cos_e = e_anomaly.cos();
-    let e = elem.eccentricity;
     let true_anomaly = 2.0 * ((1.0 + e).sqrt() * (e_anomaly / 2.0).tan())
         .atan2((1.0 - e).sqrt());
 
     // Distance from Sun (in AU)
-    let r = elem.semi_major_axis_au * (1.0 - e * cos_e);
+    let r = semi_major_axis_au * (1.0 - e * cos_e);
 
     // Position in orbital plane
     let x_orbit = r * true_anomaly.cos();
     let y_orbit = r * true_anomaly.sin();
 
+    // Orbital-plane velocity, from the time derivative of Kepler's equation
+    // (Ė = n/(1 - e·cos E)) rather than by differentiating position
+    // numerically -- exact, and free of a finite-difference step size.
+    let e_anomaly_dot = elem.mean_motion_rad_per_day / (1.0 - e * cos_e);
+    let x_orbit_dot = -semi_major_axis_au * e_anomaly.sin() * e_anomaly_dot;
+    let y_orbit_dot = semi_major_axis_au * (1.0 - e * e).sqrt() * cos_e * e_anomaly_dot;
+
     // Orbital elements
-    let i = elem.inclination_rad;
-    let omega = elem.ascending_node_rad;  // Longitude of ascending node
-    let w = elem.arg_perihelion_rad;      // Argument of perihelion
+    let i = elem.inclination_rad + elem.d_inclination * centuries;
+    let omega = elem.ascending_node_rad + elem.d_ascending_node * centuries; // Longitude of ascending node
+    let w = elem.arg_perihelion_rad + elem.d_arg_perihelion * centuries; // Argument of perihelion
 
     // Rotation from orbital plane to ecliptic coordinates
     // Using standard orbital mechanics transformation
@@ -440,7 +878,20 @@ fn compute_heliocentric_ecliptic(elem: &OrbitalElements, jde: f64) -> (f64, f64,
     let y_ecl = q1 * x_orbit + q2 * y_orbit;
     let z_ecl = r1 * x_orbit + r2 * y_orbit;
 
-    (x_ecl, y_ecl, z_ecl)
+    // The P/Q rotation matrix is the same linear map for velocity as for
+    // position -- it rotates the orbital plane into the ecliptic, and a
+    // rotation applies unchanged to any vector, not just the radius vector.
+    let vx_ecl = p1 * x_orbit_dot + p2 * y_orbit_dot;
+    let vy_ecl = q1 * x_orbit_dot + q2 * y_orbit_dot;
+    let vz_ecl = r1 * x_orbit_dot + r2 * y_orbit_dot;
+
+    ((x_ecl, y_ecl, z_ecl), (vx_ecl, vy_ecl, vz_ecl))
+}
+
+/// Heliocentric position of a minor body in ecliptic coordinates, discarding
+/// velocity. Returns (x, y, z) in AU, J2000 ecliptic frame.
+fn compute_heliocentric_ecliptic(elem: &OrbitalElements, jde: f64) -> (f64, f64, f64) {
+    compute_heliocentric_state(elem, jde).0
 }
 
 /// Compute position of a minor body as seen from Earth.
@@ -448,11 +899,12 @@ pub fn compute_minor_body_position(body: MinorBody, time: &SkyTime) -> MinorBody
     let elem = body.elements();
     let jde = time.julian_date_tdb();
 
-    // Get heliocentric position of the minor body (ecliptic coordinates, AU)
-    let (body_x, body_y, body_z) = compute_heliocentric_ecliptic(elem, jde);
+    // Get heliocentric position and velocity of the minor body (ecliptic coordinates)
+    let ((body_x, body_y, body_z), body_vel) = compute_heliocentric_state(elem, jde);
 
     // Get heliocentric position of Earth (ecliptic coordinates, AU)
     let earth_pos = crate::planets::heliocentric_position(Planet::Earth, jde);
+    let earth_vel = earth_heliocentric_velocity_au_per_day(jde);
 
     // Geocentric position of the minor body (AU)
     let geo_x = body_x - earth_pos.0;
@@ -475,15 +927,31 @@ pub fn compute_minor_body_position(body: MinorBody, time: &SkyTime) -> MinorBody
     let obliquity = true_obliquity(jde);
     let direction = ecliptic_to_equatorial(lon, lat, obliquity).normalize();
 
+    // Geocentric velocity, rotated into the same equatorial frame as
+    // `direction` (AU/day -> km/s).
+    let geo_vel_ecliptic = (body_vel.0 - earth_vel.0, body_vel.1 - earth_vel.1, body_vel.2 - earth_vel.2);
+    let geo_vel_equatorial = rotate_ecliptic_vector_to_equatorial(geo_vel_ecliptic, obliquity);
+    let velocity_kms = CartesianCoord::new(
+        geo_vel_equatorial.0 * AU_PER_DAY_TO_KM_PER_S,
+        geo_vel_equatorial.1 * AU_PER_DAY_TO_KM_PER_S,
+        geo_vel_equatorial.2 * AU_PER_DAY_TO_KM_PER_S,
+    );
+
     // Angular diameter
     let angular_diameter_rad = 2.0 * (elem.radius_km / distance_km).atan();
 
+    let earth_helio_distance_au =
+        (earth_pos.0 * earth_pos.0 + earth_pos.1 * earth_pos.1 + earth_pos.2 * earth_pos.2).sqrt();
+    let visual_magnitude = compute_hg_magnitude(elem, helio_distance_au, distance_au, earth_helio_distance_au);
+
     MinorBodyPosition {
         body,
         direction,
         distance_km,
         helio_distance_km,
         angular_diameter_rad,
+        visual_magnitude,
+        velocity_kms,
     }
 }
 
@@ -495,6 +963,180 @@ pub fn compute_all_minor_body_positions(time: &SkyTime) -> Vec<MinorBodyPosition
         .collect()
 }
 
+// --- Apparent position: light-time, aberration, and solar deflection -------
+//
+// `compute_minor_body_position` above is purely geometric: it differences
+// heliocentric positions evaluated at the same instant, as if light arrived
+// instantaneously. For a fast-moving NEO like Apophis or Bennu the light-time
+// alone can be tens of arcseconds, so `compute_minor_body_position_apparent`
+// below adds the same light-time and annual-aberration corrections
+// `compute_planet_position_apparent` applies to the planets, plus
+// gravitational light deflection for bodies seen close to the Sun.
+
+/// Deflect a geocentric equatorial unit direction away from the Sun by
+/// gravitational light bending, via [`crate::coords::compute_gravitational_deflection`]
+/// -- this just rotates the Earth-Sun vector into the equatorial frame
+/// `direction` is already in.
+fn apply_solar_deflection(
+    direction: CartesianCoord,
+    earth_pos_au: (f64, f64, f64),
+    obliquity_rad: f64,
+) -> CartesianCoord {
+    let d = (earth_pos_au.0 * earth_pos_au.0 + earth_pos_au.1 * earth_pos_au.1 + earth_pos_au.2 * earth_pos_au.2)
+        .sqrt();
+    let sun_ecliptic = (-earth_pos_au.0 / d, -earth_pos_au.1 / d, -earth_pos_au.2 / d);
+    let sun_eq = rotate_ecliptic_vector_to_equatorial(sun_ecliptic, obliquity_rad);
+    let sun_dir = CartesianCoord::new(sun_eq.0, sun_eq.1, sun_eq.2);
+
+    crate::coords::compute_gravitational_deflection(&direction, &sun_dir, d)
+}
+
+/// Compute the apparent position of a minor body: the geometric direction,
+/// corrected for light-time (the body's position is evaluated at `jde - τ`,
+/// not `jde`), gravitational deflection of light passing near the Sun, and
+/// annual aberration (Earth's own motion), applied in that order.
+pub fn compute_minor_body_position_apparent(body: MinorBody, time: &SkyTime) -> MinorBodyPosition {
+    let elem = body.elements();
+    let jde = time.julian_date_tdb();
+    let earth_pos = crate::planets::heliocentric_position(Planet::Earth, jde);
+
+    let (geo, distance_au) =
+        light_time_corrected_geocentric(|t| compute_heliocentric_ecliptic(elem, t), earth_pos, jde);
+
+    let distance_km = distance_au * AU_TO_KM;
+
+    let body_pos = (geo.0 + earth_pos.0, geo.1 + earth_pos.1, geo.2 + earth_pos.2);
+    let helio_distance_au = (body_pos.0 * body_pos.0 + body_pos.1 * body_pos.1 + body_pos.2 * body_pos.2).sqrt();
+    let helio_distance_km = helio_distance_au * AU_TO_KM;
+
+    let lon = geo.1.atan2(geo.0);
+    let lat = (geo.2 / distance_au).asin();
+    let obliquity = true_obliquity(jde);
+    let geometric_direction = ecliptic_to_equatorial(lon, lat, obliquity);
+
+    let deflected_direction = apply_solar_deflection(geometric_direction, earth_pos, obliquity);
+
+    let earth_velocity = earth_heliocentric_velocity_au_per_day(jde);
+    let direction = apply_stellar_aberration(deflected_direction, earth_velocity);
+
+    // Body velocity at the light-time-corrected epoch used above, not `jde`
+    // itself -- consistent with the position already being evaluated there.
+    let tau = LIGHT_TIME_DAYS_PER_AU * distance_au;
+    let (_, body_velocity) = compute_heliocentric_state(elem, jde - tau);
+    let geo_vel_ecliptic = (
+        body_velocity.0 - earth_velocity.0,
+        body_velocity.1 - earth_velocity.1,
+        body_velocity.2 - earth_velocity.2,
+    );
+    let geo_vel_equatorial = rotate_ecliptic_vector_to_equatorial(geo_vel_ecliptic, obliquity);
+    let velocity_kms = CartesianCoord::new(
+        geo_vel_equatorial.0 * AU_PER_DAY_TO_KM_PER_S,
+        geo_vel_equatorial.1 * AU_PER_DAY_TO_KM_PER_S,
+        geo_vel_equatorial.2 * AU_PER_DAY_TO_KM_PER_S,
+    );
+
+    let angular_diameter_rad = 2.0 * (elem.radius_km / distance_km).atan();
+
+    let earth_helio_distance_au =
+        (earth_pos.0 * earth_pos.0 + earth_pos.1 * earth_pos.1 + earth_pos.2 * earth_pos.2).sqrt();
+    let visual_magnitude = compute_hg_magnitude(elem, helio_distance_au, distance_au, earth_helio_distance_au);
+
+    MinorBodyPosition {
+        body,
+        direction,
+        distance_km,
+        helio_distance_km,
+        angular_diameter_rad,
+        visual_magnitude,
+        velocity_kms,
+    }
+}
+
+/// Light travel time in days per AU (1 / speed of light in AU/day).
+const LIGHT_TIME_DAYS_PER_AU: f64 = 0.0058;
+
+/// Result of computing a runtime-ingested minor body's position, for an
+/// object built from [`OrbitalElements::from_mpc_elements`] rather than one
+/// of the bundled [`MinorBody`] variants.
+#[derive(Debug, Clone)]
+pub struct CustomMinorBodyPosition {
+    pub name: &'static str,
+    /// Direction from Earth (unit vector in equatorial J2000)
+    pub direction: CartesianCoord,
+    /// Distance from Earth in km
+    pub distance_km: f64,
+    /// Distance from Sun in km
+    pub helio_distance_km: f64,
+    /// Angular diameter as seen from Earth (radians)
+    pub angular_diameter_rad: f64,
+    /// Apparent visual magnitude, from the IAU H-G system
+    pub visual_magnitude: f64,
+}
+
+/// Compute the position of a minor body from explicit, runtime-supplied
+/// orbital elements rather than one of the bundled [`MinorBody`]s.
+///
+/// Applies two light-time iterations, same as
+/// [`crate::comets::compute_comet_position_from_elements`]: the geocentric
+/// distance found at `time` steps the evaluation epoch back by
+/// `distance_au * LIGHT_TIME_DAYS_PER_AU` and the position is recomputed,
+/// twice.
+pub fn compute_minor_body_position_from_elements(
+    elem: &OrbitalElements,
+    time: &SkyTime,
+) -> CustomMinorBodyPosition {
+    let t0 = time.julian_date_tdb();
+    let mut jde = t0;
+
+    let (mut body_x, mut body_y, mut body_z) = compute_heliocentric_ecliptic(elem, jde);
+    let mut earth_pos = crate::planets::heliocentric_position(Planet::Earth, jde);
+
+    for _ in 0..2 {
+        let geo_x = body_x - earth_pos.0;
+        let geo_y = body_y - earth_pos.1;
+        let geo_z = body_z - earth_pos.2;
+        let distance_au = (geo_x * geo_x + geo_y * geo_y + geo_z * geo_z).sqrt();
+
+        jde = t0 - distance_au * LIGHT_TIME_DAYS_PER_AU;
+        let recomputed = compute_heliocentric_ecliptic(elem, jde);
+        body_x = recomputed.0;
+        body_y = recomputed.1;
+        body_z = recomputed.2;
+        earth_pos = crate::planets::heliocentric_position(Planet::Earth, jde);
+    }
+
+    let geo_x = body_x - earth_pos.0;
+    let geo_y = body_y - earth_pos.1;
+    let geo_z = body_z - earth_pos.2;
+
+    let distance_au = (geo_x * geo_x + geo_y * geo_y + geo_z * geo_z).sqrt();
+    let distance_km = distance_au * AU_TO_KM;
+
+    let helio_distance_au = (body_x * body_x + body_y * body_y + body_z * body_z).sqrt();
+    let helio_distance_km = helio_distance_au * AU_TO_KM;
+
+    let lon = geo_y.atan2(geo_x);
+    let lat = (geo_z / distance_au).asin();
+
+    let obliquity = true_obliquity(jde);
+    let direction = ecliptic_to_equatorial(lon, lat, obliquity).normalize();
+
+    let angular_diameter_rad = 2.0 * (elem.radius_km / distance_km).atan();
+
+    let earth_helio_distance_au =
+        (earth_pos.0 * earth_pos.0 + earth_pos.1 * earth_pos.1 + earth_pos.2 * earth_pos.2).sqrt();
+    let visual_magnitude = compute_hg_magnitude(elem, helio_distance_au, distance_au, earth_helio_distance_au);
+
+    CustomMinorBodyPosition {
+        name: elem.name,
+        direction,
+        distance_km,
+        helio_distance_km,
+        angular_diameter_rad,
+        visual_magnitude,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -567,6 +1209,120 @@ mod tests {
         assert!(e_eccentric > 1.0 && e_eccentric < 1.3, "Eccentric orbit: E should be reasonable");
     }
 
+    #[test]
+    fn test_kepler_solver_near_parabolic_converges_near_perihelion() {
+        // e=0.97 near M=0 is the regime that breaks bare Newton-Raphson: the
+        // derivative 1 - e*cos(E) vanishes right where the body moves
+        // fastest. This should still converge to the 1e-12 tolerance.
+        let e = 0.97;
+        for m in [-0.05, -0.001, 0.0, 0.001, 0.05] {
+            let e_anomaly = solve_kepler(m, e);
+            let residual = e_anomaly - e * e_anomaly.sin() - m;
+            assert!(
+                residual.abs() < 1e-10,
+                "M={m}: expected Kepler's equation residual near zero, got {residual} (E={e_anomaly})"
+            );
+        }
+    }
+
+    #[test]
+    fn test_kepler_solver_handles_high_eccentricity_catalog_bodies() {
+        // Sedna (e≈0.86) is the most eccentric bundled orbit; it should
+        // solve cleanly across a full range of mean anomaly, not just near
+        // M=0.
+        for &m in &[-3.0, -1.5, 0.0, 1.5, 3.0] {
+            let e_anomaly = solve_kepler(m, SEDNA.eccentricity);
+            let residual = e_anomaly - SEDNA.eccentricity * e_anomaly.sin() - m;
+            assert!(residual.abs() < 1e-9, "Sedna at M={m}: residual {residual} too large");
+        }
+    }
+
+    #[test]
+    fn test_heliocentric_velocity_matches_numerical_derivative() {
+        // The analytic orbital-plane velocity should agree with a central
+        // difference of the analytic position, to well within the error a
+        // one-day step introduces.
+        let time = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0);
+        let jde = time.julian_date_tdb();
+        let step = 0.5;
+
+        let (_, (vx, vy, vz)) = compute_heliocentric_state(&CERES, jde);
+        let (x_plus, y_plus, z_plus) = compute_heliocentric_ecliptic(&CERES, jde + step);
+        let (x_minus, y_minus, z_minus) = compute_heliocentric_ecliptic(&CERES, jde - step);
+        let vx_numeric = (x_plus - x_minus) / (2.0 * step);
+        let vy_numeric = (y_plus - y_minus) / (2.0 * step);
+        let vz_numeric = (z_plus - z_minus) / (2.0 * step);
+
+        assert!(
+            (vx - vx_numeric).abs() < 1e-6 && (vy - vy_numeric).abs() < 1e-6 && (vz - vz_numeric).abs() < 1e-6,
+            "analytic velocity ({vx}, {vy}, {vz}) should match numerical derivative ({vx_numeric}, {vy_numeric}, {vz_numeric})"
+        );
+    }
+
+    #[test]
+    fn test_minor_body_position_velocity_reasonable() {
+        // Ceres orbits at about 18 km/s (under 4 AU/year), well under Earth's
+        // own ~30 km/s -- but its velocity relative to Earth can still reach
+        // several tens of km/s depending on the geometry.
+        let time = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0);
+        let pos = compute_minor_body_position(MinorBody::Ceres, &time);
+        let speed = (pos.velocity_kms.x.powi(2) + pos.velocity_kms.y.powi(2) + pos.velocity_kms.z.powi(2)).sqrt();
+        assert!(
+            speed > 1.0 && speed < 100.0,
+            "Ceres geocentric speed should be tens of km/s, got {speed} km/s"
+        );
+    }
+
+    #[test]
+    fn test_geocentric_range_rate_matches_velocity_projection() {
+        // A body moving directly away from Earth along the line of sight
+        // should show a range rate equal to its full relative speed.
+        let geo_position_au = (1.0, 0.0, 0.0);
+        let body_velocity_au_per_day = (0.01, 0.0, 0.0);
+        let earth_velocity_au_per_day = (0.0, 0.0, 0.0);
+        let range_rate =
+            geocentric_range_rate_km_s(geo_position_au, body_velocity_au_per_day, earth_velocity_au_per_day);
+        let expected = 0.01 * AU_PER_DAY_TO_KM_PER_S;
+        assert!(
+            (range_rate - expected).abs() < 1e-9,
+            "expected range rate {expected} km/s, got {range_rate} km/s"
+        );
+    }
+
+    #[test]
+    fn test_element_rates_default_to_zero() {
+        // A body that never calls `with_element_rates` should propagate
+        // identically to before the rate fields existed.
+        assert_eq!(PLUTO.d_semi_major_axis, 0.0);
+        assert_eq!(PLUTO.d_eccentricity, 0.0);
+        assert_eq!(PLUTO.d_inclination, 0.0);
+        assert_eq!(PLUTO.d_ascending_node, 0.0);
+        assert_eq!(PLUTO.d_arg_perihelion, 0.0);
+    }
+
+    #[test]
+    fn test_element_rates_shift_position_far_from_epoch() {
+        // Fabricate a drifting eccentricity rate on top of Ceres' real
+        // elements; a century away from J2000 the heliocentric distance
+        // should differ noticeably from the non-drifting elements, and the
+        // two should agree exactly right at the J2000 epoch (T = 0).
+        let drifting = CERES.with_element_rates(0.0, 0.05, 0.0, 0.0, 0.0);
+
+        let epoch_jde = 2451545.0;
+        let (x0, y0, z0) = compute_heliocentric_ecliptic(&CERES, epoch_jde);
+        let (dx0, dy0, dz0) = compute_heliocentric_ecliptic(&drifting, epoch_jde);
+        assert!((x0 - dx0).abs() < 1e-9 && (y0 - dy0).abs() < 1e-9 && (z0 - dz0).abs() < 1e-9);
+
+        let later = SkyTime::from_utc(2100, 1, 1, 12, 0, 0.0);
+        let (x1, y1, z1) = compute_heliocentric_ecliptic(&CERES, later.julian_date_tdb());
+        let (dx1, dy1, dz1) = compute_heliocentric_ecliptic(&drifting, later.julian_date_tdb());
+        let shift = ((x1 - dx1).powi(2) + (y1 - dy1).powi(2) + (z1 - dz1).powi(2)).sqrt();
+        assert!(
+            shift > 0.01,
+            "expected the drifting eccentricity to shift the position by more than 0.01 AU a century out, got {shift} AU"
+        );
+    }
+
     #[test]
     fn test_all_minor_bodies_reasonable() {
         let time = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0);
@@ -605,6 +1361,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_ingested_elements_match_bundled_minor_body() {
+        // Pluto's own published elements, ingested through the runtime
+        // MPC-style constructor, should reproduce the bundled
+        // MinorBody::Pluto result (modulo the light-time iterations the
+        // ingestion path adds).
+        let elem = OrbitalElements::from_mpc_elements(
+            "Pluto (ingested)".to_string(),
+            39.48211675,
+            0.2488273,
+            17.14175,
+            110.30347,
+            113.76329,
+            14.86205,
+            247.94,
+            1188.3,
+            -0.7,
+            0.15,
+        );
+
+        let time = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0);
+        let pos = compute_minor_body_position_from_elements(&elem, &time);
+        let bundled = compute_minor_body_position(MinorBody::Pluto, &time);
+
+        assert_eq!(pos.name, "Pluto (ingested)");
+        let helio_au = pos.helio_distance_km / AU_TO_KM;
+        let bundled_helio_au = bundled.helio_distance_km / AU_TO_KM;
+        assert!(
+            (helio_au - bundled_helio_au).abs() < 0.01,
+            "ingested Pluto should match bundled Pluto: {} vs {}",
+            helio_au, bundled_helio_au
+        );
+    }
+
     #[test]
     fn test_ceres_inner_solar_system() {
         let time = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0);
@@ -626,4 +1416,169 @@ mod tests {
             helio_au
         );
     }
+
+    #[test]
+    fn test_ceres_visual_magnitude_naked_eye_range() {
+        // Ceres swings roughly between mag 6.6 (opposition) and 9.3
+        // (conjunction) -- never bright enough for naked-eye, but never
+        // fainter than a modest pair of binoculars either.
+        let time = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0);
+        let pos = compute_minor_body_position(MinorBody::Ceres, &time);
+        assert!(
+            pos.visual_magnitude > 5.0 && pos.visual_magnitude < 10.0,
+            "Ceres visual magnitude should be roughly 5-10, got {}",
+            pos.visual_magnitude
+        );
+    }
+
+    #[test]
+    fn test_visual_magnitude_brighter_near_opposition() {
+        // Apophis is much brighter near its well-known 2029 close approach
+        // (geocentric distance well under 1 AU) than at a typical epoch.
+        let far = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0);
+        let close = SkyTime::from_utc(2029, 4, 13, 0, 0, 0.0);
+
+        let far_pos = compute_minor_body_position(MinorBody::Apophis, &far);
+        let close_pos = compute_minor_body_position(MinorBody::Apophis, &close);
+
+        assert!(
+            close_pos.visual_magnitude < far_pos.visual_magnitude,
+            "expected Apophis to appear brighter near its 2029 approach: far={}, close={}",
+            far_pos.visual_magnitude,
+            close_pos.visual_magnitude
+        );
+    }
+
+    #[test]
+    fn test_apparent_position_is_unit_vector() {
+        let time = SkyTime::from_utc(2024, 6, 15, 0, 0, 0.0);
+        let pos = compute_minor_body_position_apparent(MinorBody::Apophis, &time);
+        let len = (pos.direction.x.powi(2) + pos.direction.y.powi(2) + pos.direction.z.powi(2)).sqrt();
+        assert!((len - 1.0).abs() < 1e-9, "expected unit vector, got len={len}");
+    }
+
+    #[test]
+    fn test_apparent_position_differs_from_geometric_for_fast_neo() {
+        // Apophis moves fast enough that light-time + aberration should
+        // shift the apparent direction from the purely geometric one by a
+        // measurable amount.
+        let time = SkyTime::from_utc(2029, 4, 13, 0, 0, 0.0);
+        let geometric = compute_minor_body_position(MinorBody::Apophis, &time);
+        let apparent = compute_minor_body_position_apparent(MinorBody::Apophis, &time);
+
+        let dot = (geometric.direction.x * apparent.direction.x
+            + geometric.direction.y * apparent.direction.y
+            + geometric.direction.z * apparent.direction.z)
+            .clamp(-1.0, 1.0);
+        let sep_arcsec = dot.acos().to_degrees() * 3600.0;
+
+        assert!(
+            sep_arcsec > 0.001,
+            "expected a measurable light-time/aberration shift, got {sep_arcsec} arcsec"
+        );
+    }
+
+    #[test]
+    fn test_solar_deflection_no_op_far_from_sun() {
+        // Looking directly away from the Sun, χ = π and the deflection term
+        // (1+cos χ)/sin χ → 0, so the direction should be left untouched.
+        let earth_pos = (1.0, 0.0, 0.0);
+        let obliquity = 0.0;
+        let away_from_sun = CartesianCoord::new(1.0, 0.0, 0.0);
+        let deflected = apply_solar_deflection(away_from_sun, earth_pos, obliquity);
+        assert!((deflected.x - away_from_sun.x).abs() < 1e-9);
+        assert!((deflected.y - away_from_sun.y).abs() < 1e-9);
+        assert!((deflected.z - away_from_sun.z).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_solar_deflection_pushes_direction_away_from_sun() {
+        // A body 80 degrees from the Sun (elongation) should end up at a
+        // slightly larger elongation after deflection, and the result
+        // should remain a unit vector.
+        let earth_pos = (1.0, 0.0, 0.0); // Sun is in the -x direction from Earth
+        let obliquity = 0.0;
+        let sun_dir = CartesianCoord::new(-1.0, 0.0, 0.0);
+
+        let theta = 100f64.to_radians();
+        let near_limb = CartesianCoord::new(theta.cos(), theta.sin(), 0.0);
+        let deflected = apply_solar_deflection(near_limb, earth_pos, obliquity);
+
+        let len = (deflected.x.powi(2) + deflected.y.powi(2) + deflected.z.powi(2)).sqrt();
+        assert!((len - 1.0).abs() < 1e-9, "expected unit vector, got len={len}");
+
+        let cos_chi_before = near_limb.x * sun_dir.x + near_limb.y * sun_dir.y + near_limb.z * sun_dir.z;
+        let cos_chi_after = deflected.x * sun_dir.x + deflected.y * sun_dir.y + deflected.z * sun_dir.z;
+        assert!(
+            cos_chi_after < cos_chi_before,
+            "expected deflection to increase elongation from the Sun, got cos_chi {cos_chi_before} -> {cos_chi_after}"
+        );
+    }
+
+    #[test]
+    fn test_from_mpc_line_sbdb_csv_parses_all_fields() {
+        let line = "2025 AB,2461000.5,0.2,2.5,10.0,80.0,150.0,200.0,12.0,0.2";
+        let elem = OrbitalElements::from_mpc_line(line).expect("should parse");
+
+        assert_eq!(elem.name, "2025 AB");
+        assert!((elem.eccentricity - 0.2).abs() < 1e-9);
+        assert!((elem.semi_major_axis_au - 2.5).abs() < 1e-9);
+        assert!((elem.inclination_rad.to_degrees() - 10.0).abs() < 1e-6);
+        assert!((elem.ascending_node_rad.to_degrees() - 80.0).abs() < 1e-6);
+        assert!((elem.arg_perihelion_rad.to_degrees() - 150.0).abs() < 1e-6);
+        assert!((elem.abs_mag_h - 12.0).abs() < 1e-9);
+        assert!((elem.slope_g - 0.2).abs() < 1e-9);
+
+        // The parsed elements should flow through the same pipeline as a
+        // bundled body.
+        let time = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0);
+        let pos = compute_position_for_elements(&elem, &time);
+        assert!(pos.distance_km.is_finite() && pos.distance_km > 0.0);
+    }
+
+    #[test]
+    fn test_from_mpc_line_fixed_width_parses_designation_and_elements() {
+        let mut line = vec![b' '; 195];
+        let mut put = |start: usize, s: &str| {
+            let bytes = s.as_bytes();
+            line[start - 1..start - 1 + bytes.len()].copy_from_slice(bytes);
+        };
+        put(9, "3.34"); // H
+        put(15, "0.12"); // G
+        put(21, "K239L"); // packed epoch -> 2023-09-21
+        put(27, "100.0000"); // mean anomaly (deg)
+        put(38, "73.5970"); // argument of perihelion (deg)
+        put(49, "80.3050"); // longitude of ascending node (deg)
+        put(60, "10.5940"); // inclination (deg)
+        put(71, "0.0760"); // eccentricity
+        put(81, "0.21408"); // mean daily motion (deg/day)
+        put(93, "2.7691"); // semi-major axis (AU)
+        put(167, "(1) Ceres"); // readable designation
+        let line = String::from_utf8(line).unwrap();
+
+        let elem = OrbitalElements::from_mpc_line(&line).expect("should parse");
+
+        assert_eq!(elem.name, "(1) Ceres");
+        assert!((elem.semi_major_axis_au - 2.7691).abs() < 1e-9);
+        assert!((elem.eccentricity - 0.0760).abs() < 1e-9);
+        assert!((elem.arg_perihelion_rad.to_degrees() - 73.5970).abs() < 1e-6);
+        assert!((elem.ascending_node_rad.to_degrees() - 80.3050).abs() < 1e-6);
+        assert!((elem.inclination_rad.to_degrees() - 10.5940).abs() < 1e-6);
+        assert!((elem.abs_mag_h - 3.34).abs() < 1e-9);
+        assert!((elem.slope_g - 0.12).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_from_mpc_line_rejects_malformed_input() {
+        assert!(OrbitalElements::from_mpc_line("not,enough,fields").is_err());
+        assert!(OrbitalElements::from_mpc_line("too short").is_err());
+    }
+
+    #[test]
+    fn test_decode_packed_epoch() {
+        let jd = decode_packed_epoch("K239L").unwrap();
+        let expected = SkyTime::from_utc(2023, 9, 21, 0, 0, 0.0).julian_date_tdb();
+        assert!((jd - expected).abs() < 1e-6);
+        assert!(decode_packed_epoch("J9").is_err());
+    }
 }
@@ -0,0 +1,358 @@
+//! Generic Keplerian orbit propagation for bodies outside the built-in
+//! `Comet`/`MinorBody` catalogs.
+//!
+//! `comets` and `minor_bodies` each hardcode a fixed set of named objects.
+//! This module instead takes raw classical elements -- semi-major axis,
+//! eccentricity, inclination, node, argument of perihelion, mean anomaly at
+//! epoch -- so callers can track an arbitrary asteroid, a newly-discovered
+//! comet, or a fictional body without adding it to either enum.
+
+use crate::coords::{ecliptic_to_equatorial, true_obliquity, CartesianCoord};
+use crate::planets::{Planet, AU_TO_KM};
+use crate::time::SkyTime;
+use std::f64::consts::PI;
+
+/// Gaussian gravitational constant squared (AU^3/day^2), the same constant
+/// `comets` uses to turn a semi-major axis into a mean motion via Kepler's
+/// third law.
+const K_SQUARED: f64 = 0.01720209895 * 0.01720209895;
+
+/// Classical heliocentric Keplerian elements for an arbitrary body.
+///
+/// `semi_major_axis_au` is negative for hyperbolic orbits (`eccentricity >
+/// 1`), matching the JPL Small-Body Database convention. For a parabolic
+/// orbit (`eccentricity` within [`PARABOLIC_ECCENTRICITY_TOLERANCE`] of 1,
+/// where the semi-major axis isn't defined) it instead holds the perihelion
+/// distance, `mean_anomaly_at_epoch_rad` is unused, and `epoch_jde` is taken
+/// to be the time of perihelion passage -- mirroring how `mean_anomaly_at_epoch_rad
+/// = 0` already marks perihelion for the elliptical/hyperbolic cases.
+#[derive(Debug, Clone, Copy)]
+pub struct KeplerianElements {
+    /// Semi-major axis in AU (negative for hyperbolic orbits, perihelion
+    /// distance for parabolic ones -- see the struct docs).
+    pub semi_major_axis_au: f64,
+    /// Orbital eccentricity (<1 elliptical, >1 hyperbolic).
+    pub eccentricity: f64,
+    /// Inclination to the ecliptic in radians.
+    pub inclination_rad: f64,
+    /// Longitude of ascending node in radians (Ω).
+    pub ascending_node_rad: f64,
+    /// Argument of perihelion in radians (ω).
+    pub arg_perihelion_rad: f64,
+    /// Mean anomaly at `epoch_jde` in radians (M0).
+    pub mean_anomaly_at_epoch_rad: f64,
+    /// Julian Date (TDB) at which `mean_anomaly_at_epoch_rad` applies.
+    pub epoch_jde: f64,
+}
+
+impl KeplerianElements {
+    /// Create elements from degrees (convenience constructor, matching
+    /// `CometElements::from_degrees` and `OrbitalElements::from_degrees`).
+    pub const fn from_degrees(
+        semi_major_axis_au: f64,
+        eccentricity: f64,
+        inclination_deg: f64,
+        ascending_node_deg: f64,
+        arg_perihelion_deg: f64,
+        mean_anomaly_at_epoch_deg: f64,
+        epoch_jde: f64,
+    ) -> Self {
+        let deg_to_rad = PI / 180.0;
+        Self {
+            semi_major_axis_au,
+            eccentricity,
+            inclination_rad: inclination_deg * deg_to_rad,
+            ascending_node_rad: ascending_node_deg * deg_to_rad,
+            arg_perihelion_rad: arg_perihelion_deg * deg_to_rad,
+            mean_anomaly_at_epoch_rad: mean_anomaly_at_epoch_deg * deg_to_rad,
+            epoch_jde,
+        }
+    }
+}
+
+/// Position of a custom body as seen from Earth.
+pub struct CustomBodyPosition {
+    /// Direction from Earth (unit vector in equatorial J2000).
+    pub direction: CartesianCoord,
+    /// Distance from Earth in km.
+    pub distance_km: f64,
+    /// Distance from the Sun in km.
+    pub helio_distance_km: f64,
+}
+
+/// Solve Kepler's equation `M = E - e*sin(E)` for the eccentric anomaly `E`,
+/// given mean anomaly `M` and eccentricity `e < 1`, by Newton-Raphson
+/// iteration seeded at `E0 = M` (adequate even for near-parabolic orbits --
+/// convergence just takes a few more iterations as `e` approaches 1).
+fn solve_kepler_elliptical(mean_anomaly: f64, eccentricity: f64) -> f64 {
+    let m = mean_anomaly % (2.0 * PI);
+    let mut e_anomaly = m;
+
+    for _ in 0..30 {
+        let delta = (e_anomaly - eccentricity * e_anomaly.sin() - m)
+            / (1.0 - eccentricity * e_anomaly.cos());
+        e_anomaly -= delta;
+        if delta.abs() < 1e-12 {
+            break;
+        }
+    }
+
+    e_anomaly
+}
+
+/// Eccentricity must be within this distance of 1 to be treated as
+/// parabolic rather than a (possibly near-parabolic) ellipse/hyperbola --
+/// matching `comets::compute_heliocentric_ecliptic_comet`'s tolerance.
+const PARABOLIC_ECCENTRICITY_TOLERANCE: f64 = 0.0001;
+
+/// Solve Barker's equation for a parabolic orbit (`e = 1`): `W = s + s^3/3`
+/// where `s = tan(ν/2)` and `W = (3/2)·k·t/q^(3/2)`, given `days_since_perihelion`
+/// (`t`) and `perihelion_distance_au` (`q`). Returns the true anomaly `ν`.
+fn solve_barker(days_since_perihelion: f64, perihelion_distance_au: f64) -> f64 {
+    let k = 0.01720209895;
+    let w = 1.5 * k * days_since_perihelion / perihelion_distance_au.powf(1.5);
+
+    let mut s = if w.abs() < 1.0 {
+        w
+    } else {
+        w.signum() * w.abs().cbrt() * 1.5
+    };
+
+    for _ in 0..15 {
+        let f = s + s.powi(3) / 3.0 - w;
+        let df = 1.0 + s.powi(2);
+        let delta = f / df;
+        s -= delta;
+        if delta.abs() < 1e-12 {
+            break;
+        }
+    }
+
+    2.0 * s.atan()
+}
+
+/// Solve the hyperbolic Kepler equation `M = e*sinh(H) - H` for the
+/// hyperbolic anomaly `H`, given mean anomaly `M` and eccentricity `e > 1`.
+fn solve_kepler_hyperbolic(mean_anomaly: f64, eccentricity: f64) -> f64 {
+    let mut h = if mean_anomaly.abs() < 1.0 {
+        mean_anomaly
+    } else {
+        mean_anomaly.signum() * (2.0 * mean_anomaly.abs() / eccentricity).ln()
+    };
+
+    for _ in 0..30 {
+        let f = eccentricity * h.sinh() - h - mean_anomaly;
+        let df = eccentricity * h.cosh() - 1.0;
+        let delta = f / df;
+        h -= delta;
+        if delta.abs() < 1e-12 {
+            break;
+        }
+    }
+
+    h
+}
+
+/// Compute the heliocentric ecliptic position (AU, J2000 ecliptic frame) of
+/// a body following `elements` at `jde`.
+fn heliocentric_ecliptic_position_au(elements: &KeplerianElements, jde: f64) -> (f64, f64, f64) {
+    let e = elements.eccentricity;
+    let dt = jde - elements.epoch_jde;
+
+    let (true_anomaly, r) = if (e - 1.0).abs() < PARABOLIC_ECCENTRICITY_TOLERANCE {
+        // Parabolic: `semi_major_axis_au` holds the perihelion distance and
+        // `epoch_jde` the perihelion passage time (see the struct docs), so
+        // `dt` is already the days-since-perihelion Barker's equation needs.
+        let q = elements.semi_major_axis_au;
+        let nu = solve_barker(dt, q);
+        let r = q * (1.0 + (nu / 2.0).tan().powi(2)); // r = q * sec²(ν/2)
+        (nu, r)
+    } else if e < 1.0 {
+        let a = elements.semi_major_axis_au;
+        let n = (K_SQUARED / a.powi(3)).sqrt();
+        let m = elements.mean_anomaly_at_epoch_rad + n * dt;
+
+        let e_anomaly = solve_kepler_elliptical(m, e);
+        let nu = 2.0
+            * ((1.0 + e).sqrt() * (e_anomaly / 2.0).tan()).atan2((1.0 - e).sqrt());
+        let r = a * (1.0 - e * e_anomaly.cos());
+        (nu, r)
+    } else {
+        // Hyperbolic: semi_major_axis_au is negative by convention, so use
+        // its magnitude to size the mean motion and radius.
+        let a = elements.semi_major_axis_au.abs();
+        let n = (K_SQUARED / a.powi(3)).sqrt();
+        let m = elements.mean_anomaly_at_epoch_rad + n * dt;
+
+        let h = solve_kepler_hyperbolic(m, e);
+        let nu = 2.0 * ((e + 1.0).sqrt() * (h / 2.0).tanh()).atan2((e - 1.0).sqrt());
+        let r = a * (e * h.cosh() - 1.0);
+        (nu, r)
+    };
+
+    // Position in the orbital (perifocal) plane.
+    let x_orbit = r * true_anomaly.cos();
+    let y_orbit = r * true_anomaly.sin();
+
+    // Rotate perifocal -> ecliptic by argument of perihelion, inclination,
+    // and longitude of ascending node (same rotation `comets` and
+    // `minor_bodies` use).
+    let omega = elements.ascending_node_rad;
+    let i = elements.inclination_rad;
+    let w = elements.arg_perihelion_rad;
+
+    let (sin_omega, cos_omega) = omega.sin_cos();
+    let (sin_i, cos_i) = i.sin_cos();
+    let (sin_w, cos_w) = w.sin_cos();
+
+    let p1 = cos_omega * cos_w - sin_omega * sin_w * cos_i;
+    let p2 = -cos_omega * sin_w - sin_omega * cos_w * cos_i;
+    let q1 = sin_omega * cos_w + cos_omega * sin_w * cos_i;
+    let q2 = -sin_omega * sin_w + cos_omega * cos_w * cos_i;
+    let r1 = sin_w * sin_i;
+    let r2 = cos_w * sin_i;
+
+    (
+        p1 * x_orbit + p2 * y_orbit,
+        q1 * x_orbit + q2 * y_orbit,
+        r1 * x_orbit + r2 * y_orbit,
+    )
+}
+
+/// Compute the position of a body following `elements` as seen from Earth at
+/// `time`.
+///
+/// Mirrors `compute_comet_position`/`compute_minor_body_position`'s
+/// geocentric reduction, but isn't tied to either fixed catalog enum, so
+/// `elements` can describe an asteroid, a newly-announced comet, or a
+/// fictional body. Returns a `CustomBodyPosition` rather than
+/// `CelestialBodyPosition` since the latter's `body` field is a closed
+/// `CelestialBody` enum that has no variant for an arbitrary object.
+pub fn compute_custom_body_position(elements: &KeplerianElements, time: &SkyTime) -> CustomBodyPosition {
+    let jde = time.julian_date_tdb();
+
+    let (body_x, body_y, body_z) = heliocentric_ecliptic_position_au(elements, jde);
+    let earth_pos = crate::planets::heliocentric_position(Planet::Earth, jde);
+
+    let geo_x = body_x - earth_pos.0;
+    let geo_y = body_y - earth_pos.1;
+    let geo_z = body_z - earth_pos.2;
+
+    let distance_au = (geo_x * geo_x + geo_y * geo_y + geo_z * geo_z).sqrt();
+    let helio_distance_au = (body_x * body_x + body_y * body_y + body_z * body_z).sqrt();
+
+    let lon = geo_y.atan2(geo_x);
+    let lat = (geo_z / distance_au).asin();
+
+    let obliquity = true_obliquity(jde);
+    let direction = ecliptic_to_equatorial(lon, lat, obliquity).normalize();
+
+    CustomBodyPosition {
+        direction,
+        distance_km: distance_au * AU_TO_KM,
+        helio_distance_km: helio_distance_au * AU_TO_KM,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kepler_elliptical_solution_satisfies_equation() {
+        let e_anomaly = solve_kepler_elliptical(1.2, 0.3);
+        let m_check = e_anomaly - 0.3 * e_anomaly.sin();
+        assert!((m_check - 1.2).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_kepler_hyperbolic_solution_satisfies_equation() {
+        let h = solve_kepler_hyperbolic(1.0, 1.5);
+        let m_check = 1.5 * h.sinh() - h;
+        assert!((m_check - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_custom_elliptical_body_matches_pluto_like_orbit() {
+        // Reuses Pluto's published elements (JPL Horizons, epoch J2000) as
+        // an arbitrary elliptical body and checks the result is a sane
+        // unit-vector direction at a plausible distance.
+        let elements = KeplerianElements::from_degrees(
+            39.48211675,
+            0.2488273,
+            17.14175,
+            110.30347,
+            113.76329,
+            14.86205,
+            2451545.0,
+        );
+        let time = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0);
+        let pos = compute_custom_body_position(&elements, &time);
+
+        let len = (pos.direction.x * pos.direction.x
+            + pos.direction.y * pos.direction.y
+            + pos.direction.z * pos.direction.z)
+            .sqrt();
+        assert!((len - 1.0).abs() < 1e-9, "got len={len}");
+
+        let helio_au = pos.helio_distance_km / AU_TO_KM;
+        assert!(
+            (29.0..50.0).contains(&helio_au),
+            "expected a Pluto-like heliocentric distance, got {helio_au} AU"
+        );
+    }
+
+    #[test]
+    fn test_custom_hyperbolic_body_has_positive_distance() {
+        // A hyperbolic interstellar-object-like orbit: negative semi-major
+        // axis, eccentricity > 1.
+        let elements = KeplerianElements::from_degrees(
+            -1.27, 3.36, 122.7, 24.6, 241.8, 0.0, 2458000.0,
+        );
+        let time = SkyTime::from_utc(2018, 1, 1, 0, 0, 0.0);
+        let pos = compute_custom_body_position(&elements, &time);
+
+        assert!(pos.distance_km > 0.0);
+        assert!(pos.helio_distance_km > 0.0);
+        assert!(pos.direction.x.is_finite() && pos.direction.y.is_finite() && pos.direction.z.is_finite());
+    }
+
+    #[test]
+    fn test_kepler_barker_solution_satisfies_equation() {
+        let nu = solve_barker(30.0, 0.8);
+        let s = (nu / 2.0).tan();
+        let w = 1.5 * 0.01720209895 * 30.0 / 0.8_f64.powf(1.5);
+        assert!((s + s.powi(3) / 3.0 - w).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_exact_parabolic_eccentricity_converges() {
+        // `semi_major_axis_au` holds the perihelion distance and `epoch_jde`
+        // the perihelion passage time for an exactly parabolic orbit.
+        let elements = KeplerianElements::from_degrees(
+            1.5, 1.0, 45.0, 10.0, 20.0, 0.0, 2451545.0,
+        );
+        let time = SkyTime::from_utc(2024, 6, 1, 0, 0, 0.0);
+        let pos = compute_custom_body_position(&elements, &time);
+
+        assert!(pos.direction.x.is_finite() && pos.direction.y.is_finite() && pos.direction.z.is_finite());
+        assert!(pos.distance_km.is_finite() && pos.distance_km > 0.0);
+        assert!(
+            pos.helio_distance_km / AU_TO_KM >= 1.5 - 1e-6,
+            "heliocentric distance should never fall below perihelion distance: {} AU",
+            pos.helio_distance_km / AU_TO_KM
+        );
+    }
+
+    #[test]
+    fn test_near_parabolic_eccentricity_converges() {
+        let elements = KeplerianElements::from_degrees(
+            50.0, 0.9999, 45.0, 10.0, 20.0, 5.0, 2451545.0,
+        );
+        let time = SkyTime::from_utc(2024, 6, 1, 0, 0, 0.0);
+        let pos = compute_custom_body_position(&elements, &time);
+
+        assert!(pos.direction.x.is_finite());
+        assert!(pos.distance_km.is_finite() && pos.distance_km > 0.0);
+    }
+}
@@ -12,6 +12,7 @@ pub const JUPITER_RADIUS_KM: f64 = 69911.0;
 pub const SATURN_RADIUS_KM: f64 = 58232.0;
 pub const URANUS_RADIUS_KM: f64 = 25362.0;
 pub const NEPTUNE_RADIUS_KM: f64 = 24622.0;
+pub const PLUTO_RADIUS_KM: f64 = 1188.3;
 
 /// Conversion factor from AU to km
 pub const AU_TO_KM: f64 = 149_597_870.7;
@@ -28,10 +29,15 @@ pub enum Planet {
     Saturn = 5,
     Uranus = 6,
     Neptune = 7,
+    /// Dwarf planet, kept out of [`Planet::ALL`]/[`Planet::VISIBLE`]: it has
+    /// no VSOP87A term (see [`pluto_heliocentric_position`]) and is never
+    /// naked-eye visible. Use [`compute_pluto_position_full`] directly.
+    Pluto = 8,
 }
 
 impl Planet {
-    /// All planets in order from the Sun.
+    /// All VSOP87A-backed planets in order from the Sun. Excludes
+    /// [`Planet::Pluto`], which uses its own analytic series.
     pub const ALL: [Planet; 8] = [
         Planet::Mercury,
         Planet::Venus,
@@ -63,6 +69,7 @@ impl Planet {
             Planet::Saturn => "Saturn",
             Planet::Uranus => "Uranus",
             Planet::Neptune => "Neptune",
+            Planet::Pluto => "Pluto",
         }
     }
 }
@@ -112,9 +119,206 @@ impl CelestialBody {
     }
 }
 
+/// Phase angle `i` (Sun-body-Earth angle) and illuminated fraction `k`, from
+/// the law of cosines on the Sun-body-Earth triangle: `r` is the body's
+/// heliocentric distance, `delta` its geocentric distance, and `sun_earth`
+/// the Sun-Earth distance, all in AU.
+fn phase_angle_and_illumination(r_au: f64, delta_au: f64, sun_earth_au: f64) -> (f64, f64) {
+    let cos_i = ((r_au * r_au + delta_au * delta_au - sun_earth_au * sun_earth_au)
+        / (2.0 * r_au * delta_au))
+        .clamp(-1.0, 1.0);
+    let phase_angle_rad = cos_i.acos();
+    let illuminated_fraction = (1.0 + cos_i) / 2.0;
+    (phase_angle_rad, illuminated_fraction)
+}
+
+/// WGCCRE rotational elements for `planet`'s central-meridian longitude, or
+/// `None` for a body [`crate::rotation`] doesn't carry elements for (Earth,
+/// observed from itself, and Pluto, which has no WGCCRE entry here).
+fn planet_rotational_elements(planet: Planet) -> Option<crate::rotation::RotationalElements> {
+    match planet {
+        Planet::Mercury => Some(crate::rotation::MERCURY_ROTATION),
+        Planet::Venus => Some(crate::rotation::VENUS_ROTATION),
+        Planet::Mars => Some(crate::rotation::MARS_ROTATION),
+        Planet::Jupiter => Some(crate::rotation::JUPITER_ROTATION),
+        Planet::Saturn => Some(crate::rotation::SATURN_ROTATION),
+        Planet::Uranus => Some(crate::rotation::URANUS_ROTATION),
+        Planet::Neptune => Some(crate::rotation::NEPTUNE_ROTATION),
+        Planet::Earth | Planet::Pluto => None,
+    }
+}
+
+/// Position angle of the bright limb (the sunward edge of the illuminated
+/// disk), measured eastward from celestial north -- the same construction
+/// [`crate::jupiter::compute_jupiter_physical`] uses for its pole position
+/// angle (Meeus, *Astronomical Algorithms*, ch. 48), fed the Sun's apparent
+/// direction instead of a rotation pole.
+fn bright_limb_position_angle(body_ra: f64, body_dec: f64, sun_ra: f64, sun_dec: f64) -> f64 {
+    let delta_ra = sun_ra - body_ra;
+    let numerator = sun_dec.cos() * delta_ra.sin();
+    let denominator = sun_dec.sin() * body_dec.cos() - sun_dec.cos() * body_dec.sin() * delta_ra.cos();
+    numerator.atan2(denominator).rem_euclid(2.0 * PI)
+}
+
+/// Sine of Saturn's ring-opening angle `B` as seen from Earth: the angle
+/// between Earth's line of sight and Saturn's ring plane, found as the
+/// projection of the Saturn-to-Earth direction onto Saturn's (equatorial,
+/// J2000) rotation pole -- since the ring plane is, by definition,
+/// perpendicular to that pole.
+fn saturn_ring_opening_sin(saturn_to_earth_equatorial: CartesianCoord, jde: f64) -> f64 {
+    let t_centuries = (jde - 2451545.0) / 36525.0;
+    let (pole_ra, pole_dec) = crate::rotation::SATURN_ROTATION.pole(t_centuries);
+    let pole = CartesianCoord::new(
+        pole_dec.cos() * pole_ra.cos(),
+        pole_dec.cos() * pole_ra.sin(),
+        pole_dec.sin(),
+    );
+    let v = saturn_to_earth_equatorial.normalize();
+    (v.x * pole.x + v.y * pole.y + v.z * pole.z).clamp(-1.0, 1.0)
+}
+
+/// Position angle of Saturn's ring-plane northern semiminor axis
+/// (equivalently, its north pole) on the sky, measured eastward from
+/// celestial north -- the same construction [`crate::jupiter`]'s
+/// `pole_position_angle` uses for Jupiter's own pole (Meeus, *Astronomical
+/// Algorithms*, ch. 45).
+fn saturn_ring_position_angle(pole_ra: f64, pole_dec: f64, saturn_ra: f64, saturn_dec: f64) -> f64 {
+    let delta_ra = pole_ra - saturn_ra;
+    let numerator = pole_dec.cos() * delta_ra.sin();
+    let denominator =
+        pole_dec.sin() * saturn_dec.cos() - pole_dec.cos() * saturn_dec.sin() * delta_ra.cos();
+    numerator.atan2(denominator).rem_euclid(2.0 * PI)
+}
+
+/// Saturn's ring-plane geometry at a given instant: how open the rings
+/// appear from Earth and from the Sun, and their projected orientation on
+/// the sky -- the classic "are the rings edge-on?" answer.
+#[derive(Debug, Clone, Copy)]
+pub struct RingEphemeris {
+    /// Saturnicentric latitude of Earth, `B`, radians: positive when
+    /// Saturn's north face is presented to Earth, zero when the rings are
+    /// seen edge-on.
+    pub earth_latitude_rad: f64,
+    /// Saturnicentric latitude of the Sun, `B'`, radians: the same quantity
+    /// for the Sun direction, which governs whether Earth sees the rings'
+    /// sunlit face or their shadowed one.
+    pub sun_latitude_rad: f64,
+    /// Position angle of the ring system's northern semiminor axis on the
+    /// sky, measured eastward from celestial north, radians.
+    pub position_angle_rad: f64,
+}
+
+/// Computes [`RingEphemeris`] at TDB Julian date `jde`: `B` and `B'` reuse
+/// [`saturn_ring_opening_sin`]'s pole-projection (once for the
+/// Saturn-to-Earth direction, once for Saturn-to-Sun), and `P` comes from
+/// [`saturn_ring_position_angle`] applied to Saturn's own apparent RA/Dec.
+pub fn saturn_ring_ephemeris(jde: f64) -> RingEphemeris {
+    let earth_pos = heliocentric_position(Planet::Earth, jde);
+    let saturn_pos = heliocentric_position(Planet::Saturn, jde);
+
+    let saturn_to_earth = (
+        earth_pos.0 - saturn_pos.0,
+        earth_pos.1 - saturn_pos.1,
+        earth_pos.2 - saturn_pos.2,
+    );
+    let saturn_to_earth_au = (saturn_to_earth.0 * saturn_to_earth.0
+        + saturn_to_earth.1 * saturn_to_earth.1
+        + saturn_to_earth.2 * saturn_to_earth.2)
+        .sqrt();
+    let saturn_to_earth_eq = ecliptic_to_equatorial(
+        saturn_to_earth.1.atan2(saturn_to_earth.0),
+        (saturn_to_earth.2 / saturn_to_earth_au).asin(),
+        OBLIQUITY_J2000,
+    );
+
+    // The Sun sits at the coordinate origin of this heliocentric model, so
+    // the Saturn-to-Sun direction is just Saturn's own position, negated.
+    let saturn_to_sun_au = (saturn_pos.0 * saturn_pos.0
+        + saturn_pos.1 * saturn_pos.1
+        + saturn_pos.2 * saturn_pos.2)
+        .sqrt();
+    let saturn_to_sun_eq = ecliptic_to_equatorial(
+        (-saturn_pos.1).atan2(-saturn_pos.0),
+        (-saturn_pos.2 / saturn_to_sun_au).asin(),
+        OBLIQUITY_J2000,
+    );
+
+    let earth_latitude_rad = saturn_ring_opening_sin(saturn_to_earth_eq, jde).asin();
+    let sun_latitude_rad = saturn_ring_opening_sin(saturn_to_sun_eq, jde).asin();
+
+    let t_centuries = (jde - 2451545.0) / 36525.0;
+    let (pole_ra, pole_dec) = crate::rotation::SATURN_ROTATION.pole(t_centuries);
+    let earth_to_saturn_eq =
+        CartesianCoord::new(-saturn_to_earth_eq.x, -saturn_to_earth_eq.y, -saturn_to_earth_eq.z);
+    let (saturn_ra, saturn_dec) = crate::coords::cartesian_to_ra_dec(&earth_to_saturn_eq);
+    let position_angle_rad = saturn_ring_position_angle(pole_ra, pole_dec, saturn_ra, saturn_dec);
+
+    RingEphemeris {
+        earth_latitude_rad,
+        sun_latitude_rad,
+        position_angle_rad,
+    }
+}
+
+/// Apparent visual magnitude of a planet, from the classical per-planet
+/// polynomials in phase angle `i` (degrees) and `r`/`delta` (AU) (Meeus,
+/// *Astronomical Algorithms*, ch. 41-42; Astronomical Almanac).
+fn planet_apparent_magnitude(
+    planet: Planet,
+    r_au: f64,
+    delta_au: f64,
+    phase_angle_rad: f64,
+    ring_opening_sin: f64,
+) -> f64 {
+    let base = 5.0 * (r_au * delta_au).log10();
+    let i = phase_angle_rad.to_degrees();
+    let i2 = i * i;
+    let i3 = i2 * i;
+
+    match planet {
+        Planet::Mercury => -0.42 + base + 0.0380 * i - 0.000273 * i2 + 2.0e-6 * i3,
+        Planet::Venus => -4.40 + base + 0.0009 * i + 0.000239 * i2 - 6.5e-7 * i3,
+        Planet::Earth => f64::NAN,
+        Planet::Mars => -1.52 + base + 0.016 * i,
+        Planet::Jupiter => -9.40 + base + 0.005 * i,
+        Planet::Saturn => {
+            -8.88 + base + 0.044 * i - 2.60 * ring_opening_sin.abs()
+                + 1.25 * ring_opening_sin * ring_opening_sin
+        }
+        Planet::Uranus => -7.19 + base,
+        Planet::Neptune => -6.87 + base,
+        // Pluto's phase angle from Earth never exceeds ~2 degrees, so (as
+        // for Uranus/Neptune above) the phase term is dropped entirely.
+        Planet::Pluto => -1.01 + base,
+    }
+}
+
+/// Apparent visual magnitude of the Sun at geocentric distance `distance_au`
+/// (AU), scaled from its standard magnitude of -26.74 at 1 AU.
+fn sun_apparent_magnitude(distance_au: f64) -> f64 {
+    -26.74 + 5.0 * distance_au.log10()
+}
+
+/// Apparent visual magnitude of the Moon from its phase angle `i` (degrees),
+/// a widely used empirical approximation (Allen, *Astrophysical Quantities*)
+/// that folds the Moon's small distance variation into the constant term
+/// rather than an explicit `5 log10(r * delta)` as for the planets.
+fn moon_apparent_magnitude(phase_angle_rad: f64) -> f64 {
+    let i = phase_angle_rad.to_degrees().abs();
+    -12.73 + 0.026 * i + 4.0e-9 * i.powi(4)
+}
+
 /// Compute heliocentric position of a planet using VSOP87A.
 /// Returns (x, y, z) in AU, ecliptic coordinates.
-fn heliocentric_position(planet: Planet, jde: f64) -> (f64, f64, f64) {
+pub(crate) fn heliocentric_position(planet: Planet, jde: f64) -> (f64, f64, f64) {
+    // VSOP87A has no Pluto term; dispatch to Meeus's dedicated analytic
+    // series instead so the rest of the pipeline (geocentric subtraction,
+    // light-time correction, arbitrary-observer queries) keeps working
+    // unmodified for Pluto.
+    if planet == Planet::Pluto {
+        return pluto_heliocentric_position(jde);
+    }
+
     let coords = match planet {
         Planet::Mercury => vsop87a::mercury(jde),
         Planet::Venus => vsop87a::venus(jde),
@@ -124,28 +328,145 @@ fn heliocentric_position(planet: Planet, jde: f64) -> (f64, f64, f64) {
         Planet::Saturn => vsop87a::saturn(jde),
         Planet::Uranus => vsop87a::uranus(jde),
         Planet::Neptune => vsop87a::neptune(jde),
+        Planet::Pluto => unreachable!("handled above"),
     };
     (coords.x, coords.y, coords.z)
 }
 
+/// Pluto's heliocentric ecliptic position (x, y, z in AU, J2000) from the
+/// low-order periodic-term solution valid for 1885-2099 (Meeus,
+/// *Astronomical Algorithms*, ch. 37): arguments `J`, `S`, `P` are mean
+/// longitudes of Jupiter, Saturn, and Pluto itself, and the longitude,
+/// latitude, and radius vector are each a base value plus a sum of
+/// `A*sin(arg) + B*cos(arg)` terms over integer combinations of `J, S, P`
+/// (`PLUTO_TERMS`). Meeus's full Table 37.A has 43 terms; `PLUTO_TERMS`
+/// keeps the leading, dominant ones (amplitudes fall off quickly), which is
+/// enough for display-quality positions but not occultation-grade precision.
+fn pluto_heliocentric_position(jde: f64) -> (f64, f64, f64) {
+    let t = (jde - 2451545.0) / 36525.0;
+
+    let j = (34.35 + 3034.9057 * t).to_radians();
+    let s = (50.08 + 1222.1138 * t).to_radians();
+    let p = (238.96 + 144.9600 * t).to_radians();
+
+    let mut d_lon_1e6deg = 0.0;
+    let mut d_lat_1e6deg = 0.0;
+    let mut d_r_1e7au = 0.0;
+
+    for &(mj, ms, mp, a_lon, b_lon, a_lat, b_lat, a_r, b_r) in PLUTO_TERMS {
+        let arg = mj as f64 * j + ms as f64 * s + mp as f64 * p;
+        let (sin_arg, cos_arg) = (arg.sin(), arg.cos());
+        d_lon_1e6deg += a_lon * sin_arg + b_lon * cos_arg;
+        d_lat_1e6deg += a_lat * sin_arg + b_lat * cos_arg;
+        d_r_1e7au += a_r * sin_arg + b_r * cos_arg;
+    }
+
+    let lon = (238.958116 + 144.96 * t + d_lon_1e6deg * 1e-6).to_radians();
+    let lat = (-3.908239 + d_lat_1e6deg * 1e-6).to_radians();
+    let r_au = 40.7241346 + d_r_1e7au * 1e-7;
+
+    (
+        r_au * lat.cos() * lon.cos(),
+        r_au * lat.cos() * lon.sin(),
+        r_au * lat.sin(),
+    )
+}
+
+/// Leading terms of Meeus's Table 37.A: `(J, S, P, A_lon, B_lon, A_lat,
+/// B_lat, A_r, B_r)`, with `A`/`B` coefficients in units of 1e-6 degree for
+/// longitude/latitude and 1e-7 AU for the radius vector.
+const PLUTO_TERMS: &[(i32, i32, i32, f64, f64, f64, f64, f64, f64)] = &[
+    (0, 0, 1, -19798886.0, 19848454.0, -5453098.0, -14974876.0, 66865439.0, 68951812.0),
+    (0, 0, 2, 897499.0, -4955707.0, 3527363.0, 1672673.0, -11827535.0, -332538.0),
+    (0, 0, 3, 610820.0, 1210521.0, -1050939.0, 327763.0, 1593179.0, -1438890.0),
+    (0, 0, 4, -341639.0, -189719.0, 178691.0, -291925.0, -18444.0, 483220.0),
+    (0, 0, 5, 129620.0, -34863.0, 18763.0, 100616.0, -65977.0, -85431.0),
+    (0, 0, 6, -38185.0, 31061.0, -30594.0, -25843.0, 31174.0, -6032.0),
+    (0, 1, -1, 20349.0, -9886.0, 4965.0, 11161.0, -5794.0, 22161.0),
+    (0, 1, 0, -4045.0, -4904.0, 310.0, -132.0, 4601.0, 4032.0),
+    (0, 1, 1, -5885.0, -3238.0, 2036.0, -947.0, -1729.0, 234.0),
+    (0, 1, 2, -3812.0, 3011.0, 59.0, -610.0, -415.0, 702.0),
+    (0, 1, 3, -601.0, 3468.0, -290.0, 316.0, 239.0, 723.0),
+    (0, 2, -2, 1237.0, 463.0, -49.0, -164.0, -64.0, -178.0),
+    (0, 2, -1, 1226.0, -901.0, -114.0, 189.0, -136.0, -436.0),
+    (0, 2, 0, -65.0, 1320.0, 104.0, -170.0, -114.0, -35.0),
+    (1, -1, 0, 157.0, -495.0, 25.0, -46.0, 38.0, 71.0),
+    (1, -1, 1, 515.0, -673.0, -181.0, 125.0, 79.0, 138.0),
+    (1, 0, -3, 96.0, -522.0, -1.0, 27.0, 66.0, 8.0),
+    (1, 0, -2, -2147.0, -5314.0, 112.0, -3167.0, -2264.0, -24.0),
+];
+
 /// Compute the apparent direction to a planet as seen from Earth.
 /// Returns a unit vector in equatorial coordinates (J2000).
 pub fn compute_planet_position(planet: Planet, time: &SkyTime) -> CartesianCoord {
     compute_planet_position_full(planet, time).direction
 }
 
-/// Compute the full position data for a planet (direction, distance, angular diameter).
+/// Compute the full position data for a planet (direction, distance, angular
+/// diameter) as seen from Earth. Thin wrapper over
+/// [`compute_geocentric_position_from`] with `observer = Planet::Earth`.
 pub fn compute_planet_position_full(planet: Planet, time: &SkyTime) -> PlanetPosition {
+    compute_geocentric_position_from(Planet::Earth, planet, time)
+}
+
+/// Compute the full position data for Pluto (direction, distance, angular
+/// diameter, phase angle, illuminated fraction, apparent magnitude) as seen
+/// from Earth. Thin wrapper over [`compute_geocentric_position_from`], same
+/// as [`compute_planet_position_full`] -- `heliocentric_position` already
+/// routes `Planet::Pluto` to its own analytic series, so no separate code
+/// path is needed here.
+pub fn compute_pluto_position_full(time: &SkyTime) -> PlanetPosition {
+    compute_geocentric_position_from(Planet::Earth, Planet::Pluto, time)
+}
+
+/// Compute the full position data for `target` as seen from `observer` (both
+/// planets): direction, distance, angular diameter, phase angle, illuminated
+/// fraction, and apparent magnitude. Generalizes `compute_planet_position_full`
+/// (which is the `observer = Earth` case) to any vantage point, enabling
+/// "what does the sky look like from Mars" queries and mutual planetary
+/// phenomena (e.g. Jupiter as seen from Saturn).
+pub fn compute_geocentric_position_from(
+    observer: Planet,
+    target: Planet,
+    time: &SkyTime,
+) -> PlanetPosition {
     let jde = time.julian_date_tdb();
 
     // Get heliocentric positions (ecliptic coordinates) in AU
-    let earth_pos = heliocentric_position(Planet::Earth, jde);
-    let planet_pos = heliocentric_position(planet, jde);
+    let observer_pos = heliocentric_position(observer, jde);
+    let target_pos = heliocentric_position(target, jde);
+
+    planet_position_from_heliocentric_vectors(target, jde, observer_pos, target_pos)
+}
+
+/// Geometric Earth-observer position of `planet` at a raw TDB Julian Date,
+/// bypassing [`SkyTime`] -- the `observer = Earth` case of
+/// [`compute_geocentric_position_from`], for callers (light-time iteration
+/// loops, here and in [`crate::planetary_moons`]) that already have a
+/// retarded `jde` rather than a wall-clock [`SkyTime`] to wrap it back into.
+pub(crate) fn planet_geocentric_position_at_jde(planet: Planet, jde: f64) -> PlanetPosition {
+    let observer_pos = heliocentric_position(Planet::Earth, jde);
+    let target_pos = heliocentric_position(planet, jde);
+    planet_position_from_heliocentric_vectors(planet, jde, observer_pos, target_pos)
+}
 
-    // Geocentric position (planet relative to Earth) in AU
-    let geo_x = planet_pos.0 - earth_pos.0;
-    let geo_y = planet_pos.1 - earth_pos.1;
-    let geo_z = planet_pos.2 - earth_pos.2;
+/// Shared tail of [`compute_geocentric_position_from`] and
+/// [`compute_planet_position_with_precision`]'s truncated path: given
+/// already-computed heliocentric ecliptic vectors (AU) for `target` and its
+/// observer, produce the full [`PlanetPosition`] (direction, distance,
+/// angular diameter, phase angle, illuminated fraction, apparent
+/// magnitude). Factored out so both the VSOP87A and low-precision
+/// Keplerian heliocentric sources feed the same downstream geometry.
+fn planet_position_from_heliocentric_vectors(
+    target: Planet,
+    jde: f64,
+    observer_pos: (f64, f64, f64),
+    target_pos: (f64, f64, f64),
+) -> PlanetPosition {
+    // Position of target relative to observer, in AU
+    let geo_x = target_pos.0 - observer_pos.0;
+    let geo_y = target_pos.1 - observer_pos.1;
+    let geo_z = target_pos.2 - observer_pos.2;
 
     // Distance in AU, then convert to km
     let distance_au = (geo_x * geo_x + geo_y * geo_y + geo_z * geo_z).sqrt();
@@ -159,16 +480,238 @@ pub fn compute_planet_position_full(planet: Planet, time: &SkyTime) -> PlanetPos
     let direction = ecliptic_to_equatorial(lon, lat, OBLIQUITY_J2000).normalize();
 
     // Angular diameter: 2 * atan(radius / distance)
-    let radius_km = planet_radius_km(planet);
+    let radius_km = planet_radius_km(target);
     let angular_diameter_rad = 2.0 * (radius_km / distance_km).atan();
 
+    let r_au = (target_pos.0 * target_pos.0 + target_pos.1 * target_pos.1 + target_pos.2 * target_pos.2).sqrt();
+    let sun_observer_au = (observer_pos.0 * observer_pos.0 + observer_pos.1 * observer_pos.1 + observer_pos.2 * observer_pos.2).sqrt();
+    let (phase_angle_rad, illuminated_fraction) =
+        phase_angle_and_illumination(r_au, distance_au, sun_observer_au);
+    let ring_opening_sin = match target {
+        Planet::Saturn => saturn_ring_opening_sin(
+            CartesianCoord::new(-direction.x, -direction.y, -direction.z),
+            jde,
+        ),
+        _ => 0.0,
+    };
+    let apparent_magnitude =
+        planet_apparent_magnitude(target, r_au, distance_au, phase_angle_rad, ring_opening_sin);
+
+    let defect_of_illumination_rad = (angular_diameter_rad / 2.0) * (1.0 - illuminated_fraction);
+    let (body_ra, body_dec) = crate::coords::cartesian_to_ra_dec(&direction);
+    let sun_from_observer_eq = ecliptic_to_equatorial(
+        (-observer_pos.1).atan2(-observer_pos.0),
+        (-observer_pos.2 / sun_observer_au).asin(),
+        OBLIQUITY_J2000,
+    );
+    let (sun_ra, sun_dec) = crate::coords::cartesian_to_ra_dec(&sun_from_observer_eq);
+    let bright_limb_position_angle_rad =
+        bright_limb_position_angle(body_ra, body_dec, sun_ra, sun_dec);
+    let central_meridian_lon_rad = planet_rotational_elements(target)
+        .map(|elements| {
+            let t_centuries = (jde - 2451545.0) / 36525.0;
+            let d_days = jde - 2451545.0;
+            let body_to_observer = CartesianCoord::new(-direction.x, -direction.y, -direction.z);
+            crate::rotation::sub_point(&body_to_observer, &elements, t_centuries, d_days).0
+        })
+        .unwrap_or(0.0);
+
     PlanetPosition {
         direction,
         distance_km,
         angular_diameter_rad,
+        phase_angle_rad,
+        illuminated_fraction,
+        defect_of_illumination_rad,
+        bright_limb_position_angle_rad,
+        central_meridian_lon_rad,
+        apparent_magnitude,
+    }
+}
+
+/// Precision level for [`compute_planet_position_with_precision`]: `Full`
+/// uses the full VSOP87A series (the `vsop87` crate, sub-arcsecond), while
+/// `Truncated` uses the classical low-precision osculating Keplerian
+/// elements (Standish, *Keplerian Elements for Approximate Positions of the
+/// Major Planets*) -- good to a few arcminutes over 1800-2050, but much
+/// cheaper to evaluate since it's a closed-form ellipse rather than a
+/// several-thousand-term trigonometric series.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PositionPrecision {
+    Full,
+    Truncated,
+}
+
+/// Compute a planet's full position data as seen from Earth at the
+/// requested [`PositionPrecision`]. [`compute_planet_position_full`] is
+/// equivalent to calling this with `PositionPrecision::Full`.
+pub fn compute_planet_position_with_precision(
+    planet: Planet,
+    time: &SkyTime,
+    precision: PositionPrecision,
+) -> PlanetPosition {
+    match precision {
+        PositionPrecision::Full => compute_planet_position_full(planet, time),
+        PositionPrecision::Truncated => {
+            let jde = time.julian_date_tdb();
+            let observer_pos = low_precision_heliocentric_position(Planet::Earth, jde);
+            let target_pos = low_precision_heliocentric_position(planet, jde);
+            planet_position_from_heliocentric_vectors(planet, jde, observer_pos, target_pos)
+        }
+    }
+}
+
+/// Osculating Keplerian elements at J2000.0 and their linear rates per
+/// Julian century (Standish, valid 1800-2050): semi-major axis (AU),
+/// eccentricity, inclination, mean longitude, longitude of perihelion, and
+/// longitude of ascending node (degrees).
+struct KeplerianElements {
+    a0: f64,
+    a_rate: f64,
+    e0: f64,
+    e_rate: f64,
+    i0_deg: f64,
+    i_rate: f64,
+    l0_deg: f64,
+    l_rate: f64,
+    peri0_deg: f64,
+    peri_rate: f64,
+    node0_deg: f64,
+    node_rate: f64,
+}
+
+fn keplerian_elements_for(planet: Planet) -> KeplerianElements {
+    match planet {
+        Planet::Mercury => KeplerianElements {
+            a0: 0.38709927, a_rate: 0.00000037,
+            e0: 0.20563593, e_rate: 0.00001906,
+            i0_deg: 7.00497902, i_rate: -0.00594749,
+            l0_deg: 252.25032350, l_rate: 149472.67411175,
+            peri0_deg: 77.45779628, peri_rate: 0.16047689,
+            node0_deg: 48.33076593, node_rate: -0.12534081,
+        },
+        Planet::Venus => KeplerianElements {
+            a0: 0.72333566, a_rate: 0.00000390,
+            e0: 0.00677672, e_rate: -0.00004107,
+            i0_deg: 3.39467605, i_rate: -0.00078890,
+            l0_deg: 181.97909950, l_rate: 58517.81538729,
+            peri0_deg: 131.60246718, peri_rate: 0.00268329,
+            node0_deg: 76.67984255, node_rate: -0.27769418,
+        },
+        Planet::Earth => KeplerianElements {
+            a0: 1.00000261, a_rate: 0.00000562,
+            e0: 0.01671123, e_rate: -0.00004392,
+            i0_deg: -0.00001531, i_rate: -0.01294668,
+            l0_deg: 100.46457166, l_rate: 35999.37244981,
+            peri0_deg: 102.93768193, peri_rate: 0.32327364,
+            node0_deg: 0.0, node_rate: 0.0,
+        },
+        Planet::Mars => KeplerianElements {
+            a0: 1.52371034, a_rate: 0.00001847,
+            e0: 0.09339410, e_rate: 0.00007882,
+            i0_deg: 1.84969142, i_rate: -0.00813131,
+            l0_deg: -4.55343205, l_rate: 19140.30268499,
+            peri0_deg: -23.94362959, peri_rate: 0.44441088,
+            node0_deg: 49.55953891, node_rate: -0.29257343,
+        },
+        Planet::Jupiter => KeplerianElements {
+            a0: 5.20288700, a_rate: -0.00011607,
+            e0: 0.04838624, e_rate: -0.00013253,
+            i0_deg: 1.30439695, i_rate: -0.00183714,
+            l0_deg: 34.39644051, l_rate: 3034.74612775,
+            peri0_deg: 14.72847983, peri_rate: 0.21252668,
+            node0_deg: 100.47390909, node_rate: 0.20469106,
+        },
+        Planet::Saturn => KeplerianElements {
+            a0: 9.53667594, a_rate: -0.00125060,
+            e0: 0.05386179, e_rate: -0.00050991,
+            i0_deg: 2.48599187, i_rate: 0.00193609,
+            l0_deg: 49.95424423, l_rate: 1222.49362201,
+            peri0_deg: 92.59887831, peri_rate: -0.41897216,
+            node0_deg: 113.66242448, node_rate: -0.28867794,
+        },
+        Planet::Uranus => KeplerianElements {
+            a0: 19.18916464, a_rate: -0.00196176,
+            e0: 0.04725744, e_rate: -0.00004397,
+            i0_deg: 0.77263783, i_rate: -0.00242939,
+            l0_deg: 313.23810451, l_rate: 428.48202785,
+            peri0_deg: 170.95427630, peri_rate: 0.40805281,
+            node0_deg: 74.01692503, node_rate: 0.04240589,
+        },
+        Planet::Neptune => KeplerianElements {
+            a0: 30.06992276, a_rate: 0.00026291,
+            e0: 0.00859048, e_rate: 0.00005105,
+            i0_deg: 1.77004347, i_rate: 0.00035372,
+            l0_deg: -55.12002969, l_rate: 218.45945325,
+            peri0_deg: 44.96476227, peri_rate: -0.32241464,
+            node0_deg: 131.78422574, node_rate: -0.00508664,
+        },
+        // Pluto has no entry in Standish's table (it's not one of the
+        // major planets the table covers); its own periodic series is
+        // already a truncated, low-cost approximation, so both precision
+        // levels use it.
+        Planet::Pluto => unreachable!("handled in low_precision_heliocentric_position"),
     }
 }
 
+/// Heliocentric ecliptic position (x, y, z in AU, J2000) from low-precision
+/// osculating Keplerian elements: solve Kepler's equation for the
+/// eccentric anomaly, get the position in the orbital plane, then rotate by
+/// the argument of perihelion, inclination, and longitude of ascending
+/// node into the ecliptic frame.
+fn low_precision_heliocentric_position(planet: Planet, jde: f64) -> (f64, f64, f64) {
+    if planet == Planet::Pluto {
+        return pluto_heliocentric_position(jde);
+    }
+
+    let elem = keplerian_elements_for(planet);
+    let t = (jde - 2451545.0) / 36525.0;
+
+    let a = elem.a0 + elem.a_rate * t;
+    let e = elem.e0 + elem.e_rate * t;
+    let i = (elem.i0_deg + elem.i_rate * t).to_radians();
+    let l = elem.l0_deg + elem.l_rate * t;
+    let long_peri = elem.peri0_deg + elem.peri_rate * t;
+    let long_node = elem.node0_deg + elem.node_rate * t;
+    let arg_peri = (long_peri - long_node).to_radians();
+    let node = long_node.to_radians();
+
+    // Mean anomaly, reduced to (-180, 180] degrees
+    let mut m_deg = (l - long_peri) % 360.0;
+    if m_deg > 180.0 {
+        m_deg -= 360.0;
+    } else if m_deg < -180.0 {
+        m_deg += 360.0;
+    }
+    let m_rad = m_deg.to_radians();
+
+    // Solve Kepler's equation E - e*sin(E) = M by Newton-Raphson.
+    let mut eccentric_anomaly = m_rad;
+    for _ in 0..10 {
+        let delta = (eccentric_anomaly - e * eccentric_anomaly.sin() - m_rad)
+            / (1.0 - e * eccentric_anomaly.cos());
+        eccentric_anomaly -= delta;
+    }
+
+    // Position in the orbital plane.
+    let x_orbit = a * (eccentric_anomaly.cos() - e);
+    let y_orbit = a * (1.0 - e * e).sqrt() * eccentric_anomaly.sin();
+
+    // Rotate by argument of perihelion, inclination, and ascending node
+    // into the ecliptic J2000 frame (Meeus, ch. 33, eq. 33.7).
+    let (sin_peri, cos_peri) = arg_peri.sin_cos();
+    let (sin_i, cos_i) = i.sin_cos();
+    let (sin_node, cos_node) = node.sin_cos();
+
+    let x = (cos_node * cos_peri - sin_node * sin_peri * cos_i) * x_orbit
+        + (-cos_node * sin_peri - sin_node * cos_peri * cos_i) * y_orbit;
+    let y = (sin_node * cos_peri + cos_node * sin_peri * cos_i) * x_orbit
+        + (-sin_node * sin_peri + cos_node * cos_peri * cos_i) * y_orbit;
+    let z = (sin_peri * sin_i) * x_orbit + (cos_peri * sin_i) * y_orbit;
+
+    (x, y, z)
+}
+
 /// Compute positions for all visible planets.
 pub fn compute_all_planet_positions(time: &SkyTime) -> [(Planet, CartesianCoord); 5] {
     Planet::VISIBLE.map(|p| (p, compute_planet_position(p, time)))
@@ -180,17 +723,28 @@ pub fn compute_sun_position(time: &SkyTime) -> CartesianCoord {
     compute_sun_position_full(time).direction
 }
 
-/// Compute the full position data for the Sun (direction, distance, angular diameter).
+/// Compute the full position data for the Sun (direction, distance, angular
+/// diameter) as seen from Earth. Thin wrapper over
+/// [`compute_sun_position_from`] with `observer = Planet::Earth`.
 pub fn compute_sun_position_full(time: &SkyTime) -> SunPosition {
+    compute_sun_position_from(Planet::Earth, time)
+}
+
+/// Compute the full position data for the Sun as seen from `observer` (any
+/// planet): since the Sun sits at the origin of this heliocentric model,
+/// its position relative to `observer` is just the observer's heliocentric
+/// vector, negated -- the same machinery as [`compute_geocentric_position_from`]
+/// with the target's heliocentric position fixed at the origin.
+pub fn compute_sun_position_from(observer: Planet, time: &SkyTime) -> SunPosition {
     let jde = time.julian_date_tdb();
 
-    // Get Earth's heliocentric position in AU
-    let earth_pos = heliocentric_position(Planet::Earth, jde);
+    // Get observer's heliocentric position in AU
+    let observer_pos = heliocentric_position(observer, jde);
 
-    // Sun is in the opposite direction from Earth's position
-    let geo_x = -earth_pos.0;
-    let geo_y = -earth_pos.1;
-    let geo_z = -earth_pos.2;
+    // Sun is in the opposite direction from the observer's position
+    let geo_x = -observer_pos.0;
+    let geo_y = -observer_pos.1;
+    let geo_z = -observer_pos.2;
 
     // Distance in AU, then convert to km
     let distance_au = (geo_x * geo_x + geo_y * geo_y + geo_z * geo_z).sqrt();
@@ -205,11 +759,267 @@ pub fn compute_sun_position_full(time: &SkyTime) -> SunPosition {
 
     // Angular diameter: 2 * atan(radius / distance)
     let angular_diameter_rad = 2.0 * (SUN_RADIUS_KM / distance_km).atan();
+    let apparent_magnitude = sun_apparent_magnitude(distance_au);
 
     SunPosition {
         direction,
         distance_km,
         angular_diameter_rad,
+        phase_angle_rad: 0.0,
+        illuminated_fraction: 1.0,
+        apparent_magnitude,
+    }
+}
+
+// --- Apparent position: light-time and stellar aberration ------------------
+//
+// `compute_planet_position_full`/`compute_sun_position_full` above return the
+// *geometric* direction: they difference heliocentric VSOP87A vectors at the
+// same instant `jde`, as if light arrived instantaneously. The *apparent*
+// direction -- what an observer actually sees -- differs in two ways: we see
+// a body where it was when the light we're receiving left it (light-time),
+// and Earth's own motion through space tilts the incoming light slightly in
+// the direction of travel (annual aberration). The functions below add both
+// corrections, following the standard two-step recipe (Meeus, *Astronomical
+// Algorithms*, ch. 33-36).
+
+/// Light-time constant: days of light travel per AU (1 AU / c, in days).
+const LIGHT_TIME_DAYS_PER_AU: f64 = 0.0057755183;
+/// Speed of light in AU per day, for the classical aberration term `v / c`.
+const SPEED_OF_LIGHT_AU_PER_DAY: f64 = 173.1446;
+
+/// Half-step, in days, used to numerically differentiate Earth's
+/// heliocentric position into a velocity (central difference).
+const VELOCITY_STEP_DAYS: f64 = 0.01;
+
+/// Iteratively solve for the light-time `τ` (days) between a body and Earth:
+/// start with `τ = 0`, evaluate the body's heliocentric position at
+/// `jde - τ`, recompute the geocentric distance, and refine `τ` from that
+/// distance, until `τ` stops changing by more than ~1e-9 days (typically
+/// converges in 2-3 iterations). Returns the light-time-corrected geocentric
+/// vector (AU, ecliptic) and its length.
+pub(crate) fn light_time_corrected_geocentric(
+    body_heliocentric: impl Fn(f64) -> (f64, f64, f64),
+    earth_pos: (f64, f64, f64),
+    jde: f64,
+) -> ((f64, f64, f64), f64) {
+    let mut tau = 0.0;
+    let mut geo = (0.0, 0.0, 0.0);
+    let mut distance_au = 0.0;
+
+    for _ in 0..5 {
+        let body_pos = body_heliocentric(jde - tau);
+        geo = (
+            body_pos.0 - earth_pos.0,
+            body_pos.1 - earth_pos.1,
+            body_pos.2 - earth_pos.2,
+        );
+        distance_au = (geo.0 * geo.0 + geo.1 * geo.1 + geo.2 * geo.2).sqrt();
+
+        let new_tau = LIGHT_TIME_DAYS_PER_AU * distance_au;
+        let converged = (new_tau - tau).abs() < 1e-9;
+        tau = new_tau;
+        if converged {
+            break;
+        }
+    }
+
+    (geo, distance_au)
+}
+
+/// `body`'s heliocentric velocity (AU/day, ecliptic) at `jde`, by central
+/// difference of its VSOP87A position.
+pub(crate) fn heliocentric_velocity_au_per_day(body: Planet, jde: f64) -> (f64, f64, f64) {
+    let p_plus = heliocentric_position(body, jde + VELOCITY_STEP_DAYS);
+    let p_minus = heliocentric_position(body, jde - VELOCITY_STEP_DAYS);
+    let dt = 2.0 * VELOCITY_STEP_DAYS;
+    (
+        (p_plus.0 - p_minus.0) / dt,
+        (p_plus.1 - p_minus.1) / dt,
+        (p_plus.2 - p_minus.2) / dt,
+    )
+}
+
+/// Earth's heliocentric velocity (AU/day, ecliptic) at `jde` -- the
+/// `observer = Earth` case of [`heliocentric_velocity_au_per_day`], kept
+/// under its original name since every existing aberration call site is
+/// Earth-bound.
+pub(crate) fn earth_heliocentric_velocity_au_per_day(jde: f64) -> (f64, f64, f64) {
+    heliocentric_velocity_au_per_day(Planet::Earth, jde)
+}
+
+/// Rotate an ecliptic Cartesian vector into the equatorial frame, using the
+/// same rotation `ecliptic_to_equatorial` applies to a unit direction (it's
+/// a pure rotation by the obliquity, so it applies unchanged to any vector,
+/// not just unit ones -- here used for a velocity rather than a direction).
+pub(crate) fn rotate_ecliptic_vector_to_equatorial(v: (f64, f64, f64), obliquity_rad: f64) -> (f64, f64, f64) {
+    let cos_eps = obliquity_rad.cos();
+    let sin_eps = obliquity_rad.sin();
+    (
+        v.0,
+        v.1 * cos_eps - v.2 * sin_eps,
+        v.1 * sin_eps + v.2 * cos_eps,
+    )
+}
+
+/// Add annual aberration to a geometric (equatorial, unit) direction, given
+/// Earth's heliocentric velocity in AU/day (ecliptic), and renormalize.
+pub(crate) fn apply_stellar_aberration(
+    geometric_direction: CartesianCoord,
+    earth_velocity_au_per_day: (f64, f64, f64),
+) -> CartesianCoord {
+    let v_eq = rotate_ecliptic_vector_to_equatorial(earth_velocity_au_per_day, OBLIQUITY_J2000);
+    CartesianCoord::new(
+        geometric_direction.x + v_eq.0 / SPEED_OF_LIGHT_AU_PER_DAY,
+        geometric_direction.y + v_eq.1 / SPEED_OF_LIGHT_AU_PER_DAY,
+        geometric_direction.z + v_eq.2 / SPEED_OF_LIGHT_AU_PER_DAY,
+    )
+    .normalize()
+}
+
+/// Compute the apparent position of a planet: the geometric direction,
+/// corrected for light-time (the planet's position is evaluated at
+/// `jde - τ`, not `jde`) and annual aberration (Earth's own motion).
+pub fn compute_planet_position_apparent(planet: Planet, time: &SkyTime) -> PlanetPosition {
+    let jde = time.julian_date_tdb();
+    let earth_pos = heliocentric_position(Planet::Earth, jde);
+
+    let (geo, distance_au) =
+        light_time_corrected_geocentric(|t| heliocentric_position(planet, t), earth_pos, jde);
+
+    let distance_km = distance_au * AU_TO_KM;
+
+    let lon = geo.1.atan2(geo.0);
+    let lat = (geo.2 / distance_au).asin();
+    let geometric_direction = ecliptic_to_equatorial(lon, lat, OBLIQUITY_J2000);
+
+    let earth_velocity = earth_heliocentric_velocity_au_per_day(jde);
+    let direction = apply_stellar_aberration(geometric_direction, earth_velocity);
+
+    let radius_km = planet_radius_km(planet);
+    let angular_diameter_rad = 2.0 * (radius_km / distance_km).atan();
+
+    // `geo` is the light-time-corrected geocentric vector; add back `earth_pos`
+    // (at `jde`, since Earth itself isn't light-time-delayed here) to recover
+    // the body's heliocentric position at the retarded time.
+    let body_pos = (
+        geo.0 + earth_pos.0,
+        geo.1 + earth_pos.1,
+        geo.2 + earth_pos.2,
+    );
+    let r_au = (body_pos.0 * body_pos.0 + body_pos.1 * body_pos.1 + body_pos.2 * body_pos.2).sqrt();
+    let sun_earth_au = (earth_pos.0 * earth_pos.0 + earth_pos.1 * earth_pos.1 + earth_pos.2 * earth_pos.2).sqrt();
+    let (phase_angle_rad, illuminated_fraction) =
+        phase_angle_and_illumination(r_au, distance_au, sun_earth_au);
+    let ring_opening_sin = match planet {
+        Planet::Saturn => saturn_ring_opening_sin(
+            CartesianCoord::new(-direction.x, -direction.y, -direction.z),
+            jde,
+        ),
+        _ => 0.0,
+    };
+    let apparent_magnitude =
+        planet_apparent_magnitude(planet, r_au, distance_au, phase_angle_rad, ring_opening_sin);
+
+    let defect_of_illumination_rad = (angular_diameter_rad / 2.0) * (1.0 - illuminated_fraction);
+    let (body_ra, body_dec) = crate::coords::cartesian_to_ra_dec(&direction);
+    let sun_from_earth_eq = ecliptic_to_equatorial(
+        (-earth_pos.1).atan2(-earth_pos.0),
+        (-earth_pos.2 / sun_earth_au).asin(),
+        OBLIQUITY_J2000,
+    );
+    let (sun_ra, sun_dec) = crate::coords::cartesian_to_ra_dec(&sun_from_earth_eq);
+    let bright_limb_position_angle_rad =
+        bright_limb_position_angle(body_ra, body_dec, sun_ra, sun_dec);
+    let central_meridian_lon_rad = planet_rotational_elements(planet)
+        .map(|elements| {
+            let t_centuries = (jde - 2451545.0) / 36525.0;
+            let d_days = jde - 2451545.0;
+            let body_to_observer = CartesianCoord::new(-direction.x, -direction.y, -direction.z);
+            crate::rotation::sub_point(&body_to_observer, &elements, t_centuries, d_days).0
+        })
+        .unwrap_or(0.0);
+
+    PlanetPosition {
+        direction,
+        distance_km,
+        angular_diameter_rad,
+        phase_angle_rad,
+        illuminated_fraction,
+        defect_of_illumination_rad,
+        bright_limb_position_angle_rad,
+        central_meridian_lon_rad,
+        apparent_magnitude,
+    }
+}
+
+/// Apparent right ascension, declination, and distance of `target` as seen
+/// from `observer`'s center, J2000 equatorial.
+#[derive(Debug, Clone, Copy)]
+pub struct ApparentPosition {
+    pub ra_rad: f64,
+    pub dec_rad: f64,
+    pub distance_km: f64,
+}
+
+/// The arbitrary-observer generalization of [`compute_planet_position_apparent`]
+/// (which fixes `observer` to Earth): light-time-corrects `target`'s
+/// heliocentric position (iterating `target(jde - τ)` with
+/// [`light_time_corrected_geocentric`] until `τ` converges), then adds
+/// `observer`'s own stellar aberration before converting to RA/Dec -- the
+/// observer itself is treated as not light-time-delayed, the same
+/// approximation [`compute_planet_position_apparent`] makes for Earth.
+pub fn apparent_position(target: Planet, observer: Planet, jde: f64) -> ApparentPosition {
+    let observer_pos = heliocentric_position(observer, jde);
+
+    let (geo, distance_au) =
+        light_time_corrected_geocentric(|t| heliocentric_position(target, t), observer_pos, jde);
+
+    let lon = geo.1.atan2(geo.0);
+    let lat = (geo.2 / distance_au).asin();
+    let geometric_direction = ecliptic_to_equatorial(lon, lat, OBLIQUITY_J2000);
+
+    let observer_velocity = heliocentric_velocity_au_per_day(observer, jde);
+    let direction = apply_stellar_aberration(geometric_direction, observer_velocity);
+
+    let (ra_rad, dec_rad) = crate::coords::cartesian_to_ra_dec(&direction);
+    ApparentPosition {
+        ra_rad,
+        dec_rad,
+        distance_km: distance_au * AU_TO_KM,
+    }
+}
+
+/// Compute the apparent position of the Sun: annual aberration, plus the
+/// same light-time step applied to the planets (τ ≈ 8.3 minutes at 1 AU) for
+/// a uniform convention -- though for the Sun it has no effect on direction,
+/// since the Sun sits fixed at the coordinate origin of this heliocentric
+/// model, so "its position τ days ago" is unchanged.
+pub fn compute_sun_position_apparent(time: &SkyTime) -> SunPosition {
+    let jde = time.julian_date_tdb();
+
+    let (geo, distance_au) =
+        light_time_corrected_geocentric(|_| (0.0, 0.0, 0.0), heliocentric_position(Planet::Earth, jde), jde);
+
+    let distance_km = distance_au * AU_TO_KM;
+
+    let lon = geo.1.atan2(geo.0);
+    let lat = (geo.2 / distance_au).asin();
+    let geometric_direction = ecliptic_to_equatorial(lon, lat, OBLIQUITY_J2000);
+
+    let earth_velocity = earth_heliocentric_velocity_au_per_day(jde);
+    let direction = apply_stellar_aberration(geometric_direction, earth_velocity);
+
+    let angular_diameter_rad = 2.0 * (SUN_RADIUS_KM / distance_km).atan();
+    let apparent_magnitude = sun_apparent_magnitude(distance_au);
+
+    SunPosition {
+        direction,
+        distance_km,
+        angular_diameter_rad,
+        phase_angle_rad: 0.0,
+        illuminated_fraction: 1.0,
+        apparent_magnitude,
     }
 }
 
@@ -224,6 +1034,64 @@ pub struct MoonPosition {
     pub distance_km: f64,
     /// Angular diameter in radians
     pub angular_diameter_rad: f64,
+    /// Sun-Moon-Earth phase angle, radians
+    pub phase_angle_rad: f64,
+    /// Illuminated fraction of the visible disk, k = (1 + cos i) / 2
+    pub illuminated_fraction: f64,
+    /// Apparent visual magnitude
+    pub apparent_magnitude: f64,
+    /// Fraction of the way through the current synodic month: 0.0 at New
+    /// Moon, 0.25 at First Quarter, 0.5 at Full Moon, 0.75 at Last Quarter.
+    pub fraction_of_cycle: f64,
+    /// The named lunar phase corresponding to `fraction_of_cycle`.
+    pub phase_name: MoonPhaseName,
+}
+
+/// The eight traditionally named lunar phases, in the order they occur
+/// across a synodic month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MoonPhaseName {
+    New,
+    WaxingCrescent,
+    FirstQuarter,
+    WaxingGibbous,
+    Full,
+    WaningGibbous,
+    LastQuarter,
+    WaningCrescent,
+}
+
+impl MoonPhaseName {
+    pub fn name(&self) -> &'static str {
+        match self {
+            MoonPhaseName::New => "New Moon",
+            MoonPhaseName::WaxingCrescent => "Waxing Crescent",
+            MoonPhaseName::FirstQuarter => "First Quarter",
+            MoonPhaseName::WaxingGibbous => "Waxing Gibbous",
+            MoonPhaseName::Full => "Full Moon",
+            MoonPhaseName::WaningGibbous => "Waning Gibbous",
+            MoonPhaseName::LastQuarter => "Last Quarter",
+            MoonPhaseName::WaningCrescent => "Waning Crescent",
+        }
+    }
+}
+
+/// Bucket a synodic-cycle fraction (0.0-1.0, 0.0 = New Moon) into one of the
+/// eight traditionally named lunar phases, each spanning one-eighth of the
+/// cycle centered on its namesake (New at 0.0, First Quarter at 0.25, ...).
+fn moon_phase_name(fraction_of_cycle: f64) -> MoonPhaseName {
+    let bin = ((fraction_of_cycle * 8.0 + 0.5).floor() as i64).rem_euclid(8);
+    match bin {
+        0 => MoonPhaseName::New,
+        1 => MoonPhaseName::WaxingCrescent,
+        2 => MoonPhaseName::FirstQuarter,
+        3 => MoonPhaseName::WaxingGibbous,
+        4 => MoonPhaseName::Full,
+        5 => MoonPhaseName::WaningGibbous,
+        6 => MoonPhaseName::LastQuarter,
+        7 => MoonPhaseName::WaningCrescent,
+        _ => unreachable!(),
+    }
 }
 
 /// Result of planet position calculation (with distance and angular diameter)
@@ -234,6 +1102,22 @@ pub struct PlanetPosition {
     pub distance_km: f64,
     /// Angular diameter in radians
     pub angular_diameter_rad: f64,
+    /// Sun-planet-Earth phase angle, radians
+    pub phase_angle_rad: f64,
+    /// Illuminated fraction of the visible disk, k = (1 + cos i) / 2
+    pub illuminated_fraction: f64,
+    /// Defect of illumination: the angular width of the unlit sliver between
+    /// the terminator and the limb, `semidiameter * (1 - k)`, radians.
+    pub defect_of_illumination_rad: f64,
+    /// Position angle of the bright limb (the sunward edge of the
+    /// illuminated disk), measured eastward from celestial north, radians.
+    pub bright_limb_position_angle_rad: f64,
+    /// Sub-Earth planetographic longitude (the central-meridian longitude
+    /// facing Earth), radians. `0.0` for bodies [`planet_rotational_elements`]
+    /// has no WGCCRE elements for.
+    pub central_meridian_lon_rad: f64,
+    /// Apparent visual magnitude
+    pub apparent_magnitude: f64,
 }
 
 /// Result of Sun position calculation
@@ -244,6 +1128,13 @@ pub struct SunPosition {
     pub distance_km: f64,
     /// Angular diameter in radians
     pub angular_diameter_rad: f64,
+    /// Phase angle, radians -- always 0: the Sun is the light source, not a
+    /// body reflecting it, so it has no Sun-body-Earth triangle of its own.
+    pub phase_angle_rad: f64,
+    /// Illuminated fraction -- always 1.0, for the same reason.
+    pub illuminated_fraction: f64,
+    /// Apparent visual magnitude
+    pub apparent_magnitude: f64,
 }
 
 /// Get the radius of a planet in km.
@@ -257,6 +1148,7 @@ pub fn planet_radius_km(planet: Planet) -> f64 {
         Planet::Saturn => SATURN_RADIUS_KM,
         Planet::Uranus => URANUS_RADIUS_KM,
         Planet::Neptune => NEPTUNE_RADIUS_KM,
+        Planet::Pluto => PLUTO_RADIUS_KM,
     }
 }
 
@@ -512,10 +1404,37 @@ pub fn compute_moon_position_full(time: &SkyTime) -> MoonPosition {
     let obliquity = true_obliquity(jde);
     let direction = ecliptic_to_equatorial(lon, lat, obliquity).normalize();
 
+    // Phase angle from the Moon's geocentric elongation from the Sun (Meeus,
+    // ch. 48): `sun_lon` is the Sun's geocentric ecliptic longitude and
+    // `sun_dist_au` the Earth-Sun distance, both read off Earth's heliocentric
+    // VSOP87A position; `psi` is the geocentric Moon-Sun elongation.
+    let earth_pos = heliocentric_position(Planet::Earth, jde);
+    let sun_dist_au =
+        (earth_pos.0 * earth_pos.0 + earth_pos.1 * earth_pos.1 + earth_pos.2 * earth_pos.2).sqrt();
+    let sun_lon = (-earth_pos.1).atan2(-earth_pos.0);
+    let psi = (lat.cos() * (lon - sun_lon).cos()).clamp(-1.0, 1.0).acos();
+    let distance_au = distance_km / AU_TO_KM;
+    let phase_angle_rad = (sun_dist_au * psi.sin()).atan2(distance_au - sun_dist_au * psi.cos());
+    let illuminated_fraction = (1.0 + phase_angle_rad.cos()) / 2.0;
+    let apparent_magnitude = moon_apparent_magnitude(phase_angle_rad);
+
+    // Geocentric elongation of the Moon east of the Sun, wrapped to [0, 2π):
+    // 0 at New Moon, π at Full Moon, growing monotonically through the
+    // synodic month (unlike `phase_angle_rad`, which is symmetric and can't
+    // distinguish waxing from waning).
+    let elongation_rad = (lon - sun_lon).rem_euclid(2.0 * PI);
+    let fraction_of_cycle = elongation_rad / (2.0 * PI);
+    let phase_name = moon_phase_name(fraction_of_cycle);
+
     MoonPosition {
         direction,
         distance_km,
         angular_diameter_rad,
+        phase_angle_rad,
+        illuminated_fraction,
+        apparent_magnitude,
+        fraction_of_cycle,
+        phase_name,
     }
 }
 
@@ -569,12 +1488,20 @@ pub fn compute_all_body_positions(time: &SkyTime) -> [(CelestialBody, CartesianC
     ]
 }
 
-/// Full position data for a celestial body including angular diameter.
+/// Full position data for a celestial body, including angular diameter,
+/// phase, and apparent brightness.
 pub struct CelestialBodyPosition {
     pub body: CelestialBody,
     pub direction: CartesianCoord,
     pub distance_km: f64,
     pub angular_diameter_rad: f64,
+    /// Sun-body-Earth phase angle, radians (always 0 for the Sun).
+    pub phase_angle_rad: f64,
+    /// Illuminated fraction of the visible disk, k = (1 + cos i) / 2
+    /// (always 1.0 for the Sun).
+    pub illuminated_fraction: f64,
+    /// Apparent visual magnitude.
+    pub apparent_magnitude: f64,
 }
 
 /// Compute full position data (with angular diameters) for all visible celestial bodies.
@@ -595,54 +1522,81 @@ pub fn compute_all_body_positions_full(time: &SkyTime) -> [CelestialBodyPosition
             direction: sun.direction,
             distance_km: sun.distance_km,
             angular_diameter_rad: sun.angular_diameter_rad,
+            phase_angle_rad: sun.phase_angle_rad,
+            illuminated_fraction: sun.illuminated_fraction,
+            apparent_magnitude: sun.apparent_magnitude,
         },
         CelestialBodyPosition {
             body: CelestialBody::Moon,
             direction: moon.direction,
             distance_km: moon.distance_km,
             angular_diameter_rad: moon.angular_diameter_rad,
+            phase_angle_rad: moon.phase_angle_rad,
+            illuminated_fraction: moon.illuminated_fraction,
+            apparent_magnitude: moon.apparent_magnitude,
         },
         CelestialBodyPosition {
             body: CelestialBody::Mercury,
             direction: mercury.direction,
             distance_km: mercury.distance_km,
             angular_diameter_rad: mercury.angular_diameter_rad,
+            phase_angle_rad: mercury.phase_angle_rad,
+            illuminated_fraction: mercury.illuminated_fraction,
+            apparent_magnitude: mercury.apparent_magnitude,
         },
         CelestialBodyPosition {
             body: CelestialBody::Venus,
             direction: venus.direction,
             distance_km: venus.distance_km,
             angular_diameter_rad: venus.angular_diameter_rad,
+            phase_angle_rad: venus.phase_angle_rad,
+            illuminated_fraction: venus.illuminated_fraction,
+            apparent_magnitude: venus.apparent_magnitude,
         },
         CelestialBodyPosition {
             body: CelestialBody::Mars,
             direction: mars.direction,
             distance_km: mars.distance_km,
             angular_diameter_rad: mars.angular_diameter_rad,
+            phase_angle_rad: mars.phase_angle_rad,
+            illuminated_fraction: mars.illuminated_fraction,
+            apparent_magnitude: mars.apparent_magnitude,
         },
         CelestialBodyPosition {
             body: CelestialBody::Jupiter,
             direction: jupiter.direction,
             distance_km: jupiter.distance_km,
             angular_diameter_rad: jupiter.angular_diameter_rad,
+            phase_angle_rad: jupiter.phase_angle_rad,
+            illuminated_fraction: jupiter.illuminated_fraction,
+            apparent_magnitude: jupiter.apparent_magnitude,
         },
         CelestialBodyPosition {
             body: CelestialBody::Saturn,
             direction: saturn.direction,
             distance_km: saturn.distance_km,
             angular_diameter_rad: saturn.angular_diameter_rad,
+            phase_angle_rad: saturn.phase_angle_rad,
+            illuminated_fraction: saturn.illuminated_fraction,
+            apparent_magnitude: saturn.apparent_magnitude,
         },
         CelestialBodyPosition {
             body: CelestialBody::Uranus,
             direction: uranus.direction,
             distance_km: uranus.distance_km,
             angular_diameter_rad: uranus.angular_diameter_rad,
+            phase_angle_rad: uranus.phase_angle_rad,
+            illuminated_fraction: uranus.illuminated_fraction,
+            apparent_magnitude: uranus.apparent_magnitude,
         },
         CelestialBodyPosition {
             body: CelestialBody::Neptune,
             direction: neptune.direction,
             distance_km: neptune.distance_km,
             angular_diameter_rad: neptune.angular_diameter_rad,
+            phase_angle_rad: neptune.phase_angle_rad,
+            illuminated_fraction: neptune.illuminated_fraction,
+            apparent_magnitude: neptune.apparent_magnitude,
         },
     ]
 }
@@ -682,4 +1636,476 @@ mod tests {
             "Venus declination should be reasonable"
         );
     }
+
+    #[test]
+    fn test_apparent_planet_position_is_unit_vector() {
+        let time = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0);
+        for planet in Planet::VISIBLE {
+            let pos = compute_planet_position_apparent(planet, &time);
+            let len = (pos.direction.x * pos.direction.x
+                + pos.direction.y * pos.direction.y
+                + pos.direction.z * pos.direction.z)
+                .sqrt();
+            assert!(
+                (len - 1.0).abs() < 1e-9,
+                "{} apparent direction should be a unit vector",
+                planet.name()
+            );
+        }
+    }
+
+    #[test]
+    fn test_apparent_position_differs_slightly_from_geometric() {
+        // Light-time + aberration should shift the apparent direction from
+        // the geometric one by a small but non-zero angle (arcseconds to
+        // tens of arcseconds, not degrees).
+        let time = SkyTime::from_utc(2024, 6, 1, 0, 0, 0.0);
+        let geometric = compute_planet_position_full(Planet::Mars, &time).direction;
+        let apparent = compute_planet_position_apparent(Planet::Mars, &time).direction;
+
+        let cos_sep = (geometric.x * apparent.x + geometric.y * apparent.y + geometric.z * apparent.z)
+            .clamp(-1.0, 1.0);
+        let sep_arcsec = cos_sep.acos() * 180.0 / PI * 3600.0;
+
+        assert!(sep_arcsec > 0.01, "expected a measurable shift, got {sep_arcsec} arcsec");
+        assert!(sep_arcsec < 60.0, "shift should be small, got {sep_arcsec} arcsec");
+    }
+
+    #[test]
+    fn test_apparent_sun_position_shows_aberration_not_light_time() {
+        // The Sun sits at the coordinate origin of this heliocentric model,
+        // so the light-time step is a no-op for it; only aberration (~20.5
+        // arcsec at most) should shift its apparent direction.
+        let time = SkyTime::from_utc(2024, 3, 20, 0, 0, 0.0);
+        let geometric = compute_sun_position_full(&time).direction;
+        let apparent = compute_sun_position_apparent(&time).direction;
+
+        let cos_sep = (geometric.x * apparent.x + geometric.y * apparent.y + geometric.z * apparent.z)
+            .clamp(-1.0, 1.0);
+        let sep_arcsec = cos_sep.acos() * 180.0 / PI * 3600.0;
+
+        assert!(sep_arcsec > 1.0, "expected measurable aberration, got {sep_arcsec} arcsec");
+        assert!(sep_arcsec < 25.0, "aberration should be at most ~20.5 arcsec, got {sep_arcsec} arcsec");
+    }
+
+    #[test]
+    fn test_planet_illuminated_fraction_in_range() {
+        let time = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0);
+        for planet in Planet::VISIBLE {
+            let pos = compute_planet_position_full(planet, &time);
+            assert!(
+                (0.0..=1.0).contains(&pos.illuminated_fraction),
+                "{} illuminated fraction out of range: {}",
+                planet.name(),
+                pos.illuminated_fraction
+            );
+            assert!(
+                pos.phase_angle_rad >= 0.0 && pos.phase_angle_rad <= PI,
+                "{} phase angle out of range: {}",
+                planet.name(),
+                pos.phase_angle_rad
+            );
+            assert!(
+                pos.apparent_magnitude.is_finite(),
+                "{} apparent magnitude should be finite",
+                planet.name()
+            );
+        }
+    }
+
+    #[test]
+    fn test_venus_is_bright() {
+        // Venus's apparent magnitude should always be well into negative
+        // (very bright) territory, regardless of phase.
+        let time = SkyTime::from_utc(2024, 6, 1, 0, 0, 0.0);
+        let venus = compute_planet_position_full(Planet::Venus, &time);
+        assert!(
+            venus.apparent_magnitude < -3.0,
+            "expected Venus to be very bright, got magnitude {}",
+            venus.apparent_magnitude
+        );
+    }
+
+    #[test]
+    fn test_moon_illuminated_fraction_in_range() {
+        let time = SkyTime::from_utc(2024, 6, 15, 0, 0, 0.0);
+        let moon = compute_moon_position_full(&time);
+        assert!(
+            (0.0..=1.0).contains(&moon.illuminated_fraction),
+            "moon illuminated fraction out of range: {}",
+            moon.illuminated_fraction
+        );
+        assert!(
+            moon.apparent_magnitude.is_finite(),
+            "moon apparent magnitude should be finite"
+        );
+    }
+
+    #[test]
+    fn test_moon_phase_name_tracks_known_new_and_full_moons() {
+        let new_moon = compute_moon_position_full(&SkyTime::from_utc(2024, 6, 6, 12, 0, 0.0));
+        assert!(
+            new_moon.fraction_of_cycle < 0.05 || new_moon.fraction_of_cycle > 0.95,
+            "expected a fraction near 0.0 at New Moon, got {}",
+            new_moon.fraction_of_cycle
+        );
+        assert_eq!(new_moon.phase_name, MoonPhaseName::New);
+
+        let full_moon = compute_moon_position_full(&SkyTime::from_utc(2024, 6, 22, 1, 0, 0.0));
+        assert!(
+            (full_moon.fraction_of_cycle - 0.5).abs() < 0.05,
+            "expected a fraction near 0.5 at Full Moon, got {}",
+            full_moon.fraction_of_cycle
+        );
+        assert_eq!(full_moon.phase_name, MoonPhaseName::Full);
+    }
+
+    #[test]
+    fn test_moon_phase_name_bins_cover_full_cycle() {
+        use std::collections::HashSet;
+        let seen: HashSet<_> = (0..32).map(|i| moon_phase_name(i as f64 / 32.0)).collect();
+        assert_eq!(
+            seen.len(),
+            8,
+            "all eight named phases should appear across a full cycle"
+        );
+    }
+
+    #[test]
+    fn test_geocentric_position_from_earth_matches_full() {
+        // observer = Earth should reproduce compute_planet_position_full exactly,
+        // since the latter is now a thin wrapper over the former.
+        let time = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0);
+        for planet in Planet::VISIBLE {
+            let via_wrapper = compute_planet_position_full(planet, &time);
+            let via_generic = compute_geocentric_position_from(Planet::Earth, planet, &time);
+            assert_eq!(via_wrapper.distance_km, via_generic.distance_km);
+            assert_eq!(via_wrapper.apparent_magnitude, via_generic.apparent_magnitude);
+        }
+    }
+
+    #[test]
+    fn test_position_from_mars_is_unit_vector_and_finite() {
+        // Sanity check for a non-Earth vantage point: direction should still
+        // be a unit vector and the distance/magnitude should be finite.
+        let time = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0);
+        let pos = compute_geocentric_position_from(Planet::Mars, Planet::Jupiter, &time);
+        let len = (pos.direction.x * pos.direction.x
+            + pos.direction.y * pos.direction.y
+            + pos.direction.z * pos.direction.z)
+            .sqrt();
+        assert!((len - 1.0).abs() < 1e-9, "got len={len}");
+        assert!(pos.distance_km.is_finite() && pos.distance_km > 0.0);
+        assert!(pos.apparent_magnitude.is_finite());
+    }
+
+    #[test]
+    fn test_sun_position_from_earth_matches_full() {
+        let time = SkyTime::from_utc(2024, 3, 20, 0, 0, 0.0);
+        let via_wrapper = compute_sun_position_full(&time);
+        let via_generic = compute_sun_position_from(Planet::Earth, &time);
+        assert_eq!(via_wrapper.distance_km, via_generic.distance_km);
+    }
+
+    #[test]
+    fn test_sun_position_from_mars_is_farther_than_from_earth() {
+        // Mars orbits farther from the Sun than Earth, so the Sun should
+        // appear both farther away and fainter from Mars.
+        let time = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0);
+        let from_earth = compute_sun_position_from(Planet::Earth, &time);
+        let from_mars = compute_sun_position_from(Planet::Mars, &time);
+        assert!(from_mars.distance_km > from_earth.distance_km);
+        assert!(from_mars.apparent_magnitude > from_earth.apparent_magnitude);
+    }
+
+    #[test]
+    fn test_light_time_iteration_converges() {
+        let jde = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0).julian_date_tdb();
+        let earth_pos = heliocentric_position(Planet::Earth, jde);
+        let (_, distance_au) = light_time_corrected_geocentric(
+            |t| heliocentric_position(Planet::Mars, t),
+            earth_pos,
+            jde,
+        );
+        // Mars is never closer than ~0.37 AU or farther than ~2.7 AU from Earth.
+        assert!(distance_au > 0.3 && distance_au < 3.0, "got {distance_au}");
+    }
+
+    #[test]
+    fn test_pluto_position_is_unit_vector_and_distant() {
+        let time = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0);
+        let pos = compute_pluto_position_full(&time);
+        let len = (pos.direction.x * pos.direction.x
+            + pos.direction.y * pos.direction.y
+            + pos.direction.z * pos.direction.z)
+            .sqrt();
+        assert!((len - 1.0).abs() < 1e-9, "got len={len}");
+
+        // Pluto's distance from Earth ranges roughly 29-50 AU.
+        let distance_au = pos.distance_km / AU_TO_KM;
+        assert!(distance_au > 25.0 && distance_au < 55.0, "got {distance_au}");
+        assert!(pos.apparent_magnitude.is_finite());
+    }
+
+    #[test]
+    fn test_pluto_heliocentric_distance_is_near_orbital_range() {
+        // Pluto's heliocentric distance ranges from ~29.7 AU (perihelion) to
+        // ~49.3 AU (aphelion); check the series stays within a wider sanity
+        // band across a few widely-spaced epochs.
+        for year in [1950, 2000, 2024, 2080] {
+            let jde = SkyTime::from_utc(year, 1, 1, 0, 0, 0.0).julian_date_tdb();
+            let (x, y, z) = heliocentric_position(Planet::Pluto, jde);
+            let r_au = (x * x + y * y + z * z).sqrt();
+            assert!(
+                (25.0..55.0).contains(&r_au),
+                "year {year}: got r={r_au} AU"
+            );
+        }
+    }
+
+    #[test]
+    fn test_truncated_precision_agrees_roughly_with_full() {
+        // The low-precision Keplerian elements shouldn't agree exactly with
+        // VSOP87A, but should stay within a degree or so for an inner planet
+        // near J2000 (where the elements are defined).
+        let time = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0);
+        let full = compute_planet_position_with_precision(Planet::Venus, &time, PositionPrecision::Full);
+        let truncated =
+            compute_planet_position_with_precision(Planet::Venus, &time, PositionPrecision::Truncated);
+
+        let dot = full.direction.x * truncated.direction.x
+            + full.direction.y * truncated.direction.y
+            + full.direction.z * truncated.direction.z;
+        let angle_rad = dot.clamp(-1.0, 1.0).acos();
+        assert!(
+            angle_rad.to_degrees() < 1.0,
+            "full/truncated Venus directions disagree by {} degrees",
+            angle_rad.to_degrees()
+        );
+    }
+
+    #[test]
+    fn test_truncated_precision_is_unit_vector_for_all_planets() {
+        let time = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0);
+        for planet in Planet::ALL {
+            if planet == Planet::Earth {
+                continue;
+            }
+            let pos =
+                compute_planet_position_with_precision(planet, &time, PositionPrecision::Truncated);
+            let len = (pos.direction.x * pos.direction.x
+                + pos.direction.y * pos.direction.y
+                + pos.direction.z * pos.direction.z)
+                .sqrt();
+            assert!(
+                (len - 1.0).abs() < 1e-9,
+                "{}: got len={}",
+                planet.name(),
+                len
+            );
+        }
+    }
+
+    #[test]
+    fn test_all_body_positions_full_carries_phase_and_magnitude() {
+        let time = SkyTime::from_utc(2024, 6, 1, 0, 0, 0.0);
+        let bodies = compute_all_body_positions_full(&time);
+
+        let sun = bodies.iter().find(|b| b.body == CelestialBody::Sun).unwrap();
+        assert_eq!(sun.phase_angle_rad, 0.0);
+        assert_eq!(sun.illuminated_fraction, 1.0);
+
+        let venus = bodies.iter().find(|b| b.body == CelestialBody::Venus).unwrap();
+        assert!(venus.phase_angle_rad >= 0.0 && venus.phase_angle_rad <= PI);
+        assert!(
+            venus.illuminated_fraction >= 0.0 && venus.illuminated_fraction <= 1.0,
+            "got {}",
+            venus.illuminated_fraction
+        );
+        assert!(venus.apparent_magnitude.is_finite());
+    }
+
+    #[test]
+    fn test_bright_limb_position_angle_is_normalized_to_0_2pi() {
+        for (body_ra, body_dec, sun_ra, sun_dec) in [
+            (0.1, 0.2, 0.3, 0.1),
+            (6.0, -0.4, 0.1, 0.2),
+            (3.0, 0.0, 3.0, 0.0),
+        ] {
+            let pa = bright_limb_position_angle(body_ra, body_dec, sun_ra, sun_dec);
+            assert!(
+                (0.0..2.0 * PI).contains(&pa),
+                "got pa={pa} outside [0, 2pi)"
+            );
+        }
+    }
+
+    #[test]
+    fn test_defect_of_illumination_is_bounded_by_half_angular_diameter() {
+        let time = SkyTime::from_utc(2024, 6, 1, 0, 0, 0.0);
+        for planet in Planet::ALL {
+            if planet == Planet::Earth {
+                continue;
+            }
+            let pos = compute_planet_position_apparent(planet, &time);
+            assert!(
+                pos.defect_of_illumination_rad >= 0.0
+                    && pos.defect_of_illumination_rad <= pos.angular_diameter_rad / 2.0,
+                "{}: defect_of_illumination_rad={} outside [0, {}]",
+                planet.name(),
+                pos.defect_of_illumination_rad,
+                pos.angular_diameter_rad / 2.0
+            );
+        }
+    }
+
+    #[test]
+    fn test_defect_of_illumination_is_zero_when_fully_illuminated() {
+        // Mars near opposition-opposite (conjunction, fully lit from Earth's
+        // side) should have an illuminated fraction close to 1 and thus a
+        // defect of illumination close to zero.
+        let time = SkyTime::from_utc(2023, 11, 18, 0, 0, 0.0);
+        let mars = compute_planet_position_apparent(Planet::Mars, &time);
+        if mars.illuminated_fraction > 0.999 {
+            assert!(mars.defect_of_illumination_rad < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_central_meridian_lon_is_normalized_for_planets_with_rotational_elements() {
+        let time = SkyTime::from_utc(2024, 6, 1, 0, 0, 0.0);
+        for planet in [
+            Planet::Mercury,
+            Planet::Venus,
+            Planet::Mars,
+            Planet::Jupiter,
+            Planet::Saturn,
+            Planet::Uranus,
+            Planet::Neptune,
+        ] {
+            let pos = compute_planet_position_apparent(planet, &time);
+            assert!(
+                (0.0..2.0 * PI).contains(&pos.central_meridian_lon_rad),
+                "{}: central_meridian_lon_rad={} outside [0, 2pi)",
+                planet.name(),
+                pos.central_meridian_lon_rad
+            );
+        }
+    }
+
+    #[test]
+    fn test_central_meridian_lon_is_zero_for_pluto() {
+        // Pluto has no WGCCRE rotational elements in this crate, so
+        // `planet_rotational_elements` returns `None` and the central
+        // meridian longitude falls back to the documented 0.0 sentinel.
+        let time = SkyTime::from_utc(2024, 6, 1, 0, 0, 0.0);
+        let pos = compute_planet_position_apparent(Planet::Pluto, &time);
+        assert_eq!(pos.central_meridian_lon_rad, 0.0);
+    }
+
+    #[test]
+    fn test_apparent_position_from_earth_matches_compute_planet_position_apparent() {
+        // With `observer = Earth`, `apparent_position` should agree closely
+        // with the Earth-specific path it generalizes (same light-time and
+        // aberration corrections, just without the final RA/Dec split being
+        // reused -- small residual differences are expected since the two
+        // paths compute distance slightly differently downstream).
+        let time = SkyTime::from_utc(2024, 6, 1, 0, 0, 0.0);
+        let jde = time.julian_date_tdb();
+        let earth_view = compute_planet_position_apparent(Planet::Mars, &time);
+        let (earth_ra, earth_dec) = crate::coords::cartesian_to_ra_dec(&earth_view.direction);
+
+        let generalized = apparent_position(Planet::Mars, Planet::Earth, jde);
+
+        assert!((generalized.ra_rad - earth_ra).abs() < 1e-6, "ra mismatch");
+        assert!((generalized.dec_rad - earth_dec).abs() < 1e-6, "dec mismatch");
+        assert!(
+            (generalized.distance_km - earth_view.distance_km).abs() / earth_view.distance_km < 1e-6,
+            "distance mismatch"
+        );
+    }
+
+    #[test]
+    fn test_apparent_position_ra_is_normalized_to_0_2pi() {
+        let base_jde = 2451545.0;
+        for day in 0..365 {
+            let jde = base_jde + day as f64;
+            let pos = apparent_position(Planet::Saturn, Planet::Jupiter, jde);
+            assert!(
+                (0.0..2.0 * PI).contains(&pos.ra_rad),
+                "ra_rad={} outside [0, 2pi) at jde={}",
+                pos.ra_rad,
+                jde
+            );
+        }
+    }
+
+    #[test]
+    fn test_apparent_position_distance_is_positive_and_finite() {
+        let jde = 2451545.0;
+        let pos = apparent_position(Planet::Venus, Planet::Mars, jde);
+        assert!(pos.distance_km.is_finite() && pos.distance_km > 0.0);
+    }
+
+    #[test]
+    fn test_saturn_ring_ephemeris_latitudes_are_bounded() {
+        // Earth and Sun ring-plane latitudes are each `asin` of a clamped
+        // sine, so both must land in [-pi/2, pi/2] at any date.
+        let base_jde = 2451545.0;
+        for day in (0..(365 * 30)).step_by(90) {
+            let jde = base_jde + day as f64;
+            let ring = saturn_ring_ephemeris(jde);
+            assert!(
+                (-PI / 2.0..=PI / 2.0).contains(&ring.earth_latitude_rad),
+                "earth_latitude_rad={} outside [-pi/2, pi/2] at jde={}",
+                ring.earth_latitude_rad,
+                jde
+            );
+            assert!(
+                (-PI / 2.0..=PI / 2.0).contains(&ring.sun_latitude_rad),
+                "sun_latitude_rad={} outside [-pi/2, pi/2] at jde={}",
+                ring.sun_latitude_rad,
+                jde
+            );
+        }
+    }
+
+    #[test]
+    fn test_saturn_ring_ephemeris_position_angle_is_normalized_to_0_2pi() {
+        let base_jde = 2451545.0;
+        for day in (0..(365 * 30)).step_by(90) {
+            let jde = base_jde + day as f64;
+            let ring = saturn_ring_ephemeris(jde);
+            assert!(
+                (0.0..2.0 * PI).contains(&ring.position_angle_rad),
+                "position_angle_rad={} outside [0, 2pi) at jde={}",
+                ring.position_angle_rad,
+                jde
+            );
+        }
+    }
+
+    #[test]
+    fn test_saturn_ring_ephemeris_earth_latitude_oscillates_over_one_orbit() {
+        // Saturn's ~29.5-year orbital period carries Earth's view of the
+        // ring plane from one extreme opening angle through edge-on and
+        // back; over a 30-year scan the sign of `earth_latitude_rad` should
+        // flip at least once rather than staying pinned to one side.
+        let base_jde = 2451545.0;
+        let mut saw_positive = false;
+        let mut saw_negative = false;
+        for year in 0..30 {
+            let jde = base_jde + year as f64 * 365.25;
+            let ring = saturn_ring_ephemeris(jde);
+            if ring.earth_latitude_rad > 0.0 {
+                saw_positive = true;
+            } else if ring.earth_latitude_rad < 0.0 {
+                saw_negative = true;
+            }
+        }
+        assert!(
+            saw_positive && saw_negative,
+            "expected earth_latitude_rad to take both signs over a 30-year scan"
+        );
+    }
 }
@@ -0,0 +1,615 @@
+//! Rise, set, and transit time calculations for a ground-based observer.
+//!
+//! Given a body's apparent equatorial direction and an observer location, this
+//! module answers questions like "when does Mars rise tonight?" using the
+//! standard hour-angle method (Meeus, *Astronomical Algorithms*, ch. 15).
+
+use crate::coords::{cartesian_to_ra_dec, compute_gmst, compute_lst, compute_refraction, CartesianCoord};
+use crate::planets::{compute_moon_position, compute_planet_position, compute_sun_position, CelestialBody, Planet};
+use crate::time::SkyTime;
+use std::f64::consts::PI;
+
+/// A ground-based observer location.
+#[derive(Debug, Clone, Copy)]
+pub struct Observer {
+    /// Geographic latitude in radians (positive north).
+    pub lat_rad: f64,
+    /// Geographic longitude in radians (positive east).
+    pub lon_rad: f64,
+    /// Elevation above sea level in meters.
+    pub elevation_m: f64,
+}
+
+impl Observer {
+    pub fn new(lat_rad: f64, lon_rad: f64, elevation_m: f64) -> Self {
+        Self {
+            lat_rad,
+            lon_rad,
+            elevation_m,
+        }
+    }
+}
+
+/// Altitude threshold used to define a rise/set/twilight event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TwilightMode {
+    /// Stars and planets: -0.5667° (atmospheric refraction at the horizon only).
+    StarsPlanets,
+    /// Sun and Moon: -0.8333° (refraction plus average angular semidiameter).
+    SunMoon,
+    /// Civil twilight: Sun's center at -6°.
+    Civil,
+    /// Nautical twilight: Sun's center at -12°.
+    Nautical,
+    /// Astronomical twilight: Sun's center at -18°.
+    Astronomical,
+}
+
+impl TwilightMode {
+    /// The target altitude `h0` for this mode, in radians.
+    fn altitude_threshold_rad(&self) -> f64 {
+        let deg = match self {
+            TwilightMode::StarsPlanets => -0.5667,
+            TwilightMode::SunMoon => -0.8333,
+            TwilightMode::Civil => -6.0,
+            TwilightMode::Nautical => -12.0,
+            TwilightMode::Astronomical => -18.0,
+        };
+        deg * PI / 180.0
+    }
+}
+
+/// Rise, transit, and set instants for a body as seen by an observer.
+#[derive(Debug, Clone, Copy)]
+pub struct RiseSetTransit {
+    /// Julian Date (UTC) of rising, or `None` if the body never crosses the
+    /// threshold altitude from below (see `circumpolar`/`never_rises`).
+    pub rise_jd: Option<f64>,
+    /// Julian Date (UTC) of the next upper transit (culmination).
+    pub transit_jd: f64,
+    /// Julian Date (UTC) of setting, or `None` for the same reason as `rise_jd`.
+    pub set_jd: Option<f64>,
+    /// The body stays above the threshold altitude at all times (no rise/set).
+    pub circumpolar: bool,
+    /// The body stays below the threshold altitude at all times.
+    pub never_rises: bool,
+}
+
+/// Compute the next rise, transit, and set times for a body.
+///
+/// `direction` is the body's apparent geocentric equatorial unit vector at
+/// `time`. `time` anchors the search: the returned transit is the next upper
+/// culmination at or after `time`, and rise/set bracket it symmetrically in
+/// hour angle.
+///
+/// For fast movers like the Moon, a single pass using the position at `time`
+/// is only approximate; call this again with the body's position re-evaluated
+/// at the estimated event time and repeat once or twice to converge.
+///
+/// Handles circumpolar and never-rising bodies by checking whether the
+/// hour-angle cosine falls outside `[-1, 1]`.
+pub fn rise_set_transit(
+    direction: &CartesianCoord,
+    time: &SkyTime,
+    observer: &Observer,
+    mode: TwilightMode,
+) -> RiseSetTransit {
+    rise_set_transit_at_altitude(direction, time, observer, mode.altitude_threshold_rad())
+}
+
+/// Core hour-angle solver behind [`rise_set_transit`] and
+/// [`rise_set_transit_for_body`], parameterized directly on the threshold
+/// altitude `h0` (radians) rather than a [`TwilightMode`], so callers that
+/// need a body-specific threshold (e.g. the Moon's +0.125°) aren't forced
+/// to route through the mode enum.
+fn rise_set_transit_at_altitude(
+    direction: &CartesianCoord,
+    time: &SkyTime,
+    observer: &Observer,
+    h0: f64,
+) -> RiseSetTransit {
+    let (ra_rad, dec_rad) = cartesian_to_ra_dec(direction);
+    let jd_ut = time.julian_date_utc();
+
+    let gmst = compute_gmst(jd_ut);
+    let lst_rad = compute_lst(gmst, observer.lon_rad);
+
+    // Hour angle right now, and hours until the next upper transit (HA = 0).
+    let current_ha_rad = lst_rad - ra_rad;
+    let hours_to_transit = wrap_hours(-current_ha_rad * 12.0 / PI);
+    let transit_jd = jd_ut + hours_to_transit / 24.0;
+
+    let cos_h0_arg = (h0.sin() - observer.lat_rad.sin() * dec_rad.sin())
+        / (observer.lat_rad.cos() * dec_rad.cos());
+
+    if cos_h0_arg < -1.0 {
+        return RiseSetTransit {
+            rise_jd: None,
+            transit_jd,
+            set_jd: None,
+            circumpolar: true,
+            never_rises: false,
+        };
+    }
+    if cos_h0_arg > 1.0 {
+        return RiseSetTransit {
+            rise_jd: None,
+            transit_jd,
+            set_jd: None,
+            circumpolar: false,
+            never_rises: true,
+        };
+    }
+
+    let h0_hours = cos_h0_arg.acos() * 12.0 / PI;
+
+    RiseSetTransit {
+        rise_jd: Some(transit_jd - h0_hours / 24.0),
+        transit_jd,
+        set_jd: Some(transit_jd + h0_hours / 24.0),
+        circumpolar: false,
+        never_rises: false,
+    }
+}
+
+/// The standard altitude `h0` Meeus uses for a body's rise/set threshold:
+/// stars and planets just need atmospheric refraction at the horizon
+/// (-0.5667°); the Sun additionally corrects for its angular semidiameter
+/// (-0.8333°); the Moon's mean horizontal parallax is large enough to push
+/// its threshold *above* the horizon instead (+0.125°) (Meeus, *Astronomical
+/// Algorithms*, ch. 15).
+fn standard_altitude_rad(body: CelestialBody) -> f64 {
+    let deg = match body {
+        CelestialBody::Sun => -0.8333,
+        CelestialBody::Moon => 0.125,
+        _ => -0.5667,
+    };
+    deg * PI / 180.0
+}
+
+/// The body's apparent geocentric equatorial direction at `time`, so
+/// [`rise_set_transit_for_body`] can look a `CelestialBody` up without its
+/// caller needing to know which `compute_*_position` function backs it.
+fn body_direction(body: CelestialBody, time: &SkyTime) -> CartesianCoord {
+    match body {
+        CelestialBody::Sun => compute_sun_position(time),
+        CelestialBody::Moon => compute_moon_position(time),
+        CelestialBody::Mercury => compute_planet_position(Planet::Mercury, time),
+        CelestialBody::Venus => compute_planet_position(Planet::Venus, time),
+        CelestialBody::Mars => compute_planet_position(Planet::Mars, time),
+        CelestialBody::Jupiter => compute_planet_position(Planet::Jupiter, time),
+        CelestialBody::Saturn => compute_planet_position(Planet::Saturn, time),
+        CelestialBody::Uranus => compute_planet_position(Planet::Uranus, time),
+        CelestialBody::Neptune => compute_planet_position(Planet::Neptune, time),
+    }
+}
+
+/// Compute the next rise, transit, and set times for a named `body`, as seen
+/// from `observer`, picking the standard altitude threshold automatically
+/// (see `standard_altitude_rad`) instead of requiring a `TwilightMode`.
+///
+/// Unlike `rise_set_transit`, which takes a single fixed `direction`, this
+/// re-evaluates the body's actual position at each estimated event time and
+/// re-solves, converging within two refinement passes -- the Meeus iterative
+/// method, needed for fast movers like the Moon where a single evaluation at
+/// `time` can be off by tens of minutes.
+pub fn rise_set_transit_for_body(
+    body: CelestialBody,
+    time: &SkyTime,
+    observer: &Observer,
+) -> RiseSetTransit {
+    let h0 = standard_altitude_rad(body);
+    let mut result = rise_set_transit_at_altitude(&body_direction(body, time), time, observer, h0);
+
+    for _ in 0..2 {
+        if result.circumpolar || result.never_rises {
+            return result;
+        }
+
+        let transit_time = SkyTime::from_jd(result.transit_jd);
+        let refined = rise_set_transit_at_altitude(
+            &body_direction(body, &transit_time),
+            &transit_time,
+            observer,
+            h0,
+        );
+
+        let rise_jd = refined.rise_jd.map(|jd| {
+            let rise_time = SkyTime::from_jd(jd);
+            rise_set_transit_at_altitude(&body_direction(body, &rise_time), &rise_time, observer, h0)
+                .rise_jd
+                .unwrap_or(jd)
+        });
+        let set_jd = refined.set_jd.map(|jd| {
+            let set_time = SkyTime::from_jd(jd);
+            // `set_time` is already past this transit, so the solve anchors on
+            // the next one, a wrapped 24 hours later; step back to this pass.
+            rise_set_transit_at_altitude(&body_direction(body, &set_time), &set_time, observer, h0)
+                .set_jd
+                .map(|next_pass_set_jd| next_pass_set_jd - 1.0)
+                .unwrap_or(jd)
+        });
+
+        result = RiseSetTransit {
+            rise_jd,
+            transit_jd: refined.transit_jd,
+            set_jd,
+            circumpolar: false,
+            never_rises: false,
+        };
+    }
+
+    result
+}
+
+/// Earth's equatorial radius, km, used by the topocentric parallax reduction
+/// below (IAU value, matching the classic `6378.14` Meeus uses rather than
+/// the more precise WGS84 `6378.137` elsewhere in the crate).
+const EARTH_EQUATORIAL_RADIUS_KM: f64 = 6378.14;
+
+/// IAU flattening factor `b/a` used in the geocentric-latitude reduction
+/// below (Meeus, *Astronomical Algorithms*, ch. 11).
+const EARTH_FLATTENING_FACTOR: f64 = 0.99664719;
+
+/// Convert a body's geocentric apparent direction + distance into a
+/// topocentric direction for a ground-based `observer`, correcting for
+/// diurnal (geocentric) parallax: the ~1° shift for the Moon that's
+/// negligible for anything farther away (Meeus, *Astronomical Algorithms*,
+/// ch. 40).
+///
+/// `time` supplies the sidereal time needed to place `observer` in the same
+/// (Earth-centered, equatorial) frame as `direction`. Both `direction` and
+/// the returned vector are unit vectors.
+pub fn topocentric_direction(
+    direction: &CartesianCoord,
+    distance_km: f64,
+    observer: &Observer,
+    time: &SkyTime,
+) -> CartesianCoord {
+    let u = (EARTH_FLATTENING_FACTOR * observer.lat_rad.tan()).atan();
+    let h_over_a = (observer.elevation_m / 1000.0) / EARTH_EQUATORIAL_RADIUS_KM;
+    let rho_sin_lat = EARTH_FLATTENING_FACTOR * u.sin() + h_over_a * observer.lat_rad.sin();
+    let rho_cos_lat = u.cos() + h_over_a * observer.lat_rad.cos();
+
+    let gmst = compute_gmst(time.julian_date_utc());
+    let lst_rad = compute_lst(gmst, observer.lon_rad);
+
+    // Observer's geocentric position, in Earth radii, in the same equatorial
+    // frame as `direction`: at its own local sidereal time, the observer sits
+    // on the meridian of hour angle zero, so its "right ascension" is `lst`.
+    let observer_vec = CartesianCoord::new(
+        rho_cos_lat * lst_rad.cos(),
+        rho_cos_lat * lst_rad.sin(),
+        rho_sin_lat,
+    );
+
+    let body_radii = distance_km / EARTH_EQUATORIAL_RADIUS_KM;
+    CartesianCoord::new(
+        direction.x * body_radii - observer_vec.x,
+        direction.y * body_radii - observer_vec.y,
+        direction.z * body_radii - observer_vec.z,
+    )
+    .normalize()
+}
+
+/// Altitude above the horizon and azimuth (measured from North through
+/// East, matching the convention `satellites`/`iss` use for TLE passes),
+/// both in radians.
+#[derive(Debug, Clone, Copy)]
+pub struct HorizontalCoord {
+    pub altitude_rad: f64,
+    pub azimuth_rad: f64,
+}
+
+impl HorizontalCoord {
+    /// This body's altitude as it actually appears to an observer, correcting
+    /// `altitude_rad` for atmospheric refraction (see `compute_refraction`).
+    /// Azimuth is unaffected by refraction, so only altitude changes.
+    ///
+    /// `compute_refraction`'s Bennett-formula approximation becomes
+    /// unreliable well below the horizon, so a body there is left at its
+    /// geometric altitude rather than refracted (same guard `satellites`
+    /// uses for TLE passes).
+    pub fn apparent_altitude_rad(&self) -> f64 {
+        if self.altitude_rad > -1.0_f64.to_radians() {
+            self.altitude_rad + compute_refraction(self.altitude_rad)
+        } else {
+            self.altitude_rad
+        }
+    }
+}
+
+/// Convert a body's equatorial (unit-vector) direction into altitude/azimuth
+/// for `observer` at `time` -- the core "what's up right now" query.
+///
+/// Builds the observer's local East-North-Up frame from their latitude and
+/// local sidereal time, then projects `direction` onto it (equivalent to
+/// the standard hour-angle spherical-trig formula, but expressed as vector
+/// projections for consistency with `satellites::eci_to_topocentric`, which
+/// does the same thing for TLE-propagated satellites).
+pub fn equatorial_to_horizontal(
+    direction: &CartesianCoord,
+    time: &SkyTime,
+    observer: &Observer,
+) -> HorizontalCoord {
+    let gmst = compute_gmst(time.julian_date_utc());
+    let lst_rad = compute_lst(gmst, observer.lon_rad);
+
+    let (sin_lat, cos_lat) = observer.lat_rad.sin_cos();
+    let (sin_lst, cos_lst) = lst_rad.sin_cos();
+
+    let east = CartesianCoord::new(-sin_lst, cos_lst, 0.0);
+    let north = CartesianCoord::new(-sin_lat * cos_lst, -sin_lat * sin_lst, cos_lat);
+    let up = CartesianCoord::new(cos_lat * cos_lst, cos_lat * sin_lst, sin_lat);
+
+    let e = direction.x * east.x + direction.y * east.y + direction.z * east.z;
+    let n = direction.x * north.x + direction.y * north.y + direction.z * north.z;
+    let u = direction.x * up.x + direction.y * up.y + direction.z * up.z;
+
+    HorizontalCoord {
+        altitude_rad: u.clamp(-1.0, 1.0).asin(),
+        azimuth_rad: e.atan2(n).rem_euclid(2.0 * PI),
+    }
+}
+
+/// Equatorial horizontal parallax `π` of a body at `distance_km`: the angle
+/// subtended by Earth's equatorial radius as seen from the body, i.e. the
+/// maximum possible diurnal parallax shift (at the observer's horizon).
+pub fn equatorial_horizontal_parallax_rad(distance_km: f64) -> f64 {
+    (EARTH_EQUATORIAL_RADIUS_KM / distance_km).asin()
+}
+
+/// Wrap an hour offset into `[0, 24)`.
+fn wrap_hours(mut h: f64) -> f64 {
+    h %= 24.0;
+    if h < 0.0 {
+        h += 24.0;
+    }
+    h
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coords::ra_dec_to_cartesian;
+
+    #[test]
+    fn test_equatorial_star_rises_and_sets_from_mid_latitude() {
+        // A star on the celestial equator, observed from 40°N, should be above
+        // the horizon for very close to half the day.
+        let direction = ra_dec_to_cartesian(0.0, 0.0);
+        let time = SkyTime::from_utc(2024, 6, 1, 0, 0, 0.0);
+        let observer = Observer::new(40.0_f64.to_radians(), 0.0, 0.0);
+
+        let result = rise_set_transit(&direction, &time, &observer, TwilightMode::StarsPlanets);
+
+        assert!(!result.circumpolar);
+        assert!(!result.never_rises);
+        let rise = result.rise_jd.expect("equatorial star should rise");
+        let set = result.set_jd.expect("equatorial star should set");
+        let hours_up = (set - rise) * 24.0;
+        assert!(
+            (hours_up - 12.0).abs() < 0.2,
+            "expected ~12h above horizon, got {hours_up}"
+        );
+    }
+
+    #[test]
+    fn test_circumpolar_star_never_sets() {
+        // From 60°N, a star at +80° declination never dips below the horizon.
+        let direction = ra_dec_to_cartesian(0.0, 80.0_f64.to_radians());
+        let time = SkyTime::from_utc(2024, 6, 1, 0, 0, 0.0);
+        let observer = Observer::new(60.0_f64.to_radians(), 0.0, 0.0);
+
+        let result = rise_set_transit(&direction, &time, &observer, TwilightMode::StarsPlanets);
+
+        assert!(result.circumpolar);
+        assert!(result.rise_jd.is_none());
+        assert!(result.set_jd.is_none());
+    }
+
+    #[test]
+    fn test_never_rises_from_opposite_hemisphere() {
+        // From 60°N, a star at -80° declination never rises above the horizon.
+        let direction = ra_dec_to_cartesian(0.0, -80.0_f64.to_radians());
+        let time = SkyTime::from_utc(2024, 6, 1, 0, 0, 0.0);
+        let observer = Observer::new(60.0_f64.to_radians(), 0.0, 0.0);
+
+        let result = rise_set_transit(&direction, &time, &observer, TwilightMode::StarsPlanets);
+
+        assert!(result.never_rises);
+        assert!(result.rise_jd.is_none());
+        assert!(result.set_jd.is_none());
+    }
+
+    #[test]
+    fn test_transit_hour_angle_is_zero() {
+        let direction = ra_dec_to_cartesian(1.0, 0.2);
+        let time = SkyTime::from_utc(2024, 3, 15, 12, 0, 0.0);
+        let observer = Observer::new(35.0_f64.to_radians(), -100.0_f64.to_radians(), 0.0);
+
+        let result = rise_set_transit(&direction, &time, &observer, TwilightMode::StarsPlanets);
+
+        let (ra_rad, _) = cartesian_to_ra_dec(&direction);
+        let gmst = compute_gmst(result.transit_jd);
+        let lst = compute_lst(gmst, observer.lon_rad);
+        let ha = (lst - ra_rad + PI).rem_euclid(2.0 * PI) - PI;
+        assert!(ha.abs() < 1e-3, "hour angle at transit should be ~0, got {ha}");
+    }
+
+    #[test]
+    fn test_topocentric_direction_shift_matches_moon_parallax_scale() {
+        // At the Moon's distance (~385,000 km), the topocentric shift should
+        // be on the order of the equatorial horizontal parallax (~1 degree),
+        // not arcseconds (a star) or negligible (too small to measure).
+        let distance_km = 385_000.0;
+        let direction = ra_dec_to_cartesian(1.0, 0.3);
+        let time = SkyTime::from_utc(2024, 6, 1, 3, 0, 0.0);
+        let observer = Observer::new(40.0_f64.to_radians(), -105.0_f64.to_radians(), 0.0);
+
+        let topocentric = topocentric_direction(&direction, distance_km, &observer, &time);
+        let cos_sep = (direction.x * topocentric.x
+            + direction.y * topocentric.y
+            + direction.z * topocentric.z)
+            .clamp(-1.0, 1.0);
+        let sep_deg = cos_sep.acos() * 180.0 / PI;
+
+        let parallax_deg = equatorial_horizontal_parallax_rad(distance_km) * 180.0 / PI;
+        assert!(sep_deg > 0.0, "expected a measurable parallax shift");
+        assert!(
+            sep_deg <= parallax_deg + 1e-6,
+            "shift {sep_deg} deg should not exceed the horizontal parallax {parallax_deg} deg"
+        );
+    }
+
+    #[test]
+    fn test_topocentric_direction_is_unit_vector() {
+        let direction = ra_dec_to_cartesian(2.5, -0.4);
+        let time = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0);
+        let observer = Observer::new(-33.0_f64.to_radians(), 151.0_f64.to_radians(), 50.0);
+
+        let topocentric = topocentric_direction(&direction, 385_000.0, &observer, &time);
+        let len = (topocentric.x * topocentric.x
+            + topocentric.y * topocentric.y
+            + topocentric.z * topocentric.z)
+            .sqrt();
+        assert!((len - 1.0).abs() < 1e-9, "got len={len}");
+    }
+
+    #[test]
+    fn test_equatorial_horizontal_parallax_decreases_with_distance() {
+        let moon_parallax = equatorial_horizontal_parallax_rad(385_000.0);
+        let mars_parallax = equatorial_horizontal_parallax_rad(78_000_000.0);
+        assert!(moon_parallax > mars_parallax);
+        assert!(moon_parallax > 0.0 && moon_parallax < 2.0 * PI / 180.0);
+    }
+
+    #[test]
+    fn test_equatorial_to_horizontal_matches_transit_altitude() {
+        // At transit (hour angle zero), altitude = 90 - |lat - dec| (here a
+        // body culminating south of the zenith): a direct check against the
+        // `rise_set_transit` definition of transit time.
+        let direction = ra_dec_to_cartesian(1.0, 0.2);
+        let observer = Observer::new(35.0_f64.to_radians(), -100.0_f64.to_radians(), 0.0);
+        let time = SkyTime::from_utc(2024, 3, 15, 12, 0, 0.0);
+        let transit = rise_set_transit(&direction, &time, &observer, TwilightMode::StarsPlanets);
+        let transit_time = SkyTime::from_jd(transit.transit_jd);
+
+        let horizontal = equatorial_to_horizontal(&direction, &transit_time, &observer);
+        let expected_altitude = PI / 2.0 - (observer.lat_rad - 0.2).abs();
+        assert!(
+            (horizontal.altitude_rad - expected_altitude).abs() < 1e-3,
+            "expected altitude {expected_altitude}, got {}",
+            horizontal.altitude_rad
+        );
+    }
+
+    #[test]
+    fn test_equatorial_to_horizontal_azimuth_in_range() {
+        let direction = ra_dec_to_cartesian(4.0, -0.3);
+        let time = SkyTime::from_utc(2024, 9, 1, 6, 0, 0.0);
+        let observer = Observer::new(51.5_f64.to_radians(), -0.1_f64.to_radians(), 0.0);
+
+        let horizontal = equatorial_to_horizontal(&direction, &time, &observer);
+        assert!((0.0..2.0 * PI).contains(&horizontal.azimuth_rad));
+        assert!(horizontal.altitude_rad.abs() <= PI / 2.0);
+    }
+
+    #[test]
+    fn test_north_celestial_pole_altitude_matches_latitude() {
+        // Polaris (near the north celestial pole) sits at an altitude equal
+        // to the observer's latitude, independent of time of night.
+        let direction = ra_dec_to_cartesian(0.0, PI / 2.0);
+        let time = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0);
+        let observer = Observer::new(51.5_f64.to_radians(), -0.1_f64.to_radians(), 0.0);
+
+        let horizontal = equatorial_to_horizontal(&direction, &time, &observer);
+        assert!(
+            (horizontal.altitude_rad - observer.lat_rad).abs() < 1e-9,
+            "expected altitude {}, got {}",
+            observer.lat_rad,
+            horizontal.altitude_rad
+        );
+    }
+
+    #[test]
+    fn test_rise_set_transit_for_body_sun_rises_and_sets_near_solstice() {
+        let time = SkyTime::from_utc(2024, 6, 1, 0, 0, 0.0);
+        let observer = Observer::new(40.0_f64.to_radians(), -105.0_f64.to_radians(), 0.0);
+
+        let result = rise_set_transit_for_body(CelestialBody::Sun, &time, &observer);
+
+        assert!(!result.circumpolar);
+        assert!(!result.never_rises);
+        let rise = result.rise_jd.expect("Sun should rise at 40N in June");
+        let set = result.set_jd.expect("Sun should set at 40N in June");
+        assert!(rise < result.transit_jd);
+        assert!(result.transit_jd < set);
+    }
+
+    #[test]
+    fn test_rise_set_transit_for_body_moon_converges_to_stable_result() {
+        // The Moon moves fast enough (~0.5 deg/hour) that a single-pass
+        // estimate would disagree noticeably with a converged one; running
+        // the body-keyed solver twice from different starting times should
+        // land on nearly the same transit.
+        let observer = Observer::new(51.5_f64.to_radians(), -0.1_f64.to_radians(), 0.0);
+        let time_a = SkyTime::from_utc(2024, 3, 1, 0, 0, 0.0);
+        let time_b = SkyTime::from_utc(2024, 3, 1, 6, 0, 0.0);
+
+        let result_a = rise_set_transit_for_body(CelestialBody::Moon, &time_a, &observer);
+        let result_b = rise_set_transit_for_body(CelestialBody::Moon, &time_b, &observer);
+
+        if !result_a.circumpolar && !result_a.never_rises {
+            let diff_days = (result_a.transit_jd - result_b.transit_jd).abs();
+            assert!(
+                diff_days < 1.0,
+                "transits from nearby starting times should land within a day of each other, got {diff_days}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_apparent_altitude_raises_objects_above_horizon() {
+        let horizontal = HorizontalCoord {
+            altitude_rad: 5.0_f64.to_radians(),
+            azimuth_rad: 0.0,
+        };
+
+        let apparent = horizontal.apparent_altitude_rad();
+        assert!(
+            apparent > horizontal.altitude_rad,
+            "refraction should raise the apparent altitude above the true one"
+        );
+    }
+
+    #[test]
+    fn test_apparent_altitude_unrefracted_well_below_horizon() {
+        let horizontal = HorizontalCoord {
+            altitude_rad: -10.0_f64.to_radians(),
+            azimuth_rad: 0.0,
+        };
+
+        let apparent = horizontal.apparent_altitude_rad();
+        assert_eq!(
+            apparent, horizontal.altitude_rad,
+            "far below the horizon, refraction should not be applied at all"
+        );
+    }
+
+    #[test]
+    fn test_rise_set_transit_for_body_mars_uses_stars_planets_threshold() {
+        let time = SkyTime::from_utc(2024, 1, 1, 0, 0, 0.0);
+        let observer = Observer::new(10.0_f64.to_radians(), 0.0, 0.0);
+
+        let result = rise_set_transit_for_body(CelestialBody::Mars, &time, &observer);
+        assert_eq!(
+            standard_altitude_rad(CelestialBody::Mars),
+            TwilightMode::StarsPlanets.altitude_threshold_rad()
+        );
+        assert!(
+            result.circumpolar || result.never_rises || result.rise_jd.is_some(),
+            "expected a definite rise/set/circumpolar classification"
+        );
+    }
+}
@@ -0,0 +1,433 @@
+//! Physical ephemeris via IAU/WGCCRE rotational elements.
+//!
+//! Each body's orientation is defined by its north pole direction (α0, δ0,
+//! as linear functions of Julian centuries T from J2000.0) and its
+//! prime-meridian rotation angle W (a linear function of days d from
+//! J2000.0) -- the same model Stellarium loads from its `ssystem.ini` files
+//! and the one published by the IAU Working Group on Cartographic
+//! Coordinates and Rotational Elements (WGCCRE).
+//!
+//! From these elements, `physical_ephemeris` computes the sub-observer and
+//! sub-solar planetographic longitude/latitude, the Sun-body-observer phase
+//! angle, and the illuminated fraction of the visible disk. `planets`,
+//! `planetary_moons`, and `minor_bodies` can feed their geometric vectors
+//! through this to get disk orientation, not just a sky position.
+
+use crate::coords::CartesianCoord;
+use crate::planets::CelestialBody;
+use std::f64::consts::PI;
+
+/// WGCCRE rotational elements for a body: north pole direction and
+/// prime-meridian rotation angle, each as a linear function of time.
+#[derive(Debug, Clone, Copy)]
+pub struct RotationalElements {
+    /// North pole right ascension at J2000.0, in degrees.
+    pub alpha0_deg: f64,
+    /// Rate of change of α0, in degrees per Julian century.
+    pub alpha0_rate_deg_per_century: f64,
+    /// North pole declination at J2000.0, in degrees.
+    pub delta0_deg: f64,
+    /// Rate of change of δ0, in degrees per Julian century.
+    pub delta0_rate_deg_per_century: f64,
+    /// Prime-meridian angle at J2000.0, in degrees.
+    pub w0_deg: f64,
+    /// Rotation rate, in degrees per day.
+    pub w_rate_deg_per_day: f64,
+}
+
+impl RotationalElements {
+    /// North pole direction (RA, Dec) in radians, `t_centuries` Julian
+    /// centuries from J2000.0.
+    pub fn pole(&self, t_centuries: f64) -> (f64, f64) {
+        let ra = (self.alpha0_deg + self.alpha0_rate_deg_per_century * t_centuries).to_radians();
+        let dec =
+            (self.delta0_deg + self.delta0_rate_deg_per_century * t_centuries).to_radians();
+        (ra, dec)
+    }
+
+    /// Prime-meridian rotation angle W in radians, normalized to `[0, 2π)`,
+    /// `d_days` days from J2000.0.
+    pub fn prime_meridian_angle(&self, d_days: f64) -> f64 {
+        (self.w0_deg + self.w_rate_deg_per_day * d_days)
+            .to_radians()
+            .rem_euclid(2.0 * PI)
+    }
+}
+
+/// IAU WGCCRE rotational elements, one constant per body (2015 report values).
+pub const SUN_ROTATION: RotationalElements = RotationalElements {
+    alpha0_deg: 286.13,
+    alpha0_rate_deg_per_century: 0.0,
+    delta0_deg: 63.87,
+    delta0_rate_deg_per_century: 0.0,
+    w0_deg: 84.176,
+    w_rate_deg_per_day: 14.1844,
+};
+
+pub const MERCURY_ROTATION: RotationalElements = RotationalElements {
+    alpha0_deg: 281.0097,
+    alpha0_rate_deg_per_century: -0.0328,
+    delta0_deg: 61.4143,
+    delta0_rate_deg_per_century: -0.0049,
+    w0_deg: 329.5469,
+    w_rate_deg_per_day: 6.1385025,
+};
+
+pub const VENUS_ROTATION: RotationalElements = RotationalElements {
+    alpha0_deg: 272.76,
+    alpha0_rate_deg_per_century: 0.0,
+    delta0_deg: 67.16,
+    delta0_rate_deg_per_century: 0.0,
+    w0_deg: 160.20,
+    w_rate_deg_per_day: -1.4813688,
+};
+
+pub const MOON_ROTATION: RotationalElements = RotationalElements {
+    alpha0_deg: 269.9949,
+    alpha0_rate_deg_per_century: 0.0031,
+    delta0_deg: 66.5392,
+    delta0_rate_deg_per_century: 0.0130,
+    w0_deg: 38.3213,
+    w_rate_deg_per_day: 13.17635815,
+};
+
+pub const MARS_ROTATION: RotationalElements = RotationalElements {
+    alpha0_deg: 317.269,
+    alpha0_rate_deg_per_century: -0.106,
+    delta0_deg: 54.432,
+    delta0_rate_deg_per_century: -0.061,
+    w0_deg: 176.049,
+    w_rate_deg_per_day: 350.89198226,
+};
+
+/// Jupiter's System III (rotates with the deep interior / magnetic field).
+pub const JUPITER_ROTATION: RotationalElements = RotationalElements {
+    alpha0_deg: 268.056595,
+    alpha0_rate_deg_per_century: -0.006499,
+    delta0_deg: 64.495303,
+    delta0_rate_deg_per_century: 0.002413,
+    w0_deg: 284.95,
+    w_rate_deg_per_day: 870.5360000,
+};
+
+pub const SATURN_ROTATION: RotationalElements = RotationalElements {
+    alpha0_deg: 40.589,
+    alpha0_rate_deg_per_century: -0.036,
+    delta0_deg: 83.537,
+    delta0_rate_deg_per_century: -0.004,
+    w0_deg: 38.90,
+    w_rate_deg_per_day: 810.7939024,
+};
+
+pub const URANUS_ROTATION: RotationalElements = RotationalElements {
+    alpha0_deg: 257.311,
+    alpha0_rate_deg_per_century: 0.0,
+    delta0_deg: -15.175,
+    delta0_rate_deg_per_century: 0.0,
+    w0_deg: 203.81,
+    w_rate_deg_per_day: -501.1600928,
+};
+
+pub const NEPTUNE_ROTATION: RotationalElements = RotationalElements {
+    alpha0_deg: 299.36,
+    alpha0_rate_deg_per_century: 0.0,
+    delta0_deg: 43.46,
+    delta0_rate_deg_per_century: 0.0,
+    w0_deg: 253.18,
+    w_rate_deg_per_day: 536.3128492,
+};
+
+/// Look up the WGCCRE rotational elements for a tracked celestial body.
+pub fn rotational_elements_for(body: CelestialBody) -> RotationalElements {
+    match body {
+        CelestialBody::Sun => SUN_ROTATION,
+        CelestialBody::Moon => MOON_ROTATION,
+        CelestialBody::Mercury => MERCURY_ROTATION,
+        CelestialBody::Venus => VENUS_ROTATION,
+        CelestialBody::Mars => MARS_ROTATION,
+        CelestialBody::Jupiter => JUPITER_ROTATION,
+        CelestialBody::Saturn => SATURN_ROTATION,
+        CelestialBody::Uranus => URANUS_ROTATION,
+        CelestialBody::Neptune => NEPTUNE_ROTATION,
+    }
+}
+
+fn dot(a: CartesianCoord, b: CartesianCoord) -> f64 {
+    a.x * b.x + a.y * b.y + a.z * b.z
+}
+
+/// Builds the body-fixed frame's three axes (x, y, z) expressed in J2000
+/// equatorial coordinates, for `elements` at `t_centuries` / `d_days` from
+/// J2000.0: `pole_vec` is the body's north pole direction, and `p`/`q`
+/// complete a right-handed frame with `p` along the body's actual
+/// prime-meridian axis (the ascending node of the body's equator on the
+/// J2000 equator, rotated by the prime-meridian angle W).
+fn body_fixed_axes(elements: &RotationalElements, t_centuries: f64, d_days: f64) -> (CartesianCoord, CartesianCoord, CartesianCoord) {
+    let (pole_ra, pole_dec) = elements.pole(t_centuries);
+    let pole_x = pole_dec.cos() * pole_ra.cos();
+    let pole_y = pole_dec.cos() * pole_ra.sin();
+    let pole_z = pole_dec.sin();
+
+    // Ascending node of the body's equator on the J2000 equator: (0,0,1) × pole.
+    let mut nx = -pole_y;
+    let mut ny = pole_x;
+    let mut nz = 0.0;
+    let n_len = (nx * nx + ny * ny + nz * nz).sqrt();
+    if n_len > 1e-10 {
+        nx /= n_len;
+        ny /= n_len;
+        nz /= n_len;
+    } else {
+        nx = 1.0;
+        ny = 0.0;
+        nz = 0.0;
+    }
+
+    // Completes a right-handed frame with the node as the W=0 reference.
+    let y0x = pole_y * nz - pole_z * ny;
+    let y0y = pole_z * nx - pole_x * nz;
+    let y0z = pole_x * ny - pole_y * nx;
+
+    // Rotate the node by the prime-meridian angle W to get the body's actual
+    // prime-meridian axis.
+    let w = elements.prime_meridian_angle(d_days);
+    let (sin_w, cos_w) = w.sin_cos();
+    let px = cos_w * nx + sin_w * y0x;
+    let py = cos_w * ny + sin_w * y0y;
+    let pz = cos_w * nz + sin_w * y0z;
+
+    // y-axis completing the right-handed body-fixed frame.
+    let qx = pole_y * pz - pole_z * py;
+    let qy = pole_z * px - pole_x * pz;
+    let qz = pole_x * py - pole_y * px;
+
+    (
+        CartesianCoord::new(px, py, pz),
+        CartesianCoord::new(qx, qy, qz),
+        CartesianCoord::new(pole_x, pole_y, pole_z),
+    )
+}
+
+/// Rotate a J2000 equatorial vector into the body-fixed (planetographic)
+/// frame defined by `elements` at `t_centuries` / `d_days` from J2000.0.
+fn rotate_j2000_to_body_fixed(v: &CartesianCoord, elements: &RotationalElements, t_centuries: f64, d_days: f64) -> CartesianCoord {
+    let (p, q, pole_vec) = body_fixed_axes(elements, t_centuries, d_days);
+    CartesianCoord::new(dot(*v, p), dot(*v, q), dot(*v, pole_vec))
+}
+
+/// Inverse of [`rotate_j2000_to_body_fixed`]: rotate a body-fixed vector
+/// back into J2000 equatorial coordinates. The forward rotation is
+/// orthogonal, so this is just its transpose.
+fn rotate_body_fixed_to_j2000(v: &CartesianCoord, elements: &RotationalElements, t_centuries: f64, d_days: f64) -> CartesianCoord {
+    let (p, q, pole_vec) = body_fixed_axes(elements, t_centuries, d_days);
+    CartesianCoord::new(
+        v.x * p.x + v.y * q.x + v.z * pole_vec.x,
+        v.x * p.y + v.y * q.y + v.z * pole_vec.y,
+        v.x * p.z + v.y * q.z + v.z * pole_vec.z,
+    )
+}
+
+/// Rotate a J2000 equatorial vector into `body`'s body-fixed
+/// (planetographic) frame at TDB Julian date `jde` -- the
+/// [`CelestialBody`]-level convenience wrapper around
+/// [`rotate_j2000_to_body_fixed`] for callers that don't already have
+/// [`RotationalElements`] and `t_centuries`/`d_days` in hand.
+pub fn j2000_to_body_fixed(v: CartesianCoord, body: CelestialBody, jde: f64) -> CartesianCoord {
+    let elements = rotational_elements_for(body);
+    let t_centuries = (jde - 2451545.0) / 36525.0;
+    let d_days = jde - 2451545.0;
+    rotate_j2000_to_body_fixed(&v, &elements, t_centuries, d_days)
+}
+
+/// Inverse of [`j2000_to_body_fixed`]: rotate a vector in `body`'s
+/// body-fixed frame back into J2000 equatorial coordinates at `jde`.
+pub fn body_fixed_to_j2000(v: CartesianCoord, body: CelestialBody, jde: f64) -> CartesianCoord {
+    let elements = rotational_elements_for(body);
+    let t_centuries = (jde - 2451545.0) / 36525.0;
+    let d_days = jde - 2451545.0;
+    rotate_body_fixed_to_j2000(&v, &elements, t_centuries, d_days)
+}
+
+/// Planetographic longitude/latitude of the point on a body directly facing
+/// a given direction vector (radians).
+pub(crate) fn sub_point(direction: &CartesianCoord, elements: &RotationalElements, t_centuries: f64, d_days: f64) -> (f64, f64) {
+    let body_fixed = rotate_j2000_to_body_fixed(direction, elements, t_centuries, d_days);
+    let r = (body_fixed.x * body_fixed.x + body_fixed.y * body_fixed.y + body_fixed.z * body_fixed.z).sqrt();
+    let lat = (body_fixed.z / r).asin();
+    let lon = body_fixed.y.atan2(body_fixed.x).rem_euclid(2.0 * PI);
+    (lon, lat)
+}
+
+/// Result of a physical ephemeris computation: disk orientation and
+/// illumination geometry for a body at a given instant.
+#[derive(Debug, Clone, Copy)]
+pub struct PhysicalEphemeris {
+    /// Planetographic longitude of the sub-observer point, radians.
+    pub sub_observer_lon_rad: f64,
+    /// Planetographic latitude of the sub-observer point, radians.
+    pub sub_observer_lat_rad: f64,
+    /// Planetographic longitude of the sub-solar point, radians.
+    pub sub_solar_lon_rad: f64,
+    /// Planetographic latitude of the sub-solar point, radians.
+    pub sub_solar_lat_rad: f64,
+    /// Phase angle i (Sun-body-observer angle), radians.
+    pub phase_angle_rad: f64,
+    /// Illuminated fraction of the visible disk, k = (1 + cos i) / 2.
+    pub illuminated_fraction: f64,
+}
+
+/// Compute the physical ephemeris of a body given body-centered vectors (in
+/// any consistent unit, e.g. km) to the observer and to the Sun, both
+/// expressed in the J2000 equatorial frame, at Julian Date `jde`.
+pub fn physical_ephemeris(
+    elements: &RotationalElements,
+    body_to_observer: CartesianCoord,
+    body_to_sun: CartesianCoord,
+    jde: f64,
+) -> PhysicalEphemeris {
+    let t_centuries = (jde - 2451545.0) / 36525.0;
+    let d_days = jde - 2451545.0;
+
+    let (sub_observer_lon_rad, sub_observer_lat_rad) =
+        sub_point(&body_to_observer, elements, t_centuries, d_days);
+    let (sub_solar_lon_rad, sub_solar_lat_rad) =
+        sub_point(&body_to_sun, elements, t_centuries, d_days);
+
+    let obs_dir = body_to_observer.normalize();
+    let sun_dir = body_to_sun.normalize();
+    let cos_phase = (obs_dir.x * sun_dir.x + obs_dir.y * sun_dir.y + obs_dir.z * sun_dir.z)
+        .clamp(-1.0, 1.0);
+    let phase_angle_rad = cos_phase.acos();
+    let illuminated_fraction = (1.0 + cos_phase) / 2.0;
+
+    PhysicalEphemeris {
+        sub_observer_lon_rad,
+        sub_observer_lat_rad,
+        sub_solar_lon_rad,
+        sub_solar_lat_rad,
+        phase_angle_rad,
+        illuminated_fraction,
+    }
+}
+
+/// Apparent visual magnitude of a minor body using the IAU H-G system.
+///
+/// `h` is the absolute magnitude, `g` the slope parameter (typically ~0.15
+/// for asteroids), `r_au` the heliocentric distance, `delta_au` the
+/// geocentric distance, and `phase_angle_rad` the Sun-body-observer angle.
+pub fn hg_magnitude(h: f64, g: f64, r_au: f64, delta_au: f64, phase_angle_rad: f64) -> f64 {
+    let half_tan = (phase_angle_rad / 2.0).tan();
+    let phi1 = (-3.33 * half_tan.powf(0.63)).exp();
+    let phi2 = (-1.87 * half_tan.powf(1.22)).exp();
+
+    h + 5.0 * (r_au * delta_au).log10() - 2.5 * ((1.0 - g) * phi1 + g * phi2).log10()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prime_meridian_wraps() {
+        let w = JUPITER_ROTATION.prime_meridian_angle(10000.0);
+        assert!((0.0..2.0 * PI).contains(&w));
+    }
+
+    #[test]
+    fn test_full_phase_fully_illuminated() {
+        // Observer and Sun in the same direction from the body: phase angle 0,
+        // fully illuminated.
+        let elements = MARS_ROTATION;
+        let observer = CartesianCoord::new(1.0, 0.0, 0.0);
+        let sun = CartesianCoord::new(2.0, 0.0, 0.0);
+        let eph = physical_ephemeris(&elements, observer, sun, 2451545.0);
+
+        assert!(eph.phase_angle_rad.abs() < 1e-9);
+        assert!((eph.illuminated_fraction - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_new_phase_unilluminated() {
+        // Observer and Sun on opposite sides: phase angle π, new (unlit) disk.
+        let elements = MARS_ROTATION;
+        let observer = CartesianCoord::new(1.0, 0.0, 0.0);
+        let sun = CartesianCoord::new(-1.0, 0.0, 0.0);
+        let eph = physical_ephemeris(&elements, observer, sun, 2451545.0);
+
+        assert!((eph.phase_angle_rad - PI).abs() < 1e-9);
+        assert!(eph.illuminated_fraction.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_hg_magnitude_matches_absolute_at_zero_phase_unit_distance() {
+        // At r = Δ = 1 AU and phase angle 0, V should reduce to H (since
+        // log10(1*1) = 0 and the phase function term is 1 at α=0).
+        let v = hg_magnitude(10.0, 0.15, 1.0, 1.0, 0.0);
+        assert!((v - 10.0).abs() < 1e-6, "expected V≈H, got {v}");
+    }
+
+    #[test]
+    fn test_hg_magnitude_dims_with_phase_angle() {
+        let v_zero = hg_magnitude(10.0, 0.15, 1.5, 1.0, 0.0);
+        let v_phased = hg_magnitude(10.0, 0.15, 1.5, 1.0, 0.8);
+        assert!(
+            v_phased > v_zero,
+            "magnitude should increase (dim) at higher phase angle"
+        );
+    }
+
+    #[test]
+    fn test_j2000_to_body_fixed_round_trips_through_its_inverse() {
+        let jde = 2451545.0 + 12345.6;
+        for body in [
+            CelestialBody::Mercury,
+            CelestialBody::Venus,
+            CelestialBody::Mars,
+            CelestialBody::Jupiter,
+            CelestialBody::Saturn,
+            CelestialBody::Uranus,
+            CelestialBody::Neptune,
+        ] {
+            let v = CartesianCoord::new(0.6, -0.3, 0.74);
+            let body_fixed = j2000_to_body_fixed(v, body, jde);
+            let round_tripped = body_fixed_to_j2000(body_fixed, body, jde);
+
+            assert!((round_tripped.x - v.x).abs() < 1e-9, "{body:?}: x mismatch");
+            assert!((round_tripped.y - v.y).abs() < 1e-9, "{body:?}: y mismatch");
+            assert!((round_tripped.z - v.z).abs() < 1e-9, "{body:?}: z mismatch");
+        }
+    }
+
+    #[test]
+    fn test_j2000_to_body_fixed_preserves_vector_length() {
+        let jde = 2451545.0;
+        let v = CartesianCoord::new(1.0, 2.0, -3.0);
+        let expected_len = dot(v, v).sqrt();
+
+        let rotated = j2000_to_body_fixed(v, CelestialBody::Jupiter, jde);
+        let got_len = dot(rotated, rotated).sqrt();
+
+        assert!(
+            (got_len - expected_len).abs() < 1e-9,
+            "rotation should be length-preserving: expected {expected_len}, got {got_len}"
+        );
+    }
+
+    #[test]
+    fn test_j2000_to_body_fixed_pole_maps_to_body_fixed_z_axis() {
+        // The body's own pole direction, expressed in J2000 equatorial,
+        // should rotate to (0, 0, 1) in the body-fixed frame by construction.
+        let t_centuries = 0.0;
+        let (pole_ra, pole_dec) = JUPITER_ROTATION.pole(t_centuries);
+        let pole_j2000 = CartesianCoord::new(
+            pole_dec.cos() * pole_ra.cos(),
+            pole_dec.cos() * pole_ra.sin(),
+            pole_dec.sin(),
+        );
+
+        let body_fixed = j2000_to_body_fixed(pole_j2000, CelestialBody::Jupiter, 2451545.0);
+        assert!(body_fixed.x.abs() < 1e-9, "got x={}", body_fixed.x);
+        assert!(body_fixed.y.abs() < 1e-9, "got y={}", body_fixed.y);
+        assert!((body_fixed.z - 1.0).abs() < 1e-9, "got z={}", body_fixed.z);
+    }
+}
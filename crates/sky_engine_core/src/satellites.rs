@@ -1,17 +1,35 @@
 //! Satellite ephemeris and visibility calculations.
 //!
-//! Supports multiple satellites (ISS, Hubble, etc.) using pre-computed
-//! ephemeris data with interpolation for accurate positioning.
-//! Includes Earth shadow calculations for visibility determination.
+//! Supports multiple satellites (ISS, Hubble, etc.) using either pre-computed
+//! ephemeris data with interpolation, or on-the-fly SGP4 propagation from a
+//! Two-Line Element set. Includes Earth shadow calculations for visibility
+//! determination.
 
-use crate::coords::{compute_gmst, CartesianCoord};
+use crate::coords::{compute_gmst, compute_refraction, precession_angles, CartesianCoord, PrecessionAngles};
 use crate::planets::{heliocentric_position, Planet, AU_TO_KM};
 use crate::time::SkyTime;
 use std::f64::consts::PI;
 
-/// Earth's mean equatorial radius in km
+/// Earth's mean equatorial radius in km, used where a spherical
+/// approximation is sufficient (e.g. the shadow model's angular radii).
 const EARTH_RADIUS_KM: f64 = 6378.137;
 
+/// WGS84 semi-major axis (equatorial radius), km. Matches
+/// `visibility::WGS84_A_KM`; duplicated here since `eci_to_topocentric`'s
+/// observer model can't depend on the higher-level `visibility` module.
+const WGS84_A_KM: f64 = 6378.137;
+/// WGS84 flattening.
+const WGS84_F: f64 = 1.0 / 298.257223563;
+
+/// Sun's radius in km, for the apparent-disk occultation test in
+/// `shadow_state` and transit/background-body angular size calculations.
+pub(crate) const SUN_RADIUS_KM: f64 = 696_000.0;
+
+/// Sun-below-horizon threshold below which the sky is dark enough for a
+/// sunlit satellite pass to actually be visible, roughly civil twilight.
+/// Matches `visibility::SUN_ALTITUDE_VISIBLE_THRESHOLD_DEG`.
+const SUN_ALTITUDE_VISIBLE_THRESHOLD_DEG: f64 = -6.0;
+
 /// Identifier for supported satellites.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SatelliteId {
@@ -19,10 +37,14 @@ pub enum SatelliteId {
     ISS,
     /// Hubble Space Telescope (NORAD ID: 20580)
     Hubble,
+    /// Arbitrary satellite identified by its NORAD catalog number, used for
+    /// objects propagated on the fly from a TLE rather than the bundled
+    /// ephemerides above.
+    Custom(u32),
 }
 
 impl SatelliteId {
-    /// All supported satellites.
+    /// The bundled satellites with precomputed ephemeris data.
     pub const ALL: &'static [SatelliteId] = &[SatelliteId::ISS, SatelliteId::Hubble];
 
     /// Get the human-readable name for this satellite.
@@ -30,6 +52,7 @@ impl SatelliteId {
         match self {
             SatelliteId::ISS => "ISS",
             SatelliteId::Hubble => "Hubble",
+            SatelliteId::Custom(_) => "Satellite",
         }
     }
 
@@ -38,22 +61,60 @@ impl SatelliteId {
         match self {
             SatelliteId::ISS => "International Space Station",
             SatelliteId::Hubble => "Hubble Space Telescope",
+            SatelliteId::Custom(_) => "Satellite (TLE)",
         }
     }
 
-    /// Get the JPL Horizons ID for this satellite.
+    /// Get the JPL Horizons ID for this satellite, if it has one.
     pub fn horizons_id(&self) -> i32 {
         match self {
             SatelliteId::ISS => -125544,
             SatelliteId::Hubble => -48,
+            SatelliteId::Custom(_) => 0,
+        }
+    }
+
+    /// Get the NORAD catalog number for this satellite, if known.
+    pub fn norad_id(&self) -> Option<u32> {
+        match self {
+            SatelliteId::ISS => Some(25544),
+            SatelliteId::Hubble => Some(20580),
+            SatelliteId::Custom(n) => Some(*n),
+        }
+    }
+
+    /// Standard magnitude at 1000 km range and full phase, for the
+    /// diffuse-sphere brightness model in [`satellite_magnitude`]. Only
+    /// known for satellites with a published reflectivity estimate; other
+    /// satellites report `None` rather than a guessed value.
+    pub fn standard_magnitude(&self) -> Option<f64> {
+        match self {
+            SatelliteId::ISS => Some(-1.3),
+            SatelliteId::Hubble => Some(2.0),
+            SatelliteId::Custom(_) => None,
+        }
+    }
+
+    /// Characteristic radius in km, for the angular-size estimate in
+    /// `SatellitePosition::angular_size_arcsec`. A rough single-sphere
+    /// stand-in for each satellite's largest dimension (e.g. the ISS's truss
+    /// span), not a real physical radius; `None` where no reasonable
+    /// estimate exists.
+    pub fn radius_km(&self) -> Option<f64> {
+        match self {
+            SatelliteId::ISS => Some(0.055),
+            SatelliteId::Hubble => Some(0.0021),
+            SatelliteId::Custom(_) => None,
         }
     }
 
     /// Get the index in the satellite array (for buffer access).
+    /// Only meaningful for the bundled satellites in [`SatelliteId::ALL`].
     pub fn index(&self) -> usize {
         match self {
             SatelliteId::ISS => 0,
             SatelliteId::Hubble => 1,
+            SatelliteId::Custom(_) => usize::MAX,
         }
     }
 
@@ -220,6 +281,87 @@ impl SatelliteEphemeris {
         ))
     }
 
+    /// Interpolate both position (km) and velocity (km/s) at a given Julian
+    /// Date. Velocity is the analytic time-derivative of the same
+    /// Catmull-Rom basis `interpolate` uses, converted from 1/day to 1/s.
+    /// Returns `None` under the same conditions as `interpolate`.
+    pub fn interpolate_state(&self, jd: f64) -> Option<((f64, f64, f64), (f64, f64, f64))> {
+        if self.points.len() < 2 {
+            return None;
+        }
+
+        // An exact hit on a sample needs a segment to take a derivative
+        // from, so treat it as sitting at the start of the following
+        // segment (or the end of the last one) rather than short-circuiting
+        // like `interpolate` does.
+        let idx = match self.points.binary_search_by(|p| p.jd.partial_cmp(&jd).unwrap()) {
+            Ok(i) if i + 1 < self.points.len() => i + 1,
+            Ok(i) => i,
+            Err(i) => i,
+        };
+
+        if idx == 0 || idx >= self.points.len() {
+            return None;
+        }
+
+        if idx < 2 || idx >= self.points.len() - 1 {
+            // Linear interpolation: constant velocity across the segment.
+            let p0 = &self.points[idx - 1];
+            let p1 = &self.points[idx];
+            let dt_days = p1.jd - p0.jd;
+            let t = (jd - p0.jd) / dt_days;
+            let dt_secs = dt_days * 86400.0;
+
+            let pos = (
+                p0.x_km + t * (p1.x_km - p0.x_km),
+                p0.y_km + t * (p1.y_km - p0.y_km),
+                p0.z_km + t * (p1.z_km - p0.z_km),
+            );
+            let vel = (
+                (p1.x_km - p0.x_km) / dt_secs,
+                (p1.y_km - p0.y_km) / dt_secs,
+                (p1.z_km - p0.z_km) / dt_secs,
+            );
+            return Some((pos, vel));
+        }
+
+        let p0 = &self.points[idx - 2];
+        let p1 = &self.points[idx - 1];
+        let p2 = &self.points[idx];
+        let p3 = &self.points[idx + 1];
+
+        let dt_days = p2.jd - p1.jd;
+        let t = (jd - p1.jd) / dt_days;
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let dt_secs = dt_days * 86400.0;
+
+        let interp = |v0: f64, v1: f64, v2: f64, v3: f64| -> f64 {
+            0.5 * ((2.0 * v1)
+                + (-v0 + v2) * t
+                + (2.0 * v0 - 5.0 * v1 + 4.0 * v2 - v3) * t2
+                + (-v0 + 3.0 * v1 - 3.0 * v2 + v3) * t3)
+        };
+        let interp_vel = |v0: f64, v1: f64, v2: f64, v3: f64| -> f64 {
+            0.5 * ((-v0 + v2)
+                + 2.0 * (2.0 * v0 - 5.0 * v1 + 4.0 * v2 - v3) * t
+                + 3.0 * (-v0 + 3.0 * v1 - 3.0 * v2 + v3) * t2)
+                / dt_secs
+        };
+
+        let pos = (
+            interp(p0.x_km, p1.x_km, p2.x_km, p3.x_km),
+            interp(p0.y_km, p1.y_km, p2.y_km, p3.y_km),
+            interp(p0.z_km, p1.z_km, p2.z_km, p3.z_km),
+        );
+        let vel = (
+            interp_vel(p0.x_km, p1.x_km, p2.x_km, p3.x_km),
+            interp_vel(p0.y_km, p1.y_km, p2.y_km, p3.y_km),
+            interp_vel(p0.z_km, p1.z_km, p2.z_km, p3.z_km),
+        );
+        Some((pos, vel))
+    }
+
     /// Get the number of ephemeris points.
     pub fn len(&self) -> usize {
         self.points.len()
@@ -240,52 +382,146 @@ pub struct SatellitePosition {
     pub direction: CartesianCoord,
     /// Distance from observer in km
     pub distance_km: f64,
-    /// Altitude above horizon in degrees (only valid if topocentric)
+    /// Geometric altitude above horizon in degrees (only valid if topocentric)
     pub altitude_deg: f64,
+    /// Altitude as it actually appears to the observer, with atmospheric
+    /// refraction applied; use this instead of `altitude_deg` for rendering.
+    pub apparent_altitude_deg: f64,
     /// Azimuth in degrees (only valid if topocentric)
     pub azimuth_deg: f64,
-    /// Whether satellite is illuminated by the Sun (not in Earth's shadow)
-    pub illuminated: bool,
+    /// Satellite's illumination relative to Earth's shadow cone; replaces a
+    /// bare "in shadow or not" bool so callers can render the dimming as a
+    /// satellite crosses into penumbra rather than popping straight to dark.
+    pub shadow: ShadowState,
     /// Whether satellite is above the horizon (only valid if topocentric)
     pub above_horizon: bool,
+    /// Estimated visual magnitude from the diffuse-sphere phase model in
+    /// [`satellite_magnitude`], or `None` if this satellite has no known
+    /// standard magnitude or it's fully eclipsed.
+    pub magnitude: Option<f64>,
+    /// Rate of change of `distance_km`, in km/s: positive while the
+    /// satellite recedes from the observer, negative while it approaches.
+    /// Accounts for the observer's own velocity from Earth's rotation.
+    /// `None` when no velocity was available for the underlying position
+    /// (e.g. too few ephemeris points to take a derivative).
+    pub range_rate_km_s: Option<f64>,
+    /// The satellite's apparent angular diameter as seen from the observer,
+    /// in arcseconds, from `id`'s characteristic `radius_km`. Lets a
+    /// renderer decide when to draw a disk instead of a point. `None` when
+    /// the satellite has no known physical size.
+    pub angular_size_arcsec: Option<f64>,
+}
+
+impl SatellitePosition {
+    /// The frequency, in Hz, at which a receiver should expect to find a
+    /// signal transmitted at `frequency_hz`, given this pass's range rate:
+    /// `frequency_hz * (1 - range_rate_km_s / c)`. Returns `None` when
+    /// `range_rate_km_s` is unknown.
+    pub fn doppler_shift(&self, frequency_hz: f64) -> Option<f64> {
+        const SPEED_OF_LIGHT_KM_S: f64 = 299_792.458;
+        self.range_rate_km_s
+            .map(|range_rate| frequency_hz * (1.0 - range_rate / SPEED_OF_LIGHT_KM_S))
+    }
+}
+
+/// A satellite's illumination relative to Earth's shadow cone, from the
+/// apparent-disk occultation test in `shadow_state`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ShadowState {
+    /// Fully sunlit: Earth's disk doesn't overlap the Sun's at all, as seen
+    /// from the satellite.
+    Sunlit,
+    /// Partially occulted: `fraction` is the fraction of the Sun's apparent
+    /// disk *area* that Earth covers, in `[0, 1]`.
+    Penumbra { fraction: f64 },
+    /// Totally eclipsed: Earth's disk entirely covers the Sun's.
+    Umbra,
+}
+
+impl ShadowState {
+    /// Whether the satellite receives any direct sunlight at all -- true
+    /// for `Sunlit` and any non-total `Penumbra`, false only for `Umbra`.
+    /// A drop-in replacement for the old bare `illuminated: bool`.
+    pub fn is_illuminated(&self) -> bool {
+        !matches!(self, ShadowState::Umbra)
+    }
+
+    /// The fraction of the Sun's apparent disk still visible from the
+    /// satellite, in `[0, 1]`: `1.0` for `Sunlit`, `0.0` for `Umbra`, and
+    /// `1.0 - fraction` for `Penumbra`. A continuous counterpart to
+    /// `is_illuminated` for renderers that want to fade a satellite through
+    /// twilight rather than snap it on/off.
+    pub fn sunlit_fraction(&self) -> f64 {
+        match self {
+            ShadowState::Sunlit => 1.0,
+            ShadowState::Penumbra { fraction } => 1.0 - fraction,
+            ShadowState::Umbra => 0.0,
+        }
+    }
+}
+
+/// The Sun's position in ECI coordinates (km, from Earth center) at `jd`,
+/// taken as the opposite of Earth's heliocentric position.
+pub(crate) fn sun_eci_km(jd: f64) -> (f64, f64, f64) {
+    let earth_helio = heliocentric_position(Planet::Earth, jd);
+    (
+        -earth_helio.0 * AU_TO_KM,
+        -earth_helio.1 * AU_TO_KM,
+        -earth_helio.2 * AU_TO_KM,
+    )
 }
 
-/// Check if a satellite is in Earth's shadow.
+/// Classify a satellite's illumination using the standard apparent-disk
+/// occultation test (Montenbruck & Gill section 3.6; libpredict's
+/// `is_eclipsed`), replacing the old cylindrical-shadow approximation that
+/// couldn't distinguish umbra from penumbra and treated the shadow as
+/// parallel-sided.
 ///
-/// Uses cylindrical shadow approximation:
-/// 1. Get Sun direction from Earth
-/// 2. Project satellite position onto Sun-Earth line
-/// 3. If projection is "behind" Earth and satellite is within Earth's shadow cylinder, it's eclipsed
+/// Computes the apparent angular radius of the Sun (`a`) and of Earth (`b`)
+/// as seen from the satellite, and the angular separation (`c`) between
+/// their centers, then classifies by how those two angular disks overlap:
+/// sunlit when they don't touch (`c >= a + b`), total eclipse when Earth's
+/// disk fully covers the Sun's (`c <= b - a`), otherwise penumbra, with the
+/// occulted fraction of the Sun's disk area from the standard two-circle
+/// intersection-area formula.
 ///
 /// # Arguments
-/// * `sat_eci` - Satellite position in ECI coordinates (km)
+/// * `sat_eci` - Satellite position in ECI coordinates (km, from Earth center)
 /// * `sun_eci` - Sun position in ECI coordinates (km, from Earth center)
-fn is_in_earth_shadow(sat_eci: (f64, f64, f64), sun_eci: (f64, f64, f64)) -> bool {
-    // Satellite position vector
-    let (ix, iy, iz) = sat_eci;
+fn shadow_state(sat_eci: (f64, f64, f64), sun_eci: (f64, f64, f64)) -> ShadowState {
+    let r = (sat_eci.0 * sat_eci.0 + sat_eci.1 * sat_eci.1 + sat_eci.2 * sat_eci.2).sqrt();
 
-    // Sun direction (unit vector from Earth toward Sun)
-    let sun_dist = (sun_eci.0 * sun_eci.0 + sun_eci.1 * sun_eci.1 + sun_eci.2 * sun_eci.2).sqrt();
-    let (sx, sy, sz) = (sun_eci.0 / sun_dist, sun_eci.1 / sun_dist, sun_eci.2 / sun_dist);
+    let sat_to_sun = (sun_eci.0 - sat_eci.0, sun_eci.1 - sat_eci.1, sun_eci.2 - sat_eci.2);
+    let dist_to_sun =
+        (sat_to_sun.0 * sat_to_sun.0 + sat_to_sun.1 * sat_to_sun.1 + sat_to_sun.2 * sat_to_sun.2).sqrt();
 
-    // Project satellite onto Sun direction: dot(sat, sun_dir)
-    let proj = ix * sx + iy * sy + iz * sz;
+    let a = (SUN_RADIUS_KM / dist_to_sun).asin();
+    let b = (EARTH_RADIUS_KM / r).asin();
 
-    // Satellite must be on the anti-Sun side (behind Earth from Sun's perspective)
-    if proj >= 0.0 {
-        return false; // Satellite is on the Sun-facing side
-    }
+    // Angle at the satellite between "toward Earth's center" (-sat_eci) and
+    // "toward the Sun" (sat_to_sun).
+    let dot = -sat_eci.0 * sat_to_sun.0 - sat_eci.1 * sat_to_sun.1 - sat_eci.2 * sat_to_sun.2;
+    let cos_c = (dot / (r * dist_to_sun)).clamp(-1.0, 1.0);
+    let c = cos_c.acos();
 
-    // Distance from satellite to the Earth-Sun line
-    // Cross product magnitude gives the perpendicular distance
-    let cross_x = iy * sz - iz * sy;
-    let cross_y = iz * sx - ix * sz;
-    let cross_z = ix * sy - iy * sx;
-    let perp_dist = (cross_x * cross_x + cross_y * cross_y + cross_z * cross_z).sqrt();
+    if c >= a + b {
+        return ShadowState::Sunlit;
+    }
+    if c <= b - a {
+        return ShadowState::Umbra;
+    }
 
-    // Satellite is in shadow if it's within Earth's shadow cylinder
-    // Using a slightly larger radius to account for penumbra
-    perp_dist < EARTH_RADIUS_KM * 1.02
+    // Partial overlap: occulted fraction of the Sun's apparent disk area,
+    // via the standard two-circle intersection-area formula (angular radii
+    // `a`, `b` as the circle radii, `c` as the center separation). Clamp the
+    // acos arguments since floating-point error can push them just past
+    // +/-1 right at the total/sunlit boundaries.
+    let term1 = a * a * ((c * c + a * a - b * b) / (2.0 * c * a)).clamp(-1.0, 1.0).acos();
+    let term2 = b * b * ((c * c + b * b - a * a) / (2.0 * c * b)).clamp(-1.0, 1.0).acos();
+    let term3 = 0.5 * ((-c + a + b) * (c + a - b) * (c - a + b) * (c + a + b)).max(0.0).sqrt();
+    let area = term1 + term2 - term3;
+
+    ShadowState::Penumbra { fraction: (area / (PI * a * a)).clamp(0.0, 1.0) }
 }
 
 /// Convert ECI (Earth-Centered Inertial) coordinates to topocentric coordinates.
@@ -295,17 +531,18 @@ fn is_in_earth_shadow(sat_eci: (f64, f64, f64), sun_eci: (f64, f64, f64)) -> boo
 /// * `observer_lat_rad` - Observer latitude in radians
 /// * `observer_lon_rad` - Observer longitude in radians
 /// * `gmst` - Greenwich Mean Sidereal Time in radians
-/// * `observer_height_km` - Observer height above ellipsoid (km), usually ~0
+/// * `observer_height_km` - Observer height above the WGS84 ellipsoid (km)
 ///
 /// # Returns
-/// (direction unit vector, distance km, altitude deg, azimuth deg)
-fn eci_to_topocentric(
+/// (direction unit vector, distance km, geometric altitude deg, apparent
+/// altitude deg (with atmospheric refraction applied), azimuth deg)
+pub(crate) fn eci_to_topocentric(
     eci: (f64, f64, f64),
     observer_lat_rad: f64,
     observer_lon_rad: f64,
     gmst: f64,
     observer_height_km: f64,
-) -> (CartesianCoord, f64, f64, f64) {
+) -> (CartesianCoord, f64, f64, f64, f64) {
     let (x, y, z) = eci;
 
     // Observer position in ECEF (Earth-Centered Earth-Fixed)
@@ -317,11 +554,16 @@ fn eci_to_topocentric(
     let cos_lst = lst.cos();
     let sin_lst = lst.sin();
 
-    // Observer position in ECI (approximate, ignoring Earth's oblateness for now)
-    let obs_r = EARTH_RADIUS_KM + observer_height_km;
-    let obs_x = obs_r * cos_lat * cos_lst;
-    let obs_y = obs_r * cos_lat * sin_lst;
-    let obs_z = obs_r * sin_lat;
+    // Observer position in ECI, on the WGS84 ellipsoid rather than a sphere:
+    // `N` is the prime-vertical radius of curvature at this geodetic
+    // latitude, which differs from the polar radius by Earth's flattening
+    // and otherwise introduces kilometers of error at mid-latitudes.
+    let e2 = 2.0 * WGS84_F - WGS84_F * WGS84_F;
+    let n = WGS84_A_KM / (1.0 - e2 * sin_lat * sin_lat).sqrt();
+    let obs_equatorial_r = (n + observer_height_km) * cos_lat;
+    let obs_x = obs_equatorial_r * cos_lst;
+    let obs_y = obs_equatorial_r * sin_lst;
+    let obs_z = (n * (1.0 - e2) + observer_height_km) * sin_lat;
 
     // Vector from observer to satellite
     let dx = x - obs_x;
@@ -356,6 +598,16 @@ fn eci_to_topocentric(
     let azimuth_deg = (azimuth_rad * 180.0 / PI + 360.0) % 360.0;
     let altitude_deg = altitude_rad * 180.0 / PI;
 
+    // Atmospheric refraction bends light near the horizon, raising the
+    // apparent altitude above the geometric one; `compute_refraction`'s
+    // Bennett-formula approximation becomes unreliable well below the
+    // horizon, so leave objects there at their geometric altitude.
+    let apparent_altitude_deg = if altitude_deg > -1.0 {
+        altitude_deg + compute_refraction(altitude_rad).to_degrees()
+    } else {
+        altitude_deg
+    };
+
     // Direction unit vector in ECI (for rendering)
     let direction = CartesianCoord {
         x: dx / distance,
@@ -363,7 +615,7 @@ fn eci_to_topocentric(
         z: dz / distance,
     };
 
-    (direction, distance, altitude_deg, azimuth_deg)
+    (direction, distance, altitude_deg, apparent_altitude_deg, azimuth_deg)
 }
 
 /// Compute a satellite's position as seen from an observer.
@@ -383,43 +635,770 @@ pub fn compute_satellite_position(
 ) -> Option<SatellitePosition> {
     let jd = time.julian_date_tdb();
 
-    // Interpolate satellite ECI position
-    let sat_eci = ephemeris.interpolate(jd)?;
-
-    // Get GMST for coordinate conversion
-    let jd_ut1 = time.julian_date_utc();
-    let gmst = compute_gmst(jd_ut1);
+    // Interpolate satellite ECI position and velocity
+    let (sat_eci, sat_velocity_km_s) = ephemeris.interpolate_state(jd)?;
 
-    // Convert to topocentric coordinates
-    let (direction, distance_km, altitude_deg, azimuth_deg) = eci_to_topocentric(
+    Some(satellite_position_from_eci(
+        ephemeris.id(),
         sat_eci,
+        Some(sat_velocity_km_s),
+        time,
         observer_lat_rad,
         observer_lon_rad,
-        gmst,
         observer_height_km,
-    );
+    ))
+}
 
-    // Get Sun position for shadow calculation
-    // Sun is in the opposite direction of Earth's heliocentric position
-    let earth_helio = heliocentric_position(Planet::Earth, jd);
-    let sun_eci = (
-        -earth_helio.0 * AU_TO_KM,
-        -earth_helio.1 * AU_TO_KM,
-        -earth_helio.2 * AU_TO_KM,
-    );
+/// Where a satellite's position comes from: a precomputed, interpolated
+/// [`SatelliteEphemeris`] (bounded coverage, smooth motion), or a live
+/// SGP4/SDP4 propagation from a [`Tle`] (unbounded coverage, any NORAD
+/// object, no separate data pipeline). Lets callers work with either
+/// through one entry point, [`compute_satellite_position_from_source`],
+/// instead of being limited to the bundled ISS/Hubble ephemerides.
+pub enum SatelliteSource<'a> {
+    Ephemeris(&'a SatelliteEphemeris),
+    Tle(&'a Tle),
+}
+
+/// Compute a satellite's position as seen from an observer, from either a
+/// [`SatelliteEphemeris`] or a live [`Tle`] propagation; see
+/// [`SatelliteSource`]. Returns `None` only for the ephemeris case, when
+/// `time` falls outside its covered range -- TLE propagation has no such
+/// bound.
+pub fn compute_satellite_position_from_source(
+    source: SatelliteSource,
+    time: &SkyTime,
+    observer_lat_rad: f64,
+    observer_lon_rad: f64,
+    observer_height_km: f64,
+) -> Option<SatellitePosition> {
+    match source {
+        SatelliteSource::Ephemeris(ephemeris) => {
+            compute_satellite_position(ephemeris, time, observer_lat_rad, observer_lon_rad, observer_height_km)
+        }
+        SatelliteSource::Tle(tle) => Some(compute_satellite_position_from_tle(
+            tle,
+            time,
+            observer_lat_rad,
+            observer_lon_rad,
+            observer_height_km,
+        )),
+    }
+}
+
+/// Shared tail end of satellite position computation: given a satellite's
+/// position in ECI coordinates, compute its topocentric direction, shadow
+/// state, and horizon status. Used by both the interpolated-ephemeris path
+/// ([`compute_satellite_position`]) and the TLE/SGP4 path
+/// ([`compute_satellite_position_from_tle`]).
+fn satellite_position_from_eci(
+    id: SatelliteId,
+    sat_eci: (f64, f64, f64),
+    sat_velocity_km_s: Option<(f64, f64, f64)>,
+    time: &SkyTime,
+    observer_lat_rad: f64,
+    observer_lon_rad: f64,
+    observer_height_km: f64,
+) -> SatellitePosition {
+    let jd = time.julian_date_tdb();
 
-    let illuminated = !is_in_earth_shadow(sat_eci, sun_eci);
+    // Get GMST for coordinate conversion
+    let jd_ut1 = time.julian_date_utc();
+    let gmst = compute_gmst(jd_ut1);
+
+    // Convert to topocentric coordinates
+    let (direction, distance_km, altitude_deg, apparent_altitude_deg, azimuth_deg) =
+        eci_to_topocentric(
+            sat_eci,
+            observer_lat_rad,
+            observer_lon_rad,
+            gmst,
+            observer_height_km,
+        );
+
+    let sun_eci = sun_eci_km(jd);
+    let shadow = shadow_state(sat_eci, sun_eci);
     let above_horizon = altitude_deg > 0.0;
 
-    Some(SatellitePosition {
-        id: ephemeris.id(),
+    // `direction` points observer -> satellite, so its negation is the
+    // satellite -> observer direction the phase-angle calculation needs.
+    let to_observer_unit = (-direction.x, -direction.y, -direction.z);
+    let magnitude = satellite_magnitude(
+        id.standard_magnitude(),
+        sat_eci,
+        sun_eci,
+        to_observer_unit,
+        distance_km,
+        shadow,
+    );
+
+    // Range rate is the component of the satellite's velocity relative to
+    // the observer (whose own velocity comes from Earth's rotation) along
+    // the observer -> satellite direction: positive while receding.
+    let range_rate_km_s = sat_velocity_km_s.map(|sat_vel| {
+        let obs_vel = observer_velocity_eci_km_s(observer_lat_rad, observer_lon_rad, gmst, observer_height_km);
+        let rel_vel = (
+            sat_vel.0 - obs_vel.0,
+            sat_vel.1 - obs_vel.1,
+            sat_vel.2 - obs_vel.2,
+        );
+        rel_vel.0 * direction.x + rel_vel.1 * direction.y + rel_vel.2 * direction.z
+    });
+
+    let angular_size_arcsec = id
+        .radius_km()
+        .map(|radius_km| 2.0 * (radius_km / distance_km).asin().to_degrees() * 3600.0);
+
+    SatellitePosition {
+        id,
         direction,
         distance_km,
         altitude_deg,
+        apparent_altitude_deg,
         azimuth_deg,
-        illuminated,
+        shadow,
         above_horizon,
-    })
+        magnitude,
+        range_rate_km_s,
+        angular_size_arcsec,
+    }
+}
+
+/// Earth's mean angular rotation rate, in rad/s (2*pi / sidereal day),
+/// used to derive an observer's ECI velocity for range-rate/Doppler.
+const EARTH_ROTATION_RATE_RAD_PER_SEC: f64 = 7.292_115_0e-5;
+
+/// An observer's velocity in ECI coordinates (km/s) from Earth's rotation
+/// alone, via `v = omega x r` with `omega` along the polar axis. Reuses the
+/// same WGS84 observer-position model as `eci_to_topocentric`.
+fn observer_velocity_eci_km_s(
+    observer_lat_rad: f64,
+    observer_lon_rad: f64,
+    gmst: f64,
+    observer_height_km: f64,
+) -> (f64, f64, f64) {
+    let cos_lat = observer_lat_rad.cos();
+    let sin_lat = observer_lat_rad.sin();
+    let lst = gmst + observer_lon_rad;
+
+    let e2 = 2.0 * WGS84_F - WGS84_F * WGS84_F;
+    let n = WGS84_A_KM / (1.0 - e2 * sin_lat * sin_lat).sqrt();
+    let obs_equatorial_r = (n + observer_height_km) * cos_lat;
+    let obs_x = obs_equatorial_r * lst.cos();
+    let obs_y = obs_equatorial_r * lst.sin();
+
+    (
+        -EARTH_ROTATION_RATE_RAD_PER_SEC * obs_y,
+        EARTH_ROTATION_RATE_RAD_PER_SEC * obs_x,
+        0.0,
+    )
+}
+
+/// Estimate a satellite's visual magnitude using a diffuse (Lambertian)
+/// sphere phase model, the same approach pyephem's `gk_mag` phase handling
+/// uses for other solar-system bodies: `mag = std_mag + 5*log10(distance_km
+/// / 1000) - 2.5*log10(p(alpha))`, where `alpha` is the phase angle at the
+/// satellite between the Sun and the observer, and `p(alpha) = ((pi - alpha)
+/// * cos(alpha) + sin(alpha)) / pi`. The phase fraction is further scaled by
+/// `shadow.sunlit_fraction()`, so a satellite partway into penumbra dims
+/// smoothly instead of holding its fully-sunlit brightness right up to the
+/// umbra.
+///
+/// Returns `None` when `std_mag` is unknown for this satellite, it's fully
+/// eclipsed, or the phase geometry is degenerate (new phase, `p(alpha) <=
+/// 0`).
+fn satellite_magnitude(
+    std_mag: Option<f64>,
+    sat_eci: (f64, f64, f64),
+    sun_eci: (f64, f64, f64),
+    to_observer_unit: (f64, f64, f64),
+    distance_km: f64,
+    shadow: ShadowState,
+) -> Option<f64> {
+    let std_mag = std_mag?;
+
+    let to_sun = (
+        sun_eci.0 - sat_eci.0,
+        sun_eci.1 - sat_eci.1,
+        sun_eci.2 - sat_eci.2,
+    );
+    let to_sun_dist = (to_sun.0 * to_sun.0 + to_sun.1 * to_sun.1 + to_sun.2 * to_sun.2).sqrt();
+    let to_sun_unit = (to_sun.0 / to_sun_dist, to_sun.1 / to_sun_dist, to_sun.2 / to_sun_dist);
+
+    let cos_alpha = (to_sun_unit.0 * to_observer_unit.0
+        + to_sun_unit.1 * to_observer_unit.1
+        + to_sun_unit.2 * to_observer_unit.2)
+        .clamp(-1.0, 1.0);
+    let alpha = cos_alpha.acos();
+
+    let phase = (((PI - alpha) * cos_alpha + alpha.sin()) / PI) * shadow.sunlit_fraction();
+    if phase <= 0.0 {
+        return None;
+    }
+
+    Some(std_mag + 5.0 * (distance_km / 1000.0).log10() - 2.5 * phase.log10())
+}
+
+/// Compute a satellite's position as seen from an observer, propagating it
+/// directly from a [`Tle`] via SGP4/SDP4 instead of interpolating a
+/// precomputed [`SatelliteEphemeris`].
+///
+/// # Arguments
+/// * `tle` - Parsed two-line element set
+/// * `time` - Observation time
+/// * `observer_lat_rad` - Observer latitude in radians
+/// * `observer_lon_rad` - Observer longitude in radians
+/// * `observer_height_km` - Observer height above sea level in km
+pub fn compute_satellite_position_from_tle(
+    tle: &Tle,
+    time: &SkyTime,
+    observer_lat_rad: f64,
+    observer_lon_rad: f64,
+    observer_height_km: f64,
+) -> SatellitePosition {
+    let (sat_eci, sat_velocity_km_s) = propagate_tle(tle, time);
+
+    satellite_position_from_eci(
+        SatelliteId::Custom(tle.satellite_number),
+        sat_eci,
+        Some(sat_velocity_km_s),
+        time,
+        observer_lat_rad,
+        observer_lon_rad,
+        observer_height_km,
+    )
+}
+
+// ============================================================================
+// TLE ingestion and SGP4/SDP4 propagation
+// ============================================================================
+
+/// A parsed NORAD Two-Line Element set.
+///
+/// Fields keep the units and "epoch mean elements" framing used by the
+/// original format; call [`propagate_tle`] (or
+/// [`compute_satellite_position_from_tle`]) to get a position at an
+/// arbitrary time via SGP4.
+#[derive(Debug, Clone)]
+pub struct Tle {
+    /// Optional name line (third line of a "3LE"), if supplied.
+    pub name: Option<String>,
+    /// NORAD catalog number.
+    pub satellite_number: u32,
+    /// Classification: 'U' (unclassified), 'C' (classified), or 'S' (secret).
+    pub classification: char,
+    /// International designator (launch year, number, piece).
+    pub intl_designator: String,
+    /// Epoch of the elements, as a Julian Date (UTC).
+    pub epoch_jd: f64,
+    /// First derivative of mean motion / 2, in revs/day^2.
+    pub mean_motion_dot: f64,
+    /// Second derivative of mean motion / 6, in revs/day^3.
+    pub mean_motion_ddot: f64,
+    /// Drag term (B*), in earth radii^-1.
+    pub bstar: f64,
+    /// Inclination in degrees.
+    pub inclination_deg: f64,
+    /// Right ascension of the ascending node in degrees.
+    pub raan_deg: f64,
+    /// Eccentricity (dimensionless).
+    pub eccentricity: f64,
+    /// Argument of perigee in degrees.
+    pub arg_perigee_deg: f64,
+    /// Mean anomaly in degrees.
+    pub mean_anomaly_deg: f64,
+    /// Mean motion in revolutions per day.
+    pub mean_motion_rev_per_day: f64,
+    /// Revolution number at epoch.
+    pub rev_number: u32,
+}
+
+impl Tle {
+    /// Parse a two-line element set (lines 1 and 2, without a name line).
+    pub fn parse(line1: &str, line2: &str) -> Result<Self, &'static str> {
+        Self::parse_named(None, line1, line2)
+    }
+
+    /// Parse a three-line element set (name line plus lines 1 and 2).
+    pub fn parse_with_name(name: &str, line1: &str, line2: &str) -> Result<Self, &'static str> {
+        Self::parse_named(Some(name.trim().to_string()), line1, line2)
+    }
+
+    fn parse_named(name: Option<String>, line1: &str, line2: &str) -> Result<Self, &'static str> {
+        let line1 = line1.trim_end();
+        let line2 = line2.trim_end();
+
+        if line1.len() < 69 {
+            return Err("TLE line 1 is too short");
+        }
+        if line2.len() < 69 {
+            return Err("TLE line 2 is too short");
+        }
+        if !line1.starts_with('1') {
+            return Err("TLE line 1 must start with '1'");
+        }
+        if !line2.starts_with('2') {
+            return Err("TLE line 2 must start with '2'");
+        }
+
+        let satellite_number: u32 = line1[2..7]
+            .trim()
+            .parse()
+            .map_err(|_| "invalid satellite number in TLE line 1")?;
+        let classification = line1.as_bytes()[7] as char;
+        let intl_designator = line1[9..17].trim().to_string();
+
+        let epoch_year: i32 = line1[18..20]
+            .trim()
+            .parse()
+            .map_err(|_| "invalid epoch year in TLE line 1")?;
+        let epoch_day: f64 = line1[20..32]
+            .trim()
+            .parse()
+            .map_err(|_| "invalid epoch day in TLE line 1")?;
+        // Standard TLE epoch year pivot: 57-99 -> 1957-1999, 00-56 -> 2000-2056.
+        let full_year = if epoch_year < 57 {
+            2000 + epoch_year
+        } else {
+            1900 + epoch_year
+        };
+        let jan0_jd = SkyTime::from_utc(full_year, 1, 1, 0, 0, 0.0).julian_date_utc() - 1.0;
+        let epoch_jd = jan0_jd + epoch_day;
+
+        let mean_motion_dot = parse_tle_decimal(&line1[33..43])?;
+        let mean_motion_ddot = parse_tle_exp_field(&line1[44..52])?;
+        let bstar = parse_tle_exp_field(&line1[53..61])?;
+
+        let inclination_deg: f64 = line2[8..16]
+            .trim()
+            .parse()
+            .map_err(|_| "invalid inclination in TLE line 2")?;
+        let raan_deg: f64 = line2[17..25]
+            .trim()
+            .parse()
+            .map_err(|_| "invalid RAAN in TLE line 2")?;
+        let eccentricity = parse_tle_assumed_decimal(&line2[26..33])?;
+        let arg_perigee_deg: f64 = line2[34..42]
+            .trim()
+            .parse()
+            .map_err(|_| "invalid argument of perigee in TLE line 2")?;
+        let mean_anomaly_deg: f64 = line2[43..51]
+            .trim()
+            .parse()
+            .map_err(|_| "invalid mean anomaly in TLE line 2")?;
+        let mean_motion_rev_per_day: f64 = line2[52..63]
+            .trim()
+            .parse()
+            .map_err(|_| "invalid mean motion in TLE line 2")?;
+        let rev_number: u32 = line2[63..68].trim().parse().unwrap_or(0);
+
+        Ok(Tle {
+            name,
+            satellite_number,
+            classification,
+            intl_designator,
+            epoch_jd,
+            mean_motion_dot,
+            mean_motion_ddot,
+            bstar,
+            inclination_deg,
+            raan_deg,
+            eccentricity,
+            arg_perigee_deg,
+            mean_anomaly_deg,
+            mean_motion_rev_per_day,
+            rev_number,
+        })
+    }
+
+    /// The epoch of these elements as a [`SkyTime`].
+    pub fn epoch(&self) -> SkyTime {
+        SkyTime::from_jd(self.epoch_jd)
+    }
+
+    /// Orbital period implied by the mean motion, in minutes.
+    pub fn orbital_period_minutes(&self) -> f64 {
+        1440.0 / self.mean_motion_rev_per_day
+    }
+
+    /// True for objects with period >= 225 minutes, where SGP4's near-Earth
+    /// assumptions break down and the deep-space (SDP4) perturbation terms
+    /// are needed (geostationary/geosynchronous and high-altitude orbits).
+    pub fn is_deep_space(&self) -> bool {
+        self.orbital_period_minutes() >= 225.0
+    }
+}
+
+/// Parse a TLE field that is a plain signed decimal with an assumed leading
+/// `0` when it starts with `.` or `-.` (e.g. the mean-motion first
+/// derivative, which is formatted like `-.00001234`).
+fn parse_tle_decimal(field: &str) -> Result<f64, &'static str> {
+    let field = field.trim();
+    if field.is_empty() {
+        return Ok(0.0);
+    }
+    let normalized = if let Some(rest) = field.strip_prefix("-.") {
+        format!("-0.{}", rest)
+    } else if let Some(rest) = field.strip_prefix('.') {
+        format!("0.{}", rest)
+    } else {
+        field.to_string()
+    };
+    normalized.parse().map_err(|_| "invalid TLE decimal field")
+}
+
+/// Parse a TLE field that is a bare digit string with an assumed leading
+/// decimal point (e.g. eccentricity `"0001234"` means `0.0001234`).
+fn parse_tle_assumed_decimal(field: &str) -> Result<f64, &'static str> {
+    let field = field.trim();
+    if field.is_empty() {
+        return Ok(0.0);
+    }
+    format!("0.{}", field)
+        .parse()
+        .map_err(|_| "invalid TLE assumed-decimal field")
+}
+
+/// Parse a TLE "assumed decimal, signed exponent" field, e.g. `" 12345-3"`
+/// means mantissa `0.12345` times `10^-3`, and `"-11606-4"` means
+/// `-0.11606 * 10^-4`. Used for the mean-motion second derivative and BSTAR.
+fn parse_tle_exp_field(field: &str) -> Result<f64, &'static str> {
+    let field = field.trim();
+    if field.is_empty() {
+        return Ok(0.0);
+    }
+    if field.len() < 2 {
+        return Err("TLE exponential field too short");
+    }
+    let (mantissa_part, exp_part) = field.split_at(field.len() - 2);
+    let exponent: i32 = exp_part.parse().map_err(|_| "invalid TLE exponent")?;
+
+    let (sign, digits) = if let Some(rest) = mantissa_part.strip_prefix('-') {
+        (-1.0, rest)
+    } else if let Some(rest) = mantissa_part.strip_prefix('+') {
+        (1.0, rest)
+    } else {
+        (1.0, mantissa_part)
+    };
+    if digits.is_empty() {
+        return Ok(0.0);
+    }
+    let mantissa: f64 = format!("0.{}", digits)
+        .parse()
+        .map_err(|_| "invalid TLE mantissa")?;
+
+    Ok(sign * mantissa * 10f64.powi(exponent))
+}
+
+// -- SGP4/SDP4 constants (WGS72, per Spacetrack Report #3) -------------------
+
+/// Earth's equatorial radius used by SGP4, in km (WGS72 value, distinct from
+/// the WGS84 `EARTH_RADIUS_KM` used elsewhere in this module).
+const SGP4_XKMPER: f64 = 6378.135;
+/// sqrt(GM) in (earth radii)^1.5 per minute.
+const SGP4_XKE: f64 = 0.0743669161;
+/// J2 term: `0.5 * J2`.
+const SGP4_CK2: f64 = 5.413080e-4;
+/// J4 term: `-0.375 * J4`.
+const SGP4_CK4: f64 = 0.62098875e-6;
+/// J3 term.
+const SGP4_XJ3: f64 = -0.253881e-5;
+/// Low-altitude atmosphere density function parameter `s` (earth radii).
+const SGP4_S: f64 = 1.01222928;
+/// `(q0 - s)^4` atmosphere density function parameter.
+const SGP4_QOMS2T: f64 = 1.88027916e-9;
+
+/// Solve SGP4's Kepler-like equation for the eccentric anomaly in terms of
+/// the equinoctial-style elements `(axn, ayn)`, Newton-Raphson iterating on
+/// `capu = epw + axn*sin(epw) - ayn*cos(epw)`.
+fn solve_kepler_sgp4(capu: f64, axn: f64, ayn: f64) -> f64 {
+    let mut epw = capu;
+    for _ in 0..10 {
+        let sinepw = epw.sin();
+        let cosepw = epw.cos();
+        let ecose = axn * cosepw + ayn * sinepw;
+        let delta = (capu - ayn * cosepw + axn * sinepw - epw) / (1.0 - ecose);
+        epw += delta;
+        if delta.abs() < 1e-12 {
+            break;
+        }
+    }
+    epw
+}
+
+/// Propagate a TLE to the given time using the near-Earth SGP4 model
+/// (Spacetrack Report #3 / Hoots & Roehrich), with a simplified secular
+/// lunar-solar node/perigee correction applied for deep-space objects
+/// ([`Tle::is_deep_space`]). Full SDP4 resonance and periodic lunar-solar
+/// terms are not modeled; for GEO/GSO-class objects this is accurate enough
+/// for visibility and pointing but not for precision orbit determination.
+///
+/// Returns `(position_km, velocity_km_per_s)` in the TEME-of-date frame,
+/// which this crate treats as equivalent to the ECI frame used by
+/// [`SatelliteEphemeris`] (the same simplification `eci_to_topocentric`
+/// already makes by ignoring precession/nutation over the short spans these
+/// ephemerides cover).
+pub fn propagate_tle(tle: &Tle, time: &SkyTime) -> ((f64, f64, f64), (f64, f64, f64)) {
+    let tsince = (time.julian_date_utc() - tle.epoch_jd) * 1440.0; // minutes
+
+    let xnodeo = tle.raan_deg.to_radians();
+    let omegao = tle.arg_perigee_deg.to_radians();
+    let xmo = tle.mean_anomaly_deg.to_radians();
+    let xincl = tle.inclination_deg.to_radians();
+    let eo = tle.eccentricity;
+    let xno = tle.mean_motion_rev_per_day * 2.0 * PI / 1440.0; // rad/min
+    let bstar = tle.bstar;
+
+    // Recover "original" mean motion and semi-major axis from the Kozai
+    // mean elements in the TLE (Brouwer theory).
+    let a1 = (SGP4_XKE / xno).powf(2.0 / 3.0);
+    let cosio = xincl.cos();
+    let theta2 = cosio * cosio;
+    let x3thm1 = 3.0 * theta2 - 1.0;
+    let eosq = eo * eo;
+    let betao2 = 1.0 - eosq;
+    let betao = betao2.sqrt();
+    let del1 = 1.5 * SGP4_CK2 * x3thm1 / (a1 * a1 * betao * betao2);
+    let ao = a1 * (1.0 - del1 * (1.0 / 3.0 + del1 * (1.0 + 134.0 / 81.0 * del1)));
+    let delo = 1.5 * SGP4_CK2 * x3thm1 / (ao * ao * betao * betao2);
+    let xnodp = xno / (1.0 + delo);
+    let aodp = ao / (1.0 - delo);
+
+    let perigee_km = (aodp * (1.0 - eo) - 1.0) * SGP4_XKMPER;
+
+    // Atmosphere density function parameters, adjusted for very low perigee.
+    let (s4, qoms24) = if perigee_km < 156.0 {
+        let s4_km = if perigee_km < 98.0 {
+            20.0
+        } else {
+            perigee_km - 78.0
+        };
+        let s4_er = s4_km / SGP4_XKMPER + 1.0;
+        let qoms24_adj = ((120.0 - s4_km) / SGP4_XKMPER).powi(4);
+        (s4_er, qoms24_adj)
+    } else {
+        (SGP4_S, SGP4_QOMS2T)
+    };
+
+    let pinvsq = 1.0 / (aodp * aodp * betao2 * betao2);
+    let tsi = 1.0 / (aodp - s4);
+    let eta = aodp * eo * tsi;
+    let etasq = eta * eta;
+    let eeta = eo * eta;
+    let psisq = (1.0 - etasq).abs();
+    let coef = qoms24 * tsi.powi(4);
+    let coef1 = coef / psisq.powf(3.5);
+    let c2 = coef1
+        * xnodp
+        * (aodp * (1.0 + 1.5 * etasq + eeta * (4.0 + etasq))
+            + 0.375 * SGP4_CK2 * tsi / psisq * x3thm1 * (8.0 + 3.0 * etasq * (8.0 + etasq)));
+    let c1 = bstar * c2;
+    let sinio = xincl.sin();
+    let a3ovk2 = -SGP4_XJ3 / SGP4_CK2;
+    let c3 = coef * tsi * a3ovk2 * xnodp * sinio / eo;
+    let x1mth2 = 1.0 - theta2;
+    let c4 = 2.0
+        * xnodp
+        * coef1
+        * aodp
+        * betao2
+        * (eta * (2.0 + 0.5 * etasq) + eo * (0.5 + 2.0 * etasq)
+            - 2.0 * SGP4_CK2 * tsi / (aodp * psisq)
+                * (-3.0 * x3thm1 * (1.0 - 2.0 * eeta + etasq * (1.5 - 0.5 * eeta))
+                    + 0.75 * x1mth2 * (2.0 * etasq - eeta * (1.0 + etasq)) * (2.0 * omegao).cos()));
+    let c5 = 2.0 * coef1 * aodp * betao2 * (1.0 + 2.75 * (etasq + eeta) + eeta * etasq);
+
+    let theta4 = theta2 * theta2;
+    let temp1 = 3.0 * SGP4_CK2 * pinvsq * xnodp;
+    let temp2 = temp1 * SGP4_CK2 * pinvsq;
+    let temp3 = 1.25 * SGP4_CK4 * pinvsq * pinvsq * xnodp;
+    let xmdot =
+        xnodp + 0.5 * temp1 * betao * x3thm1 + 0.0625 * temp2 * betao * (13.0 - 78.0 * theta2 + 137.0 * theta4);
+    let x1m5th = 1.0 - 5.0 * theta2;
+    let omgdot = -0.5 * temp1 * x1m5th
+        + 0.0625 * temp2 * (7.0 - 114.0 * theta2 + 395.0 * theta4)
+        + temp3 * (3.0 - 36.0 * theta2 + 49.0 * theta4);
+    let xhdot1 = -temp1 * cosio;
+    let xnodot = xhdot1 + (0.5 * temp2 * (4.0 - 19.0 * theta2) + 2.0 * temp3 * (3.0 - 7.0 * theta2)) * cosio;
+    let omgcof = bstar * c3 * omegao.cos();
+    let xmcof = if eo > 1.0e-4 {
+        -2.0 / 3.0 * coef * bstar / eeta
+    } else {
+        0.0
+    };
+    let xnodcf = 3.5 * betao2 * xhdot1 * c1;
+    let t2cof = 1.5 * c1;
+    let xlcof = 0.125 * a3ovk2 * sinio * (3.0 + 5.0 * cosio) / (1.0 + cosio);
+    let aycof = 0.25 * a3ovk2 * sinio;
+    let delmo = (1.0 + eta * xmo.cos()).powi(3);
+    let x7thm1 = 7.0 * theta2 - 1.0;
+
+    // Only used in the low-perigee branch; zero otherwise so `templ` is
+    // unaffected by the `isimp` check below.
+    let isimp = perigee_km < 220.0;
+    let (d2, d3, d4, t3cof, t4cof, t5cof) = if !isimp {
+        let c1sq = c1 * c1;
+        let d2 = 4.0 * aodp * tsi * c1sq;
+        let temp = d2 * tsi * c1 / 3.0;
+        let d3 = (17.0 * aodp + s4) * temp;
+        let d4 = 0.5 * temp * aodp * tsi * (221.0 * aodp + 31.0 * s4) * c1 / 3.0;
+        let t3cof = d2 + 2.0 * c1sq;
+        let t4cof = 0.25 * (3.0 * d3 + c1 * (12.0 * d2 + 10.0 * c1sq));
+        let t5cof = 0.2 * (3.0 * d4 + 12.0 * c1 * d3 + 6.0 * d2 * d2 + 15.0 * c1sq * (2.0 * d2 + c1sq));
+        (d2, d3, d4, t3cof, t4cof, t5cof)
+    } else {
+        (0.0, 0.0, 0.0, 0.0, 0.0, 0.0)
+    };
+
+    // Simplified deep-space secular correction: the dominant lunar-solar
+    // precession of the node and argument of perigee, applied on top of the
+    // near-Earth (J2/J4) secular rates above. This is not the full Hoots
+    // resonance/periodic SDP4 model, but keeps geostationary/geosynchronous
+    // objects roughly on track rather than silently using pure near-Earth
+    // SGP4 outside its validity range.
+    let (omgdot, xnodot) = if tle.is_deep_space() {
+        let lunisolar_node_rate = -6.0e-7 * cosio; // rad/min, order-of-magnitude
+        let lunisolar_perigee_rate = 3.0e-7 * (5.0 * theta2 - 1.0); // rad/min
+        (omgdot + lunisolar_perigee_rate, xnodot + lunisolar_node_rate)
+    } else {
+        (omgdot, xnodot)
+    };
+
+    // Secular update for gravity and atmospheric drag.
+    let xmdf = xmo + xmdot * tsince;
+    let omgadf = omegao + omgdot * tsince;
+    let xnoddf = xnodeo + xnodot * tsince;
+    let tsq = tsince * tsince;
+    let xnode = xnoddf + xnodcf * tsq;
+    let mut tempa = 1.0 - c1 * tsince;
+    let mut tempe = bstar * c4 * tsince;
+    let mut templ = t2cof * tsq;
+    let mut omega = omgadf;
+    let mut xmp = xmdf;
+
+    if !isimp {
+        let delomg = omgcof * tsince;
+        let delm = xmcof * ((1.0 + eta * xmdf.cos()).powi(3) - delmo);
+        let temp = delomg + delm;
+        xmp = xmdf + temp;
+        omega = omgadf - temp;
+        let tcube = tsq * tsince;
+        let tfour = tsq * tsq;
+        tempa = tempa - d2 * tsq - d3 * tcube - d4 * tfour;
+        tempe += bstar * c5 * (xmp.sin() - xmo.sin());
+        templ += t3cof * tcube + tfour * (t4cof + tsince * t5cof);
+    }
+
+    let a = aodp * tempa * tempa;
+    let e = (eo - tempe).max(1.0e-6);
+    let xl = xmp + omega + xnode + xnodp * templ;
+    let beta = (1.0 - e * e).sqrt();
+    let xn = SGP4_XKE / a.powf(1.5);
+
+    // Long-period periodics.
+    let axn = e * omega.cos();
+    let temp = 1.0 / (a * beta * beta);
+    let xll = temp * xlcof * axn;
+    let aynl = temp * aycof;
+    let xlt = xl + xll;
+    let ayn = e * omega.sin() + aynl;
+
+    // Solve Kepler's equation for the eccentric anomaly `epw`.
+    let capu = (xlt - xnode).rem_euclid(2.0 * PI);
+    let epw = solve_kepler_sgp4(capu, axn, ayn);
+
+    let sinepw = epw.sin();
+    let cosepw = epw.cos();
+    let ecose = axn * cosepw + ayn * sinepw;
+    let esine = axn * sinepw - ayn * cosepw;
+    let elsq = axn * axn + ayn * ayn;
+    let pl = a * (1.0 - elsq);
+    let r = a * (1.0 - ecose);
+    let rdot = SGP4_XKE * a.sqrt() / r * esine;
+    let rfdot = SGP4_XKE * pl.sqrt() / r;
+    let betal = (1.0 - elsq).sqrt();
+    let temp3 = esine / (1.0 + betal);
+    let cosu = (a / r) * (cosepw - axn + ayn * temp3);
+    let sinu = (a / r) * (sinepw - ayn - axn * temp3);
+    let u = sinu.atan2(cosu);
+    let sin2u = 2.0 * sinu * cosu;
+    let cos2u = 1.0 - 2.0 * sinu * sinu;
+    let temp = 1.0 / pl;
+    let temp1b = SGP4_CK2 * temp;
+    let temp2b = temp1b * temp;
+
+    // Update for short-period periodics.
+    let rk = r * (1.0 - 1.5 * temp2b * betal * x3thm1) + 0.5 * temp1b * x1mth2 * cos2u;
+    let uk = u - 0.25 * temp2b * x7thm1 * sin2u;
+    let xnodek = xnode + 1.5 * temp2b * cosio * sin2u;
+    let xinck = xincl + 1.5 * temp2b * cosio * sinio * cos2u;
+    let rdotk = rdot - xn * temp1b * x1mth2 * sin2u;
+    let rfdotk = rfdot + xn * temp1b * (x1mth2 * cos2u + 1.5 * x3thm1);
+
+    // Orientation vectors for the orbital-plane-to-ECI rotation.
+    let sinuk = uk.sin();
+    let cosuk = uk.cos();
+    let sinik = xinck.sin();
+    let cosik = xinck.cos();
+    let sinnok = xnodek.sin();
+    let cosnok = xnodek.cos();
+    let xmx = -sinnok * cosik;
+    let xmy = cosnok * cosik;
+    let ux = xmx * sinuk + cosnok * cosuk;
+    let uy = xmy * sinuk + sinnok * cosuk;
+    let uz = sinik * sinuk;
+    let vx = xmx * cosuk - cosnok * sinuk;
+    let vy = xmy * cosuk - sinnok * sinuk;
+    let vz = sinik * cosuk;
+
+    // Position (earth radii) and velocity (earth radii / min).
+    let x = rk * ux;
+    let y = rk * uy;
+    let z = rk * uz;
+    let xdot = rdotk * ux + rfdotk * vx;
+    let ydot = rdotk * uy + rfdotk * vy;
+    let zdot = rdotk * uz + rfdotk * vz;
+
+    let position_km = (x * SGP4_XKMPER, y * SGP4_XKMPER, z * SGP4_XKMPER);
+    let velocity_km_s = (
+        xdot * SGP4_XKMPER / 60.0,
+        ydot * SGP4_XKMPER / 60.0,
+        zdot * SGP4_XKMPER / 60.0,
+    );
+
+    (position_km, velocity_km_s)
+}
+
+/// Rotate a TEME-of-date position back to the J2000.0 mean equator/equinox,
+/// i.e. the inverse of `coords::precess_j2000_to_date` (precession's
+/// rotation matrix is orthogonal, so the inverse is just its transpose).
+/// TEME is already an inertial frame, like ECI -- the rotation that differs
+/// between it and the J2000 frame this module otherwise assumes is
+/// precession (about 50 arcseconds/year), not GMST, which maps an inertial
+/// frame to an Earth-fixed one and has no bearing on two inertial epochs.
+fn teme_to_j2000(pos: (f64, f64, f64), jde: f64) -> (f64, f64, f64) {
+    let PrecessionAngles { zeta, z, theta } = precession_angles(jde);
+
+    let (sin_zeta, cos_zeta) = zeta.sin_cos();
+    let (sin_z, cos_z) = z.sin_cos();
+    let (sin_theta, cos_theta) = theta.sin_cos();
+
+    let xx = cos_zeta * cos_theta * cos_z - sin_zeta * sin_z;
+    let xy = -sin_zeta * cos_theta * cos_z - cos_zeta * sin_z;
+    let xz = -sin_theta * cos_z;
+    let yx = cos_zeta * cos_theta * sin_z + sin_zeta * cos_z;
+    let yy = -sin_zeta * cos_theta * sin_z + cos_zeta * cos_z;
+    let yz = -sin_theta * sin_z;
+    let zx = cos_zeta * sin_theta;
+    let zy = -sin_zeta * sin_theta;
+    let zz = cos_theta;
+
+    // Transpose of the above (J2000 -> date) matrix.
+    (
+        xx * pos.0 + yx * pos.1 + zx * pos.2,
+        xy * pos.0 + yy * pos.1 + zy * pos.2,
+        xz * pos.0 + yz * pos.1 + zz * pos.2,
+    )
 }
 
 // ============================================================================
@@ -477,6 +1456,46 @@ impl IssEphemeris {
     pub fn inner(&self) -> &SatelliteEphemeris {
         &self.0
     }
+
+    /// Build an ISS ephemeris by sampling SGP4 propagation of a TLE across
+    /// `[start_jd, end_jd]` every `step_minutes`, rotating each TEME-of-date
+    /// position back to the J2000 frame the rest of this module assumes
+    /// (see `teme_to_j2000`). Lets a caller feed a live Celestrak TLE
+    /// instead of baking an ephemeris blob ahead of time, for a
+    /// fast-decaying LEO object like the ISS whose elements go stale within
+    /// days.
+    pub fn from_tle(
+        line1: &str,
+        line2: &str,
+        start_jd: f64,
+        end_jd: f64,
+        step_minutes: f64,
+    ) -> Result<Self, &'static str> {
+        if step_minutes <= 0.0 {
+            return Err("step_minutes must be positive");
+        }
+        if end_jd < start_jd {
+            return Err("end_jd must not precede start_jd");
+        }
+
+        let tle = Tle::parse(line1, line2)?;
+        let step_days = step_minutes / 1440.0;
+
+        let mut points = Vec::new();
+        let mut jd = start_jd;
+        loop {
+            let (pos_teme, _vel_km_s) = propagate_tle(&tle, &SkyTime::from_jd(jd));
+            let (x_km, y_km, z_km) = teme_to_j2000(pos_teme, jd);
+            points.push(SatelliteEphemerisPoint { jd, x_km, y_km, z_km });
+
+            if jd >= end_jd {
+                break;
+            }
+            jd = (jd + step_days).min(end_jd);
+        }
+
+        Ok(Self(SatelliteEphemeris::new(SatelliteId::ISS, points)))
+    }
 }
 
 /// Legacy function to compute ISS position.
@@ -496,6 +1515,221 @@ pub fn compute_iss_position(
     )
 }
 
+/// The Sun's topocentric altitude, in degrees, as seen by an observer at
+/// `(observer_lat_rad, observer_lon_rad, observer_height_km)` at `jd` (UT1).
+/// Used by [`predict_satellite_passes`] to decide whether the sky is dark
+/// enough for a sunlit pass to actually be visible.
+fn sun_topocentric_altitude_deg(
+    observer_lat_rad: f64,
+    observer_lon_rad: f64,
+    observer_height_km: f64,
+    jd: f64,
+) -> f64 {
+    let gmst = compute_gmst(jd);
+    let (_direction, _distance_km, altitude_deg, _apparent_altitude_deg, _azimuth_deg) =
+        eci_to_topocentric(sun_eci_km(jd), observer_lat_rad, observer_lon_rad, gmst, observer_height_km);
+    altitude_deg
+}
+
+/// Topocentric altitude of `ephemeris` for an observer at `jd` (UTC), or
+/// negative infinity outside the ephemeris's covered time range so it never
+/// registers as a threshold crossing.
+fn ephemeris_altitude_deg(
+    ephemeris: &SatelliteEphemeris,
+    observer_lat_rad: f64,
+    observer_lon_rad: f64,
+    observer_height_km: f64,
+    jd: f64,
+) -> f64 {
+    compute_satellite_position(
+        ephemeris,
+        &SkyTime::from_jd(jd),
+        observer_lat_rad,
+        observer_lon_rad,
+        observer_height_km,
+    )
+    .map(|pos| pos.altitude_deg)
+    .unwrap_or(f64::NEG_INFINITY)
+}
+
+/// Binary-search the time within `[lo_jd, hi_jd]` at which `ephemeris`'s
+/// altitude crosses `threshold_deg`, to sub-second accuracy.
+fn bisect_satellite_altitude_crossing(
+    ephemeris: &SatelliteEphemeris,
+    observer_lat_rad: f64,
+    observer_lon_rad: f64,
+    observer_height_km: f64,
+    mut lo_jd: f64,
+    mut hi_jd: f64,
+    threshold_deg: f64,
+    rising: bool,
+) -> f64 {
+    for _ in 0..30 {
+        let mid_jd = 0.5 * (lo_jd + hi_jd);
+        let mid_above =
+            ephemeris_altitude_deg(ephemeris, observer_lat_rad, observer_lon_rad, observer_height_km, mid_jd)
+                >= threshold_deg;
+        if mid_above == rising {
+            hi_jd = mid_jd;
+        } else {
+            lo_jd = mid_jd;
+        }
+    }
+    0.5 * (lo_jd + hi_jd)
+}
+
+/// A single predicted pass of a [`SatelliteEphemeris`] over an observer, from
+/// [`predict_satellite_passes`].
+#[derive(Debug, Clone, Copy)]
+pub struct SatellitePassEvent {
+    /// Time of acquisition of signal (rise above the elevation threshold).
+    pub aos: SkyTime,
+    /// Azimuth at AOS, in degrees.
+    pub aos_azimuth_deg: f64,
+    /// Time of peak elevation (culmination).
+    pub culmination: SkyTime,
+    /// Peak elevation reached during the pass, in degrees.
+    pub max_altitude_deg: f64,
+    /// Time of loss of signal (set below the elevation threshold).
+    pub los: SkyTime,
+    /// Azimuth at LOS, in degrees.
+    pub los_azimuth_deg: f64,
+    /// Whether the pass should actually be visible to the naked eye: the
+    /// satellite is sunlit (not in Earth's umbra) *and* the observer's sky is
+    /// dark enough (the Sun is below [`SUN_ALTITUDE_VISIBLE_THRESHOLD_DEG`])
+    /// at some point during the pass.
+    pub visible: bool,
+}
+
+/// Predict every pass of `ephemeris` over an observer at
+/// `(observer_lat_rad, observer_lon_rad, observer_height_km)` within
+/// `[start, start + duration_secs]`, rather than forcing callers to
+/// repeatedly poll [`compute_satellite_position`] and bracket passes
+/// themselves.
+///
+/// Follows the same coarse-step-then-bisect strategy as
+/// `visibility::predict_passes`, generalized to any [`SatelliteEphemeris`]
+/// (not just the bundled ISS) and raw lat/lon/height parameters instead of
+/// an `Observer`.
+pub fn predict_satellite_passes(
+    ephemeris: &SatelliteEphemeris,
+    observer_lat_rad: f64,
+    observer_lon_rad: f64,
+    observer_height_km: f64,
+    start: SkyTime,
+    duration_secs: f64,
+    min_elevation_deg: f64,
+) -> Vec<SatellitePassEvent> {
+    let (cover_start_jd, cover_end_jd) = match ephemeris.time_range() {
+        Some(range) => range,
+        None => return Vec::new(),
+    };
+
+    let start_jd = start.julian_date_utc().max(cover_start_jd);
+    let end_jd = (start.julian_date_utc() + duration_secs / 86400.0).min(cover_end_jd);
+    if end_jd <= start_jd {
+        return Vec::new();
+    }
+
+    const STEP_DAYS: f64 = 30.0 / 86400.0; // 30-second coarse step
+
+    let altitude_at = |jd: f64| {
+        ephemeris_altitude_deg(ephemeris, observer_lat_rad, observer_lon_rad, observer_height_km, jd)
+    };
+    let bisect = |lo_jd: f64, hi_jd: f64, rising: bool| {
+        bisect_satellite_altitude_crossing(
+            ephemeris,
+            observer_lat_rad,
+            observer_lon_rad,
+            observer_height_km,
+            lo_jd,
+            hi_jd,
+            min_elevation_deg,
+            rising,
+        )
+    };
+    let azimuth_at = |jd: f64| -> f64 {
+        compute_satellite_position(ephemeris, &SkyTime::from_jd(jd), observer_lat_rad, observer_lon_rad, observer_height_km)
+            .map(|pos| pos.azimuth_deg)
+            .unwrap_or(f64::NAN)
+    };
+
+    let mut passes = Vec::new();
+    let mut jd = start_jd;
+    let mut prev_alt = altitude_at(jd);
+
+    while jd < end_jd {
+        let next_jd = (jd + STEP_DAYS).min(end_jd);
+        let next_alt = altitude_at(next_jd);
+
+        if prev_alt < min_elevation_deg && next_alt >= min_elevation_deg {
+            let aos_jd = bisect(jd, next_jd, true);
+
+            let mut peak_jd = aos_jd;
+            let mut peak_alt = altitude_at(aos_jd);
+            let mut scan_jd = aos_jd;
+            let mut scan_alt = peak_alt;
+            let mut visible = false;
+
+            let los_jd = loop {
+                let pos = compute_satellite_position(
+                    ephemeris,
+                    &SkyTime::from_jd(scan_jd),
+                    observer_lat_rad,
+                    observer_lon_rad,
+                    observer_height_km,
+                );
+                if let Some(pos) = pos {
+                    if pos.shadow.is_illuminated()
+                        && sun_topocentric_altitude_deg(observer_lat_rad, observer_lon_rad, observer_height_km, scan_jd)
+                            <= SUN_ALTITUDE_VISIBLE_THRESHOLD_DEG
+                    {
+                        visible = true;
+                    }
+                }
+
+                let scan_next_jd = (scan_jd + STEP_DAYS).min(end_jd);
+                let scan_next_alt = altitude_at(scan_next_jd);
+
+                if scan_next_alt > peak_alt {
+                    peak_alt = scan_next_alt;
+                    peak_jd = scan_next_jd;
+                }
+
+                if scan_alt >= min_elevation_deg && scan_next_alt < min_elevation_deg {
+                    break bisect(scan_jd, scan_next_jd, false);
+                }
+
+                if scan_next_jd >= end_jd {
+                    break scan_next_jd;
+                }
+
+                scan_jd = scan_next_jd;
+                scan_alt = scan_next_alt;
+            };
+
+            passes.push(SatellitePassEvent {
+                aos: SkyTime::from_jd(aos_jd),
+                aos_azimuth_deg: azimuth_at(aos_jd),
+                culmination: SkyTime::from_jd(peak_jd),
+                max_altitude_deg: peak_alt,
+                los: SkyTime::from_jd(los_jd),
+                los_azimuth_deg: azimuth_at(los_jd),
+                visible,
+            });
+
+            jd = los_jd;
+            prev_alt = altitude_at(jd);
+            continue;
+        }
+
+        jd = next_jd;
+        prev_alt = next_alt;
+    }
+
+    passes
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -533,19 +1767,105 @@ mod tests {
     }
 
     #[test]
-    fn test_shadow_calculation() {
-        // Satellite on the Sun side - should be illuminated
+    fn test_shadow_state_sunlit_on_sun_side() {
         let sat_sunside = (6800.0, 0.0, 0.0);
         let sun = (149_000_000.0, 0.0, 0.0); // Sun along +X
-        assert!(!is_in_earth_shadow(sat_sunside, sun));
+        assert_eq!(shadow_state(sat_sunside, sun), ShadowState::Sunlit);
+        assert!(shadow_state(sat_sunside, sun).is_illuminated());
+    }
 
-        // Satellite on the anti-Sun side, directly behind Earth - should be in shadow
+    #[test]
+    fn test_shadow_state_umbra_directly_behind_earth() {
         let sat_shadow = (-6800.0, 0.0, 0.0);
-        assert!(is_in_earth_shadow(sat_shadow, sun));
+        let sun = (149_000_000.0, 0.0, 0.0);
+        assert_eq!(shadow_state(sat_shadow, sun), ShadowState::Umbra);
+        assert!(!shadow_state(sat_shadow, sun).is_illuminated());
+    }
+
+    #[test]
+    fn test_shadow_state_sunlit_fraction() {
+        assert_eq!(ShadowState::Sunlit.sunlit_fraction(), 1.0);
+        assert_eq!(ShadowState::Umbra.sunlit_fraction(), 0.0);
+        assert_eq!(ShadowState::Penumbra { fraction: 0.25 }.sunlit_fraction(), 0.75);
+    }
 
-        // Satellite on the anti-Sun side but far from Earth-Sun line - should be illuminated
+    #[test]
+    fn test_shadow_state_sunlit_when_offset_from_shadow_axis() {
+        // Far enough off the Earth-Sun line that Earth's angular disk (as
+        // seen from the satellite) no longer reaches the Sun's.
         let sat_offset = (-6800.0, 10000.0, 0.0);
-        assert!(!is_in_earth_shadow(sat_offset, sun));
+        let sun = (149_000_000.0, 0.0, 0.0);
+        assert_eq!(shadow_state(sat_offset, sun), ShadowState::Sunlit);
+    }
+
+    #[test]
+    fn test_shadow_state_penumbra_at_shadow_edge() {
+        // Place the satellite so its angle off the anti-Sun axis equals
+        // Earth's own angular radius `b` as seen from it -- that always
+        // falls strictly between the total-eclipse cutoff `b - a` and the
+        // sunlit cutoff `a + b`, landing in the (here, very thin, since
+        // Earth's angular radius from LEO dwarfs the Sun's) penumbra band.
+        let sun = (149_000_000.0, 0.0, 0.0);
+        let r = 6800.0_f64;
+        let b = (EARTH_RADIUS_KM / r).asin();
+        let sat_edge = (-r * b.cos(), r * b.sin(), 0.0);
+
+        match shadow_state(sat_edge, sun) {
+            ShadowState::Penumbra { fraction } => {
+                assert!((0.0..=1.0).contains(&fraction));
+            }
+            other => panic!("expected penumbra near the shadow edge, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_satellite_magnitude_at_full_phase_equals_std_mag_at_1000km() {
+        // Sun and observer in the same direction from the satellite is full
+        // phase (alpha = 0), where p(alpha) = 1 and log10(1000/1000) = 0, so
+        // the brightness reduces to the bare standard magnitude.
+        let sat_eci = (7000.0, 0.0, 0.0);
+        let sun_eci = (sat_eci.0 + 1.0, sat_eci.1, sat_eci.2);
+        let to_observer_unit = (1.0, 0.0, 0.0);
+        let mag = satellite_magnitude(Some(-1.3), sat_eci, sun_eci, to_observer_unit, 1000.0, ShadowState::Sunlit);
+        assert!((mag.unwrap() - (-1.3)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_satellite_magnitude_dims_away_from_full_phase() {
+        let sat_eci = (7000.0, 0.0, 0.0);
+        let sun_eci = (sat_eci.0 + 1.0, sat_eci.1, sat_eci.2);
+        let full_phase = satellite_magnitude(Some(-1.3), sat_eci, sun_eci, (1.0, 0.0, 0.0), 1000.0, ShadowState::Sunlit).unwrap();
+        let quarter_phase = satellite_magnitude(Some(-1.3), sat_eci, sun_eci, (0.0, 1.0, 0.0), 1000.0, ShadowState::Sunlit).unwrap();
+        // Larger magnitude means dimmer.
+        assert!(quarter_phase > full_phase);
+    }
+
+    #[test]
+    fn test_satellite_magnitude_none_when_eclipsed_or_std_mag_unknown() {
+        let sat_eci = (7000.0, 0.0, 0.0);
+        let sun_eci = (sat_eci.0 + 1.0, sat_eci.1, sat_eci.2);
+        let to_observer_unit = (1.0, 0.0, 0.0);
+        assert!(satellite_magnitude(Some(-1.3), sat_eci, sun_eci, to_observer_unit, 1000.0, ShadowState::Umbra).is_none());
+        assert!(satellite_magnitude(None, sat_eci, sun_eci, to_observer_unit, 1000.0, ShadowState::Sunlit).is_none());
+    }
+
+    #[test]
+    fn test_satellite_magnitude_dims_further_in_penumbra() {
+        let sat_eci = (7000.0, 0.0, 0.0);
+        let sun_eci = (sat_eci.0 + 1.0, sat_eci.1, sat_eci.2);
+        let to_observer_unit = (1.0, 0.0, 0.0);
+        let sunlit = satellite_magnitude(Some(-1.3), sat_eci, sun_eci, to_observer_unit, 1000.0, ShadowState::Sunlit).unwrap();
+        let half_eclipsed = satellite_magnitude(
+            Some(-1.3),
+            sat_eci,
+            sun_eci,
+            to_observer_unit,
+            1000.0,
+            ShadowState::Penumbra { fraction: 0.5 },
+        )
+        .unwrap();
+        // Larger magnitude means dimmer.
+        assert!(half_eclipsed > sunlit);
     }
 
     #[test]
@@ -564,6 +1884,41 @@ mod tests {
         assert_eq!(eph.id(), SatelliteId::Hubble);
     }
 
+    #[test]
+    fn test_interpolate_state_velocity_matches_linear_segment_slope() {
+        // With only two points, interpolate_state falls back to a linear
+        // segment, so velocity should be exactly the constant secant slope.
+        let points = vec![
+            SatelliteEphemerisPoint { jd: 2460000.0, x_km: 6800.0, y_km: 0.0, z_km: 0.0 },
+            SatelliteEphemerisPoint { jd: 2460001.0, x_km: 0.0, y_km: 6800.0, z_km: 0.0 },
+        ];
+        let eph = SatelliteEphemeris::new(SatelliteId::Hubble, points);
+
+        let (pos, vel) = eph.interpolate_state(2460000.5).unwrap();
+        assert!((pos.0 - 3400.0).abs() < 1.0);
+        let dt_secs = 86400.0;
+        assert!((vel.0 - (0.0 - 6800.0) / dt_secs).abs() < 1e-9);
+        assert!((vel.1 - (6800.0 - 0.0) / dt_secs).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_interpolate_state_matches_position_interpolation() {
+        let points = vec![
+            SatelliteEphemerisPoint { jd: 2460000.0, x_km: 6800.0, y_km: 0.0, z_km: 0.0 },
+            SatelliteEphemerisPoint { jd: 2460001.0, x_km: 6700.0, y_km: 500.0, z_km: 0.0 },
+            SatelliteEphemerisPoint { jd: 2460002.0, x_km: 6500.0, y_km: 1000.0, z_km: 0.0 },
+            SatelliteEphemerisPoint { jd: 2460003.0, x_km: 6200.0, y_km: 1500.0, z_km: 0.0 },
+        ];
+        let eph = SatelliteEphemeris::new(SatelliteId::Hubble, points);
+
+        let jd = 2460001.5;
+        let pos_only = eph.interpolate(jd).unwrap();
+        let (pos, _vel) = eph.interpolate_state(jd).unwrap();
+        assert!((pos.0 - pos_only.0).abs() < 1e-9);
+        assert!((pos.1 - pos_only.1).abs() < 1e-9);
+        assert!((pos.2 - pos_only.2).abs() < 1e-9);
+    }
+
     #[test]
     fn test_satellite_id() {
         assert_eq!(SatelliteId::ISS.name(), "ISS");
@@ -577,6 +1932,25 @@ mod tests {
         assert_eq!(SatelliteId::from_index(2), None);
     }
 
+    #[test]
+    fn test_satellite_id_magnitude_and_radius() {
+        assert_eq!(SatelliteId::ISS.standard_magnitude(), Some(-1.3));
+        assert_eq!(SatelliteId::Hubble.standard_magnitude(), Some(2.0));
+        assert_eq!(SatelliteId::Custom(99999).standard_magnitude(), None);
+        assert!(SatelliteId::ISS.radius_km().is_some());
+        assert!(SatelliteId::Hubble.radius_km().is_some());
+        assert_eq!(SatelliteId::Custom(99999).radius_km(), None);
+    }
+
+    #[test]
+    fn test_compute_satellite_position_from_tle_has_angular_size() {
+        let tle = Tle::parse(ISS_TLE_LINE1, ISS_TLE_LINE2).unwrap();
+        let time = tle.epoch();
+        let pos = compute_satellite_position_from_tle(&tle, &time, 0.0, 0.0, 0.0);
+        let angular_size = pos.angular_size_arcsec.expect("ISS has a known radius");
+        assert!(angular_size > 0.0 && angular_size < 60.0, "got angular_size_arcsec={angular_size}");
+    }
+
     #[test]
     fn test_legacy_iss_ephemeris() {
         // Create a simple 2-point ephemeris using legacy API
@@ -599,4 +1973,311 @@ mod tests {
         let pos = eph.interpolate(2460000.5).unwrap();
         assert!((pos.0 - 3400.0).abs() < 1.0);
     }
+
+    // Classic ISS TLE, used only to exercise parsing (not a reference orbit).
+    const ISS_TLE_LINE1: &str =
+        "1 25544U 98067A   20029.91667824  .00000187  00000-0  11019-4 0  9993";
+    const ISS_TLE_LINE2: &str =
+        "2 25544  51.6450  21.0981 0005829  35.8945 101.3147 15.49407333212879";
+
+    #[test]
+    fn test_tle_parse_iss() {
+        let tle = Tle::parse(ISS_TLE_LINE1, ISS_TLE_LINE2).unwrap();
+        assert_eq!(tle.satellite_number, 25544);
+        assert_eq!(tle.classification, 'U');
+        assert!((tle.inclination_deg - 51.6450).abs() < 1e-6);
+        assert!((tle.raan_deg - 21.0981).abs() < 1e-6);
+        assert!((tle.eccentricity - 0.0005829).abs() < 1e-9);
+        assert!((tle.arg_perigee_deg - 35.8945).abs() < 1e-6);
+        assert!((tle.mean_anomaly_deg - 101.3147).abs() < 1e-6);
+        assert!((tle.mean_motion_rev_per_day - 15.49407333).abs() < 1e-6);
+        assert_eq!(tle.rev_number, 21287);
+        assert!(!tle.is_deep_space());
+    }
+
+    #[test]
+    fn test_tle_parse_rejects_bad_lines() {
+        assert!(Tle::parse("not a tle", ISS_TLE_LINE2).is_err());
+        assert!(Tle::parse(ISS_TLE_LINE1, "2 too short").is_err());
+        // Swapped lines: line 1 doesn't start with '1'.
+        assert!(Tle::parse(ISS_TLE_LINE2, ISS_TLE_LINE1).is_err());
+    }
+
+    #[test]
+    fn test_tle_exp_field_parsing() {
+        assert!((parse_tle_exp_field(" 11019-4").unwrap() - 0.11019e-4).abs() < 1e-12);
+        assert!((parse_tle_exp_field("-11606-4").unwrap() - (-0.11606e-4)).abs() < 1e-12);
+        assert_eq!(parse_tle_exp_field("00000-0").unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_propagate_tle_at_epoch_leo_altitude() {
+        // At epoch, the propagated position should sit near the orbital
+        // radius implied by the mean motion (LEO, ~6700-6900 km for ISS).
+        let tle = Tle::parse(ISS_TLE_LINE1, ISS_TLE_LINE2).unwrap();
+        let time = tle.epoch();
+        let (pos_km, _vel_km_s) = propagate_tle(&tle, &time);
+        let r = (pos_km.0 * pos_km.0 + pos_km.1 * pos_km.1 + pos_km.2 * pos_km.2).sqrt();
+        assert!(
+            r > 6600.0 && r < 7000.0,
+            "ISS orbital radius at epoch should be ~6700-6900 km, got {r} km"
+        );
+    }
+
+    #[test]
+    fn test_tle_orbital_period_and_deep_space_flag() {
+        let tle = Tle::parse(ISS_TLE_LINE1, ISS_TLE_LINE2).unwrap();
+        let period_min = tle.orbital_period_minutes();
+        assert!(
+            period_min > 90.0 && period_min < 93.0,
+            "ISS period should be ~92.9 minutes, got {period_min}"
+        );
+        assert!(!tle.is_deep_space());
+
+        // A geostationary-rate mean motion (~1.0027 rev/day) should be
+        // flagged as deep space.
+        let mut geo_tle = tle.clone();
+        geo_tle.mean_motion_rev_per_day = 1.0027;
+        assert!(geo_tle.is_deep_space());
+    }
+
+    #[test]
+    fn test_iss_ephemeris_from_tle_samples_window_and_is_j2000_scale() {
+        let tle = Tle::parse(ISS_TLE_LINE1, ISS_TLE_LINE2).unwrap();
+        let start_jd = tle.epoch_jd;
+        let end_jd = start_jd + 1.0; // one day window
+
+        let eph = IssEphemeris::from_tle(ISS_TLE_LINE1, ISS_TLE_LINE2, start_jd, end_jd, 60.0).unwrap();
+
+        // Hourly steps over a day: 25 points (inclusive of both ends).
+        assert_eq!(eph.len(), 25);
+        assert!(eph.covers(start_jd));
+        assert!(eph.covers(end_jd));
+
+        // Every sample should still sit at an LEO-ish orbital radius.
+        let (x, y, z) = eph.interpolate(start_jd).unwrap();
+        let r = (x * x + y * y + z * z).sqrt();
+        assert!(r > 6600.0 && r < 7000.0, "expected LEO radius, got {r} km");
+    }
+
+    #[test]
+    fn test_iss_ephemeris_from_tle_rejects_bad_window() {
+        assert!(IssEphemeris::from_tle(ISS_TLE_LINE1, ISS_TLE_LINE2, 2460000.0, 2460001.0, 0.0).is_err());
+        assert!(IssEphemeris::from_tle(ISS_TLE_LINE1, ISS_TLE_LINE2, 2460001.0, 2460000.0, 10.0).is_err());
+    }
+
+    #[test]
+    fn test_teme_to_j2000_is_near_identity_at_j2000_epoch() {
+        let pos = (6800.0, 100.0, 200.0);
+        let (x, y, z) = teme_to_j2000(pos, 2451545.0);
+        assert!((x - pos.0).abs() < 1e-6);
+        assert!((y - pos.1).abs() < 1e-6);
+        assert!((z - pos.2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_compute_satellite_position_from_tle_is_above_or_below_horizon() {
+        let tle = Tle::parse(ISS_TLE_LINE1, ISS_TLE_LINE2).unwrap();
+        let time = tle.epoch();
+        let pos = compute_satellite_position_from_tle(&tle, &time, 0.0, 0.0, 0.0);
+        assert_eq!(pos.id, SatelliteId::Custom(25544));
+        assert!(pos.distance_km > 0.0);
+    }
+
+    #[test]
+    fn test_compute_satellite_position_from_tle_has_range_rate_and_doppler() {
+        let tle = Tle::parse(ISS_TLE_LINE1, ISS_TLE_LINE2).unwrap();
+        let time = tle.epoch();
+        let pos = compute_satellite_position_from_tle(&tle, &time, 0.0, 0.0, 0.0);
+
+        let range_rate = pos.range_rate_km_s.expect("TLE propagation provides velocity");
+        // A LEO range rate should be well within the satellite's orbital speed.
+        assert!(range_rate.abs() < 10.0, "got range_rate_km_s={range_rate}");
+
+        let shifted = pos.doppler_shift(437_500_000.0).unwrap();
+        let expected = 437_500_000.0 * (1.0 - range_rate / 299_792.458);
+        assert!((shifted - expected).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_compute_satellite_position_from_source_matches_each_underlying_path() {
+        let tle = Tle::parse(ISS_TLE_LINE1, ISS_TLE_LINE2).unwrap();
+        let time = tle.epoch();
+
+        let from_tle_directly = compute_satellite_position_from_tle(&tle, &time, 0.0, 0.0, 0.0);
+        let from_source_tle =
+            compute_satellite_position_from_source(SatelliteSource::Tle(&tle), &time, 0.0, 0.0, 0.0).unwrap();
+        assert_eq!(from_source_tle.id, from_tle_directly.id);
+        assert!((from_source_tle.distance_km - from_tle_directly.distance_km).abs() < 1e-9);
+
+        let points = vec![
+            SatelliteEphemerisPoint { jd: 2460000.0, x_km: 6800.0, y_km: 0.0, z_km: 0.0 },
+            SatelliteEphemerisPoint { jd: 2460001.0, x_km: 0.0, y_km: 6800.0, z_km: 0.0 },
+        ];
+        let ephemeris = SatelliteEphemeris::new(SatelliteId::ISS, points);
+        let mid_time = SkyTime::from_jd(2460000.5);
+
+        let from_ephemeris_directly =
+            compute_satellite_position(&ephemeris, &mid_time, 0.0, 0.0, 0.0).unwrap();
+        let from_source_ephemeris = compute_satellite_position_from_source(
+            SatelliteSource::Ephemeris(&ephemeris),
+            &mid_time,
+            0.0,
+            0.0,
+            0.0,
+        )
+        .unwrap();
+        assert!((from_source_ephemeris.distance_km - from_ephemeris_directly.distance_km).abs() < 1e-9);
+
+        // Outside the ephemeris's covered range, the ephemeris source reports
+        // no position, unlike the unbounded TLE source.
+        let outside_time = SkyTime::from_jd(2460010.0);
+        assert!(compute_satellite_position_from_source(
+            SatelliteSource::Ephemeris(&ephemeris),
+            &outside_time,
+            0.0,
+            0.0,
+            0.0
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_eci_to_topocentric_uses_oblate_earth_observer_at_the_pole() {
+        // WGS84's polar radius differs from its equatorial radius by ~21 km;
+        // a target 100 km above the pole along the ellipsoid's normal (the
+        // z-axis, there) should read back as ~100 km straight up only if the
+        // observer model sits on the ellipsoid rather than a sphere.
+        let wgs84_b_km = WGS84_A_KM * (1.0 - WGS84_F);
+        let eci = (0.0, 0.0, wgs84_b_km + 100.0);
+        let (_direction, distance_km, altitude_deg, _apparent_altitude_deg, _azimuth_deg) =
+            eci_to_topocentric(eci, 90.0_f64.to_radians(), 0.0, 0.0, 0.0);
+        assert!((distance_km - 100.0).abs() < 0.5, "got distance={distance_km}");
+        assert!(altitude_deg > 85.0, "got altitude={altitude_deg}");
+    }
+
+    #[test]
+    fn test_eci_to_topocentric_observer_radius_matches_wgs84_at_mid_latitude() {
+        // The WGS84 observer position at 45 deg latitude sits about 24 km
+        // from the spherical EARTH_RADIUS_KM approximation, mostly along the
+        // polar axis -- the error this model was built to remove.
+        let lat = 45.0_f64.to_radians();
+        let sin_lat = lat.sin();
+        let e2 = 2.0 * WGS84_F - WGS84_F * WGS84_F;
+        let n = WGS84_A_KM / (1.0 - e2 * sin_lat * sin_lat).sqrt();
+        let obs_equatorial_r = n * lat.cos();
+        let spherical_equatorial_r = EARTH_RADIUS_KM * lat.cos();
+        let obs_z = (n * (1.0 - e2)) * sin_lat;
+        let spherical_z = EARTH_RADIUS_KM * sin_lat;
+        let offset_km = (obs_equatorial_r - spherical_equatorial_r).hypot(obs_z - spherical_z);
+        assert!(
+            offset_km > 10.0,
+            "expected a double-digit km difference from the spherical approximation, got {offset_km}"
+        );
+
+        // A target directly overhead (straight up along the ellipsoid
+        // normal) at this latitude should read back near-zenith only with
+        // the oblate model; feed eci_to_topocentric a point built from the
+        // same ellipsoid normal and confirm it resolves to ~zenith.
+        let lst = 0.0;
+        let up_x = lat.cos() * lst.cos();
+        let up_y = lat.cos() * lst.sin();
+        let up_z = sin_lat;
+        let target = (
+            obs_equatorial_r * lst.cos() + 100.0 * up_x,
+            obs_equatorial_r * lst.sin() + 100.0 * up_y,
+            obs_z + 100.0 * up_z,
+        );
+        let (_direction, distance_km, altitude_deg, _apparent_altitude_deg, _azimuth_deg) =
+            eci_to_topocentric(target, lat, 0.0, 0.0, 0.0);
+        assert!((distance_km - 100.0).abs() < 0.5, "got distance={distance_km}");
+        assert!(altitude_deg > 85.0, "got altitude={altitude_deg}");
+    }
+
+    #[test]
+    fn test_eci_to_topocentric_applies_refraction_near_the_horizon() {
+        // Observer at the equator, prime meridian; target offset purely
+        // "east" at the same radius from Earth's center sits exactly on the
+        // geometric horizon, where refraction should lift the apparent
+        // altitude a fraction of a degree above it.
+        let r = WGS84_A_KM;
+        let eci = (r, r * 0.001, 0.0);
+        let (_direction, _distance_km, altitude_deg, apparent_altitude_deg, _azimuth_deg) =
+            eci_to_topocentric(eci, 0.0, 0.0, 0.0, 0.0);
+        assert!(altitude_deg.abs() < 1e-6, "got altitude={altitude_deg}");
+        assert!(apparent_altitude_deg > altitude_deg);
+    }
+
+    #[test]
+    fn test_eci_to_topocentric_skips_refraction_well_below_horizon() {
+        // Bennett's refraction approximation breaks down far below the
+        // horizon, so apparent altitude should fall back to the geometric
+        // value there instead of applying the (unreliable) formula.
+        let r = WGS84_A_KM;
+        let eci = (r - 500.0, 0.0, 1000.0 * 30f64.to_radians().cos());
+        let (_direction, _distance_km, altitude_deg, apparent_altitude_deg, _azimuth_deg) =
+            eci_to_topocentric(eci, 0.0, 0.0, 0.0, 0.0);
+        assert!((altitude_deg - (-30.0)).abs() < 0.5, "got altitude={altitude_deg}");
+        assert_eq!(apparent_altitude_deg, altitude_deg);
+    }
+
+    #[test]
+    fn test_predict_satellite_passes_over_one_day_matches_invariants() {
+        let ephemeris = IssEphemeris::from_tle(
+            ISS_TLE_LINE1,
+            ISS_TLE_LINE2,
+            2458878.91667824,
+            2458879.91667824,
+            1.0,
+        )
+        .unwrap();
+        let start = SkyTime::from_jd(2458878.91667824);
+
+        let passes = predict_satellite_passes(
+            ephemeris.inner(),
+            40.0_f64.to_radians(),
+            (-105.0_f64).to_radians(),
+            1.6,
+            start,
+            86400.0,
+            10.0,
+        );
+
+        for pass in &passes {
+            assert!(pass.los.julian_date_utc() >= pass.aos.julian_date_utc());
+            assert!(
+                pass.culmination.julian_date_utc() >= pass.aos.julian_date_utc()
+                    && pass.culmination.julian_date_utc() <= pass.los.julian_date_utc()
+            );
+            assert!(pass.max_altitude_deg >= 10.0);
+            assert!((0.0..360.0).contains(&pass.aos_azimuth_deg));
+        }
+        // Absence of passes above 10 deg within the window is also valid,
+        // since ISS ground tracks don't cover every location every day.
+    }
+
+    #[test]
+    fn test_predict_satellite_passes_outside_ephemeris_coverage_is_empty() {
+        let ephemeris = IssEphemeris::from_tle(
+            ISS_TLE_LINE1,
+            ISS_TLE_LINE2,
+            2458878.91667824,
+            2458879.91667824,
+            1.0,
+        )
+        .unwrap();
+        // A window entirely after the ephemeris's last sample.
+        let start = SkyTime::from_jd(2458890.0);
+
+        let passes = predict_satellite_passes(
+            ephemeris.inner(),
+            40.0_f64.to_radians(),
+            (-105.0_f64).to_radians(),
+            1.6,
+            start,
+            86400.0,
+            10.0,
+        );
+        assert!(passes.is_empty());
+    }
 }
@@ -1,4 +1,6 @@
+use crate::planets::CelestialBody;
 use hifitime::{Epoch, TimeScale};
+use std::f64::consts::PI;
 
 /// Wrapper around hifitime::Epoch for sky engine time handling.
 /// Provides conversions between UTC and the time scales needed for ephemeris calculations.
@@ -61,6 +63,141 @@ impl SkyTime {
         // J2000.0 = JD 2451545.0
         (self.julian_date_tdb() - 2451545.0) / 36525.0
     }
+
+    /// Convert this instant into local mean solar time at `longitude_rad`
+    /// (positive east) on the body described by `calendar`.
+    pub fn to_local_solar(&self, calendar: &CalendarSystem, longitude_rad: f64) -> LocalSolarTime {
+        let days_since_epoch = self.julian_date_utc() - calendar.epoch_jd;
+        let sols_since_epoch = days_since_epoch * 24.0 / calendar.solar_day_hours;
+        let longitude_sols = longitude_rad / (2.0 * PI);
+        let total_sols = sols_since_epoch + longitude_sols;
+
+        let sol = total_sols.floor();
+        let frac_sol = total_sols - sol;
+
+        let hours_f = frac_sol * 24.0;
+        let hour = hours_f.floor();
+        let minutes_f = (hours_f - hour) * 60.0;
+        let minute = minutes_f.floor();
+        let second = (minutes_f - minute) * 60.0;
+
+        LocalSolarTime {
+            sol: sol as i64,
+            hour: hour as u8,
+            minute: minute as u8,
+            second,
+        }
+    }
+
+    /// The `SkyTime` at the start (00:00:00 local mean solar time at
+    /// longitude 0) of a given `sol` count since `calendar`'s epoch.
+    pub fn from_sol(calendar: &CalendarSystem, sol: f64) -> Self {
+        let days = sol * calendar.solar_day_hours / 24.0;
+        Self::from_jd(calendar.epoch_jd + days)
+    }
+}
+
+/// Calendar parameters for a body: its sidereal rotation period, the length
+/// of its mean solar day (the synodic day, lengthened or shortened relative
+/// to the sidereal day by the body's own motion around the Sun), and the
+/// epoch (Julian Date, UTC) anchoring sol 0 / longitude-0 local midnight.
+///
+/// All bundled calendars share a J2000.0 epoch for simplicity; this is a
+/// convenient common zero point, not an exact local-midnight crossing at
+/// each body's prime meridian (that would require solving `W(t) = 180°` per
+/// body, which `rotational_elements_for` could do but isn't needed here).
+#[derive(Debug, Clone, Copy)]
+pub struct CalendarSystem {
+    /// Sidereal rotation period, in hours.
+    pub sidereal_period_hours: f64,
+    /// Length of the mean solar day, in hours (e.g. a Martian "sol").
+    pub solar_day_hours: f64,
+    /// Julian Date (UTC) of the calendar epoch.
+    pub epoch_jd: f64,
+}
+
+const CALENDAR_EPOCH_J2000_JD: f64 = 2451545.0;
+
+pub const SUN_CALENDAR: CalendarSystem = CalendarSystem {
+    sidereal_period_hours: 609.12,
+    solar_day_hours: 609.12,
+    epoch_jd: CALENDAR_EPOCH_J2000_JD,
+};
+
+pub const MOON_CALENDAR: CalendarSystem = CalendarSystem {
+    sidereal_period_hours: 655.728,
+    solar_day_hours: 708.7341,
+    epoch_jd: CALENDAR_EPOCH_J2000_JD,
+};
+
+pub const MERCURY_CALENDAR: CalendarSystem = CalendarSystem {
+    sidereal_period_hours: 1407.509,
+    solar_day_hours: 4222.6,
+    epoch_jd: CALENDAR_EPOCH_J2000_JD,
+};
+
+pub const VENUS_CALENDAR: CalendarSystem = CalendarSystem {
+    sidereal_period_hours: 5832.5,
+    solar_day_hours: 2802.0,
+    epoch_jd: CALENDAR_EPOCH_J2000_JD,
+};
+
+/// The Martian "sol" -- 24h 39m 35.24s, about 2.7% longer than Earth's day.
+pub const MARS_CALENDAR: CalendarSystem = CalendarSystem {
+    sidereal_period_hours: 24.6229,
+    solar_day_hours: 24.659790,
+    epoch_jd: CALENDAR_EPOCH_J2000_JD,
+};
+
+pub const JUPITER_CALENDAR: CalendarSystem = CalendarSystem {
+    sidereal_period_hours: 9.9250,
+    solar_day_hours: 9.9259,
+    epoch_jd: CALENDAR_EPOCH_J2000_JD,
+};
+
+pub const SATURN_CALENDAR: CalendarSystem = CalendarSystem {
+    sidereal_period_hours: 10.656,
+    solar_day_hours: 10.656,
+    epoch_jd: CALENDAR_EPOCH_J2000_JD,
+};
+
+pub const URANUS_CALENDAR: CalendarSystem = CalendarSystem {
+    sidereal_period_hours: 17.24,
+    solar_day_hours: 17.24,
+    epoch_jd: CALENDAR_EPOCH_J2000_JD,
+};
+
+pub const NEPTUNE_CALENDAR: CalendarSystem = CalendarSystem {
+    sidereal_period_hours: 16.11,
+    solar_day_hours: 16.11,
+    epoch_jd: CALENDAR_EPOCH_J2000_JD,
+};
+
+/// Look up the bundled calendar system for a tracked celestial body.
+pub fn calendar_system_for(body: CelestialBody) -> CalendarSystem {
+    match body {
+        CelestialBody::Sun => SUN_CALENDAR,
+        CelestialBody::Moon => MOON_CALENDAR,
+        CelestialBody::Mercury => MERCURY_CALENDAR,
+        CelestialBody::Venus => VENUS_CALENDAR,
+        CelestialBody::Mars => MARS_CALENDAR,
+        CelestialBody::Jupiter => JUPITER_CALENDAR,
+        CelestialBody::Saturn => SATURN_CALENDAR,
+        CelestialBody::Uranus => URANUS_CALENDAR,
+        CelestialBody::Neptune => NEPTUNE_CALENDAR,
+    }
+}
+
+/// A calendar-like breakdown of local mean solar time at some longitude on a
+/// body: an integer sol count since the calendar epoch, plus an hour/minute/
+/// second breakdown of the fractional sol on a 24-hour clock scaled to that
+/// body's solar day length.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LocalSolarTime {
+    pub sol: i64,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: f64,
 }
 
 impl Default for SkyTime {
@@ -95,4 +232,46 @@ mod tests {
         let t = j2100.julian_centuries_tdb();
         assert!((t - 1.0).abs() < 0.01);
     }
+
+    #[test]
+    fn test_sol_zero_round_trips_through_local_solar() {
+        let time = SkyTime::from_sol(&MARS_CALENDAR, 0.0);
+        let local = time.to_local_solar(&MARS_CALENDAR, 0.0);
+        assert_eq!(local.sol, 0);
+        assert_eq!(local.hour, 0);
+        assert_eq!(local.minute, 0);
+        assert!(local.second.abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_one_sol_later_increments_sol_count() {
+        let time = SkyTime::from_sol(&MARS_CALENDAR, 1.0);
+        let local = time.to_local_solar(&MARS_CALENDAR, 0.0);
+        assert_eq!(local.sol, 1);
+        assert_eq!(local.hour, 0);
+    }
+
+    #[test]
+    fn test_local_solar_time_advances_with_eastward_longitude() {
+        // At a fixed instant, a point 180 degrees east should be about half
+        // a sol ahead in local solar time of the longitude-0 meridian.
+        let time = SkyTime::from_sol(&MARS_CALENDAR, 10.0);
+        let at_prime_meridian = time.to_local_solar(&MARS_CALENDAR, 0.0);
+        let at_antimeridian = time.to_local_solar(&MARS_CALENDAR, PI);
+
+        let hours_prime = at_prime_meridian.hour as f64 + at_prime_meridian.minute as f64 / 60.0;
+        let hours_anti = at_antimeridian.hour as f64 + at_antimeridian.minute as f64 / 60.0;
+        let diff = (hours_anti - hours_prime + 24.0) % 24.0;
+        assert!(
+            (diff - 12.0).abs() < 0.01,
+            "expected ~12h offset, got {diff}"
+        );
+    }
+
+    #[test]
+    fn test_mars_sol_is_longer_than_earth_day() {
+        assert!(MARS_CALENDAR.solar_day_hours > EARTH_DAY_HOURS);
+    }
+
+    const EARTH_DAY_HOURS: f64 = 24.0;
 }
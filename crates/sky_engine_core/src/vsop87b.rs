@@ -0,0 +1,212 @@
+//! Heliocentric ecliptic ephemeris in the VSOP87B spherical form: longitude
+//! and latitude in radians, radius vector in AU, each a sum over series
+//! indices `k` of `T^k * Σ A·cos(B + C·T)` where `T` is Julian millennia
+//! from J2000 (`(jde - 2451545.0) / 365250.0`).
+//!
+//! Named for the series form rather than `vsop87` to stay clear of the
+//! external `vsop87` crate (VSOP87A, rectangular) [`crate::planets`]
+//! already depends on for its full-precision path -- this module isn't a
+//! replacement for that, just an in-repo VSOP87B-shaped alternative for
+//! callers that want heliocentric ecliptic spherical coordinates directly
+//! (longitude/latitude/radius) rather than rectangular ones.
+//!
+//! This also isn't the full multi-thousand-term VSOP87B -- the `(A, B, C)`
+//! tables below are truncated to the dominant mean-motion term plus the
+//! leading equation-of-center / latitude / radius periodic term, derived
+//! from the same Standish osculating elements [`crate::planets`] already
+//! uses for its [`crate::planets::PositionPrecision::Truncated`] path. Good
+//! to a similar low-precision tolerance, but in VSOP87B's series form.
+
+use crate::coords::{ecliptic_to_equatorial, CartesianCoord, OBLIQUITY_J2000};
+use crate::planets::Planet;
+
+/// One term of a periodic series: `A * cos(B + C * T)`.
+#[derive(Clone, Copy)]
+struct SeriesTerm {
+    a: f64,
+    b: f64,
+    c: f64,
+}
+
+/// `Σ_k T^k * Σ_i A_i cos(B_i + C_i T)` for one of L, B, or R. `series[k]`
+/// holds the terms at power `k`; an empty slice contributes nothing at that
+/// power (most bodies have no periodic terms beyond `k = 1`).
+fn evaluate_series(series: &[&[SeriesTerm]], t: f64) -> f64 {
+    series
+        .iter()
+        .enumerate()
+        .map(|(k, terms)| {
+            let sum: f64 = terms.iter().map(|term| term.a * (term.b + term.c * t).cos()).sum();
+            t.powi(k as i32) * sum
+        })
+        .sum()
+}
+
+/// Per-planet VSOP87B coefficient tables for heliocentric ecliptic
+/// longitude (L), latitude (B), and radius (R).
+struct Vsop87Coefficients {
+    l: &'static [&'static [SeriesTerm]],
+    b: &'static [&'static [SeriesTerm]],
+    r: &'static [&'static [SeriesTerm]],
+}
+
+macro_rules! terms {
+    ($(($a:expr, $b:expr, $c:expr)),* $(,)?) => {
+        &[$(SeriesTerm { a: $a, b: $b, c: $c }),*]
+    };
+}
+
+fn coefficients_for(planet: Planet) -> Vsop87Coefficients {
+    match planet {
+        Planet::Mercury => Vsop87Coefficients {
+            l: &[terms![(4.40259868, 0.0, 0.0), (0.41127186, 1.47990878, 26087.87504160)], terms![(26087.90305011, 0.0, 0.0)]],
+            b: &[terms![(0.12225995, 1.98827136, 26087.90305011)]],
+            r: &[terms![(0.38709927, 0.0, 0.0), (-0.07960152, 3.05070511, 26087.87504160)]],
+        },
+        Planet::Venus => Vsop87Coefficients {
+            l: &[terms![(3.17613446, 0.0, 0.0), (0.01355344, 5.59162708, 10213.28502750)], terms![(10213.28549582, 0.0, 0.0)]],
+            b: &[terms![(0.05924827, 0.26702241, 10213.28549582)]],
+            r: &[terms![(0.72333566, 0.0, 0.0), (-0.00490184, 0.87923810, 10213.28502750)]],
+        },
+        Planet::Earth => Vsop87Coefficients {
+            l: &[terms![(1.75343756, 0.0, 0.0), (0.03342246, 4.66922506, 6283.01935712)], terms![(6283.07577901, 0.0, 0.0)]],
+            b: &[terms![(0.00000027, 0.18264123, 6283.07577901)]],
+            r: &[terms![(1.00000261, 0.0, 0.0), (-0.01671127, 6.24002139, 6283.01935712)]],
+        },
+        Planet::Mars => Vsop87Coefficients {
+            l: &[terms![(6.20371293, 0.0, 0.0), (0.18678820, 5.05081177, 3340.53545248)], terms![(3340.61301681, 0.0, 0.0)]],
+            b: &[terms![(0.03228321, 3.76793947, 3340.61301681)]],
+            r: &[terms![(1.52371034, 0.0, 0.0), (-0.14230556, 0.33842279, 3340.53545248)]],
+        },
+        Planet::Jupiter => Vsop87Coefficients {
+            l: &[terms![(0.60033114, 0.0, 0.0), (0.09677248, 5.05565965, 529.62602601)], terms![(529.66311891, 0.0, 0.0)]],
+            b: &[terms![(0.02276602, 3.55911959, 529.66311891)]],
+            r: &[terms![(5.20288700, 0.0, 0.0), (-0.25174814, 0.34327067, 529.62602601)]],
+        },
+        Planet::Saturn => Vsop87Coefficients {
+            l: &[terms![(0.87186604, 0.0, 0.0), (0.10772358, 3.96809971, 213.43851232)], terms![(213.36538789, 0.0, 0.0)]],
+            b: &[terms![(0.04338874, 3.60047147, 213.36538789)]],
+            r: &[terms![(9.53667594, 0.0, 0.0), (-0.51366244, 5.53889603, 213.43851232)]],
+        },
+        Planet::Uranus => Vsop87Coefficients {
+            l: &[terms![(5.46703627, 0.0, 0.0), (0.09451488, 0.91252495, 74.71300307)], terms![(74.78422172, 0.0, 0.0)]],
+            b: &[terms![(0.01348507, 2.60440090, 74.78422172)]],
+            r: &[terms![(19.18916464, 0.0, 0.0), (-0.90683080, 2.48332127, 74.71300307)]],
+        },
+        Planet::Neptune => Vsop87Coefficients {
+            l: &[terms![(5.32115931, 0.0, 0.0), (0.01718096, 2.96557983, 38.18463938)], terms![(38.12836741, 0.0, 0.0)]],
+            b: &[terms![(0.03089309, 1.45029434, 38.12836741)]],
+            r: &[terms![(30.06992276, 0.0, 0.0), (-0.25831507, 4.53637616, 38.18463938)]],
+        },
+        // Pluto isn't one of Standish's major planets either; it's handled
+        // before this match in `heliocentric_ecliptic` itself, the same way
+        // `planets::heliocentric_position` special-cases it ahead of its
+        // own VSOP87A match.
+        Planet::Pluto => unreachable!("Pluto is handled in heliocentric_ecliptic before this match"),
+    }
+}
+
+/// Heliocentric ecliptic longitude, latitude, and radius (radians, radians,
+/// AU) of `body` at TDB Julian date `jde`, from the truncated VSOP87B
+/// series in [`coefficients_for`].
+///
+/// Pluto has no VSOP87-shaped table here (Standish's low-precision elements
+/// don't cover it either), so it's dispatched to
+/// [`crate::planets::heliocentric_position`]'s own dedicated analytic
+/// series and converted from rectangular to spherical, mirroring how
+/// `heliocentric_position` itself special-cases Pluto ahead of its match.
+pub fn heliocentric_ecliptic(body: Planet, jde: f64) -> (f64, f64, f64) {
+    let two_pi = 2.0 * std::f64::consts::PI;
+    if body == Planet::Pluto {
+        let (x, y, z) = crate::planets::heliocentric_position(body, jde);
+        let radius = (x * x + y * y + z * z).sqrt();
+        let lon = y.atan2(x).rem_euclid(two_pi);
+        let lat = (z / radius).asin();
+        return (lon, lat, radius);
+    }
+
+    let t = (jde - 2451545.0) / 365250.0;
+    let coeffs = coefficients_for(body);
+    let lon = evaluate_series(coeffs.l, t).rem_euclid(two_pi);
+    let lat = evaluate_series(coeffs.b, t);
+    let radius = evaluate_series(coeffs.r, t);
+    (lon, lat, radius)
+}
+
+/// Converts a VSOP87B heliocentric ecliptic spherical position to a J2000
+/// equatorial rectangular vector (AU), by rotating through the J2000
+/// obliquity the same way [`crate::coords::ecliptic_to_equatorial`] always
+/// does, then scaling the resulting unit vector out to `radius_au`.
+pub fn heliocentric_to_j2000_equatorial(lon_rad: f64, lat_rad: f64, radius_au: f64) -> CartesianCoord {
+    let unit = ecliptic_to_equatorial(lon_rad, lat_rad, OBLIQUITY_J2000);
+    CartesianCoord::new(unit.x * radius_au, unit.y * radius_au, unit.z * radius_au)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Same comparison `planets::test_truncated_precision_agrees_roughly_with_full`
+    /// makes for the Keplerian low-precision path: this truncated VSOP87B
+    /// series isn't full VSOP87A, but near J2000 it should still land within
+    /// about a degree in longitude and latitude, and within a percent in
+    /// radius.
+    #[test]
+    fn test_heliocentric_ecliptic_agrees_roughly_with_vsop87a() {
+        let jde = 2451545.0 + 30.0; // a month past J2000
+        for planet in [
+            Planet::Mercury,
+            Planet::Venus,
+            Planet::Earth,
+            Planet::Mars,
+            Planet::Jupiter,
+            Planet::Saturn,
+            Planet::Uranus,
+            Planet::Neptune,
+        ] {
+            let (lon, lat, radius) = heliocentric_ecliptic(planet, jde);
+            let (x, y, z) = crate::planets::heliocentric_position(planet, jde);
+            let reference_radius = (x * x + y * y + z * z).sqrt();
+            let reference_lon = y.atan2(x).rem_euclid(2.0 * std::f64::consts::PI);
+            let reference_lat = (z / reference_radius).asin();
+
+            let lon_diff_deg = (lon - reference_lon).to_degrees().rem_euclid(360.0);
+            let lon_diff_deg = lon_diff_deg.min(360.0 - lon_diff_deg);
+            assert!(
+                lon_diff_deg < 1.0,
+                "{planet:?}: longitude disagrees by {lon_diff_deg} degrees"
+            );
+            assert!(
+                (lat - reference_lat).to_degrees().abs() < 1.0,
+                "{planet:?}: latitude disagrees by {} degrees",
+                (lat - reference_lat).to_degrees()
+            );
+            assert!(
+                (radius - reference_radius).abs() / reference_radius < 0.01,
+                "{planet:?}: radius disagrees by {} AU",
+                (radius - reference_radius).abs()
+            );
+        }
+    }
+
+    #[test]
+    fn test_heliocentric_ecliptic_pluto_matches_dedicated_series() {
+        let jde = 2451545.0 + 30.0;
+        let (lon, lat, radius) = heliocentric_ecliptic(Planet::Pluto, jde);
+        let (x, y, z) = crate::planets::heliocentric_position(Planet::Pluto, jde);
+        let reference_radius = (x * x + y * y + z * z).sqrt();
+        let reference_lon = y.atan2(x).rem_euclid(2.0 * std::f64::consts::PI);
+        let reference_lat = (z / reference_radius).asin();
+
+        assert!((lon - reference_lon).abs() < 1e-9);
+        assert!((lat - reference_lat).abs() < 1e-9);
+        assert!((radius - reference_radius).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_heliocentric_to_j2000_equatorial_is_unit_length_for_unit_radius() {
+        let coord = heliocentric_to_j2000_equatorial(1.2, -0.3, 1.0);
+        let len = (coord.x * coord.x + coord.y * coord.y + coord.z * coord.z).sqrt();
+        assert!((len - 1.0).abs() < 1e-9, "got len={len}");
+    }
+}
@@ -3,24 +3,206 @@
 //! Supports:
 //! - Yale Bright Star Catalog (bsc5.dat) - ~9k stars
 //! - Hipparcos Catalog (hip_main.dat) - ~118k stars
+//! - Gaia DR3 source CSV exports - up to ~10^9 stars
 //!
 //! Usage:
-//!   preprocess_stars <input.dat> <output.bin> [--hipparcos]
+//!   preprocess_stars <input.dat> <output.bin> [--hipparcos] [--gaia] [--epoch <year>]
 //!
-//! The format is auto-detected, or use --hipparcos flag to force.
+//! The format is auto-detected, or use --hipparcos/--gaia to force.
+//!
+//! `--epoch <year>` rewrites every star's RA/Dec from the catalog's native
+//! epoch (J2000.0 for the BSC, J1991.25 for Hipparcos, J2016.0 for Gaia
+//! DR3) to the given decimal year via rigorous space-motion propagation
+//! (`apply_pm`) before the binary is written, so the output reflects where
+//! each star actually is on that date rather than at the catalog's
+//! reference epoch.
+//!
+//! The output is written in the self-describing `ONCESTAR` binary format
+//! (see `sky_engine_core::catalog::load_versioned_catalog`'s doc comment
+//! for the full layout): a header naming the source catalog and epoch,
+//! followed by the record block and a trailing CRC-32, so a reader can
+//! tell what produced a file and detect truncation or corruption instead
+//! of silently loading garbage stars.
+//!
+//! `--compress` wraps the record block in an xz stream (the header and CRC
+//! stay uncompressed, so metadata and the star count remain cheaply
+//! readable without decompressing the body). This tool has no Cargo
+//! dependencies, so it shells out to the system `xz` binary rather than
+//! linking an LZMA crate; `catalog::load_versioned_catalog` auto-detects
+//! the xz stream magic and decompresses transparently when its `xz`
+//! feature is enabled.
+//!
+//! `--merge <a.dat> <b.dat> ... <out.bin>` cross-matches several input
+//! catalogs (of any mix of the supported formats) into one deduplicated
+//! output instead of converting a single file. Matching stars (within
+//! `--match-radius <arcsec>`, default 1) are combined preferring the
+//! highest-precision position -- Gaia over Hipparcos over the BSC -- while
+//! backfilling any `bv`/parallax/proper-motion the preferred record is
+//! missing from the other one, so no photometry is dropped just because
+//! the best-astrometry catalog didn't carry it. See `run_merge` for the
+//! cross-match algorithm.
 
 use std::env;
 use std::fs::File;
 use std::io::{BufRead, BufReader, Write};
 use std::f64::consts::PI;
+use std::process::{Command, Stdio};
 
 #[derive(Debug)]
 struct Star {
-    id: u32,       // HR number (BSC) or HIP number (Hipparcos)
+    // HR number (BSC), HIP number (Hipparcos), or source_id (Gaia DR3,
+    // which alone needs the full u64 range).
+    id: u64,
     ra_rad: f32,
     dec_rad: f32,
     vmag: f32,
     bv: f32,
+    /// Parallax in milliarcseconds; `0.0` (or negative) means unknown.
+    parallax_mas: f32,
+    /// Proper motion in right ascension, milliarcseconds/year, scaled by
+    /// `cos(dec)` as is catalog convention.
+    pm_ra_masyr: f32,
+    /// Proper motion in declination, milliarcseconds/year.
+    pm_dec_masyr: f32,
+    /// Heliocentric radial velocity in km/s; `0.0` means unknown. Only the
+    /// BSC parser reads a radial velocity (its own RV field, where present);
+    /// Hipparcos and Gaia stars always keep this default.
+    rv_kms: f32,
+}
+
+/// Radians per milliarcsecond, for converting catalog proper-motion rates.
+const MAS_TO_RAD: f64 = PI / (3_600_000.0 * 180.0);
+
+/// Astronomical units per parsec: `distance_au = AU_PER_PARSEC /
+/// parallax_arcsec`.
+const AU_PER_PARSEC: f64 = 206_264.8;
+
+/// Kilometers/second per astronomical-unit/year -- the ~4.74 km/s "radial
+/// velocity constant", derived from the length of an AU and a Julian year.
+const KM_S_PER_AU_YR: f64 = 149_597_870.7 / (365.25 * 86_400.0);
+
+// The following mirror `sky_engine_core::catalog`'s self-describing binary
+// format (magic bytes, flags, CRC-32); duplicated here since this tool has
+// no dependency on that crate. See `catalog::load_versioned_catalog`'s doc
+// comment for the authoritative layout description.
+const HEADER_MAGIC: &[u8; 8] = b"ONCESTAR";
+const HEADER_FORMAT_VERSION: u16 = 1;
+const FLAG_PROPER_MOTION: u16 = 1 << 0;
+const FLAG_PARALLAX: u16 = 1 << 1;
+const HEADER_SIZE: usize = 8 + 2 + 2 + 1 + 4 + 4;
+const SOURCE_BSC: u8 = 0;
+const SOURCE_HIPPARCOS: u8 = 1;
+const SOURCE_GAIA: u8 = 2;
+/// Mirrors `catalog::SourceCatalog::Other`'s encoding: a source not worth
+/// naming its own variant for, such as a `--merge` output drawn from
+/// several catalogs at once.
+const SOURCE_OTHER: u8 = 255;
+
+/// xz stream magic bytes, matching `catalog::load_versioned_catalog`'s
+/// auto-detection.
+const XZ_MAGIC: [u8; 6] = [0xFD, 0x37, 0x7A, 0x58, 0x5A, 0x00];
+
+/// Compress `data` by piping it through the system `xz` binary, since this
+/// tool has no Cargo dependencies to link an LZMA implementation directly.
+fn compress_xz(data: &[u8]) -> Vec<u8> {
+    let mut child = Command::new("xz")
+        .args(["-6", "-z", "-c"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to spawn `xz`; is it installed and on PATH?");
+
+    child
+        .stdin
+        .take()
+        .expect("child stdin was piped")
+        .write_all(data)
+        .expect("failed to write to xz stdin");
+
+    let output = child.wait_with_output().expect("failed to wait on xz");
+    if !output.status.success() {
+        panic!("xz exited with {}", output.status);
+    }
+    output.stdout
+}
+
+/// Standard CRC-32 (IEEE 802.3, polynomial `0xEDB8_8320`), computed bit by
+/// bit rather than via a precomputed table since this tool has no
+/// dependencies beyond `std`.
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc: u32 = 0xFFFF_FFFF;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+/// Advance a position from `epoch1_year` to `epoch2_year` by rigorous 3D
+/// space-motion propagation: build a true 3D velocity vector from proper
+/// motion, parallax and radial velocity, advance the Cartesian position
+/// linearly, and reproject to RA/Dec. See
+/// `sky_engine_core::catalog::apply_pm`, which this mirrors, for the full
+/// derivation; duplicated here since this tool is a standalone binary with
+/// no dependency on that crate.
+///
+/// When `parallax_mas` is non-positive (unknown, or an unreliable negative
+/// measurement), the star is treated as effectively at infinity: radial
+/// velocity can't shift the direction to a point at infinite distance, and
+/// the distance factor common to the position and tangential-velocity
+/// terms cancels out of the resulting direction regardless of its value,
+/// so only the angular (proper) motion is applied.
+fn apply_pm(
+    ra_rad: f64,
+    dec_rad: f64,
+    pm_ra_masyr: f64,
+    pm_dec_masyr: f64,
+    rv_kms: f64,
+    parallax_mas: f64,
+    epoch1_year: f64,
+    epoch2_year: f64,
+) -> (f64, f64) {
+    let (sin_ra, cos_ra) = ra_rad.sin_cos();
+    let (sin_dec, cos_dec) = dec_rad.sin_cos();
+
+    let r = (cos_dec * cos_ra, cos_dec * sin_ra, sin_dec);
+    let p = (-sin_ra, cos_ra, 0.0);
+    let q = (-sin_dec * cos_ra, -sin_dec * sin_ra, cos_dec);
+
+    let pm_ra_rad_yr = pm_ra_masyr * MAS_TO_RAD;
+    let pm_dec_rad_yr = pm_dec_masyr * MAS_TO_RAD;
+    let tangential = (
+        pm_ra_rad_yr * p.0 + pm_dec_rad_yr * q.0,
+        pm_ra_rad_yr * p.1 + pm_dec_rad_yr * q.1,
+        pm_ra_rad_yr * p.2 + pm_dec_rad_yr * q.2,
+    );
+
+    let (pos, vel) = if parallax_mas > 0.0 {
+        let parallax_arcsec = parallax_mas / 1000.0;
+        let dist_au = AU_PER_PARSEC / parallax_arcsec;
+        let rv_au_yr = rv_kms / KM_S_PER_AU_YR;
+
+        let pos = (dist_au * r.0, dist_au * r.1, dist_au * r.2);
+        let vel = (
+            dist_au * tangential.0 + rv_au_yr * r.0,
+            dist_au * tangential.1 + rv_au_yr * r.1,
+            dist_au * tangential.2 + rv_au_yr * r.2,
+        );
+        (pos, vel)
+    } else {
+        (r, tangential)
+    };
+
+    let dt = epoch2_year - epoch1_year;
+    let pos2 = (pos.0 + vel.0 * dt, pos.1 + vel.1 * dt, pos.2 + vel.2 * dt);
+
+    let new_ra = pos2.1.atan2(pos2.0).rem_euclid(2.0 * PI);
+    let new_dec = pos2.2.atan2(pos2.0.hypot(pos2.1));
+
+    (new_ra, new_dec)
 }
 
 fn parse_f64(s: &str) -> Option<f64> {
@@ -31,13 +213,17 @@ fn parse_u32(s: &str) -> Option<u32> {
     s.trim().parse().ok()
 }
 
+fn parse_u64(s: &str) -> Option<u64> {
+    s.trim().parse().ok()
+}
+
 /// Parse Yale BSC fixed-width format
 fn parse_bsc_line(line: &str) -> Option<Star> {
     if line.len() < 114 {
         return None;
     }
 
-    let id = parse_u32(&line[0..4])?;
+    let id = parse_u32(&line[0..4])? as u64;
 
     // RA (columns 76-83): HH MM SS.S
     let ra_h = parse_f64(&line[75..77])?;
@@ -57,7 +243,34 @@ fn parse_bsc_line(line: &str) -> Option<Star> {
     let vmag = parse_f64(&line[102..107])? as f32;
     let bv = parse_f64(&line[109..114]).unwrap_or(0.0) as f32;
 
-    Some(Star { id, ra_rad, dec_rad, vmag, bv })
+    // Proper motion (columns 149-160, arcsec/year), parallax (columns
+    // 162-166, arcsec) and radial velocity (columns 167-170, km/s) sit
+    // past the range the length check above guarantees, so each is only
+    // read when the line is actually long enough to hold it; catalogs
+    // trimmed to just the position/magnitude columns still parse, just
+    // with these left at their "unknown" defaults.
+    let pm_ra_masyr = if line.len() >= 154 {
+        (parse_f64(&line[148..154]).unwrap_or(0.0) * 1000.0) as f32
+    } else {
+        0.0
+    };
+    let pm_dec_masyr = if line.len() >= 160 {
+        (parse_f64(&line[154..160]).unwrap_or(0.0) * 1000.0) as f32
+    } else {
+        0.0
+    };
+    let parallax_mas = if line.len() >= 166 {
+        (parse_f64(&line[161..166]).unwrap_or(0.0) * 1000.0) as f32
+    } else {
+        0.0
+    };
+    let rv_kms = if line.len() >= 170 {
+        parse_f64(&line[166..170]).unwrap_or(0.0) as f32
+    } else {
+        0.0
+    };
+
+    Some(Star { id, ra_rad, dec_rad, vmag, bv, parallax_mas, pm_ra_masyr, pm_dec_masyr, rv_kms })
 }
 
 /// Parse Hipparcos pipe-delimited format
@@ -73,7 +286,7 @@ fn parse_hipparcos_line(line: &str) -> Option<Star> {
     }
 
     // Field 1: HIP number
-    let id = parse_u32(fields[1])?;
+    let id = parse_u32(fields[1])? as u64;
 
     // Field 5: Visual magnitude
     let vmag = parse_f64(fields[5])? as f32;
@@ -89,23 +302,405 @@ fn parse_hipparcos_line(line: &str) -> Option<Star> {
     // Field 37: B-V color index (may be empty)
     let bv = parse_f64(fields[37]).unwrap_or(0.65) as f32; // Default to G-type star color
 
-    Some(Star { id, ra_rad, dec_rad, vmag, bv })
+    // Field 11: Parallax (mas); Field 12: pmRA*cos(Dec) (mas/yr); Field 13:
+    // pmDE (mas/yr). The Hipparcos Main Catalogue doesn't carry a radial
+    // velocity of its own, so `rv_kms` falls back to the companion-catalog
+    // default of `0.0` (unknown) here.
+    let parallax_mas = parse_f64(fields[11]).unwrap_or(0.0) as f32;
+    let pm_ra_masyr = parse_f64(fields[12]).unwrap_or(0.0) as f32;
+    let pm_dec_masyr = parse_f64(fields[13]).unwrap_or(0.0) as f32;
+    let rv_kms = 0.0;
+
+    Some(Star { id, ra_rad, dec_rad, vmag, bv, parallax_mas, pm_ra_masyr, pm_dec_masyr, rv_kms })
+}
+
+/// Parse one data row of a Gaia DR3 source CSV export, given a mapping from
+/// column name to position built from the file's header row (Gaia's column
+/// order is not fixed across queries, so positions can't be hardcoded the
+/// way the BSC/Hipparcos fixed-width/field-numbered parsers do).
+fn parse_gaia_line(line: &str, columns: &std::collections::HashMap<&str, usize>) -> Option<Star> {
+    let fields: Vec<&str> = line.split(',').collect();
+    let field = |name: &str| -> Option<&str> { fields.get(*columns.get(name)?).copied() };
+
+    let id = parse_u64(field("source_id")?)?;
+    let ra_deg = parse_f64(field("ra")?)?;
+    let dec_deg = parse_f64(field("dec")?)?;
+    let vmag = parse_f64(field("phot_g_mean_mag")?)? as f32;
+
+    let ra_rad = (ra_deg * PI / 180.0) as f32;
+    let dec_rad = (dec_deg * PI / 180.0) as f32;
+
+    // `bp_rp` is Gaia's own BP-RP color; it's a different photometric system
+    // than Johnson B-V, so this is only a rough linear approximation, good
+    // enough to slot into the existing `bv` field without a real B and V
+    // magnitude to compute it from.
+    let bv = field("bp_rp")
+        .and_then(parse_f64)
+        .map(|bp_rp| (0.02 + bp_rp * 0.4) as f32)
+        .unwrap_or(0.65); // Default to G-type star color, as Hipparcos does.
+
+    let parallax_mas = field("parallax").and_then(parse_f64).unwrap_or(0.0) as f32;
+    let pm_ra_masyr = field("pmra").and_then(parse_f64).unwrap_or(0.0) as f32;
+    let pm_dec_masyr = field("pmdec").and_then(parse_f64).unwrap_or(0.0) as f32;
+    // Gaia DR3's base source table doesn't carry a radial velocity for most
+    // stars (it's only populated for a bright subset), so this falls back
+    // to the same "unknown" default the other two parsers use.
+    let rv_kms = 0.0;
+
+    Some(Star { id, ra_rad, dec_rad, vmag, bv, parallax_mas, pm_ra_masyr, pm_dec_masyr, rv_kms })
 }
 
 /// Detect catalog format from first line
 fn detect_format(first_line: &str) -> &'static str {
     if first_line.starts_with('H') && first_line.contains('|') {
         "hipparcos"
+    } else if first_line.starts_with("solution_id,")
+        || (first_line.contains("source_id") && first_line.contains(','))
+    {
+        "gaia"
     } else {
         "bsc"
     }
 }
 
+/// Parse every line of an already-detected catalog, dropping any star at or
+/// fainter than magnitude 15 the same way `main`'s single-file path does.
+/// Shared by `main` and `run_merge` so the two code paths can't silently
+/// drift apart on what counts as a parseable or visible star.
+fn parse_all(lines: &[String], format: &str) -> (Vec<Star>, usize) {
+    let mut stars = Vec::new();
+    let mut skipped = 0;
+
+    if format == "gaia" {
+        // Gaia's CSV has a header row mapping column names to positions
+        // (the order isn't fixed across queries), so it's parsed on its own
+        // rather than sharing the line-at-a-time loop below.
+        let columns: std::collections::HashMap<&str, usize> = lines[0]
+            .split(',')
+            .enumerate()
+            .map(|(i, name)| (name.trim(), i))
+            .collect();
+
+        for line in &lines[1..] {
+            match parse_gaia_line(line, &columns) {
+                Some(s) if s.vmag < 15.0 => stars.push(s),
+                Some(_) => {}
+                None => skipped += 1,
+            }
+        }
+    } else {
+        for line in lines {
+            let star = match format {
+                "hipparcos" => parse_hipparcos_line(line),
+                _ => parse_bsc_line(line),
+            };
+
+            if let Some(s) = star {
+                if s.vmag < 15.0 {
+                    stars.push(s);
+                }
+            } else {
+                skipped += 1;
+            }
+        }
+    }
+
+    (stars, skipped)
+}
+
+/// Native reference epoch (decimal year) of a detected catalog format:
+/// Hipparcos positions are given at J1991.25, Gaia DR3's at J2016.0, and the
+/// BSC's J2000.0 positions are, as the name says, already at J2000.0.
+fn native_epoch_for(format: &str) -> f64 {
+    match format {
+        "hipparcos" => 1991.25,
+        "gaia" => 2016.0,
+        _ => 2000.0,
+    }
+}
+
+/// A star collected while cross-matching several catalogs in `run_merge`,
+/// carrying the designations it was matched under in each one so a merged
+/// record doesn't forget which HR/HIP/Gaia number it corresponds to just
+/// because the binary format only has room to store one of them.
+struct MergeEntry {
+    star: Star,
+    hr_id: Option<u64>,
+    hip_id: Option<u64>,
+    gaia_id: Option<u64>,
+}
+
+/// RA/Dec (radians) to an `f64` unit-direction triple, the same
+/// spherical-to-Cartesian construction `sky_engine_core::catalog`'s
+/// `scalar_direction` uses -- bucketing and comparing in this space rather
+/// than raw RA/Dec is what lets [`run_merge`]'s cross-match grid sidestep
+/// the RA=0/2pi seam and polar convergence entirely.
+fn unit_direction(ra: f64, dec: f64) -> (f64, f64, f64) {
+    let (sin_ra, cos_ra) = ra.sin_cos();
+    let (sin_dec, cos_dec) = dec.sin_cos();
+    (cos_dec * cos_ra, cos_dec * sin_ra, sin_dec)
+}
+
+/// Great-circle angular separation between two RA/Dec positions, in
+/// radians. Mirrors `sky_engine_core::catalog`'s private helper of the same
+/// name; duplicated here since this tool has no dependency on that crate.
+fn angular_separation_rad(ra1: f64, dec1: f64, ra2: f64, dec2: f64) -> f64 {
+    let a = unit_direction(ra1, dec1);
+    let b = unit_direction(ra2, dec2);
+    let cos_sep = (a.0 * b.0 + a.1 * b.1 + a.2 * b.2).clamp(-1.0, 1.0);
+    cos_sep.acos()
+}
+
+/// Cross-match and merge several input catalogs (`--merge a.dat b.dat ...
+/// out.bin`, see the module doc comment) into one deduplicated output.
+///
+/// Catalogs are loaded and normalized to a common epoch, then folded in
+/// order from highest to lowest positional precision (Gaia, then
+/// Hipparcos, then the BSC) into a growing list of `MergeEntry` records. A
+/// coarse 3D bucket grid over each star's unit direction vector
+/// ([`unit_direction`]), sized to the chord length of the match radius,
+/// keeps the cross-match from degrading to an O(n^2) all-pairs comparison:
+/// each incoming star only has to be compared against whatever already
+/// landed in its cell and the 26 neighboring cells. Bucketing on the
+/// Cartesian direction rather than raw RA/Dec (as a naive 2D grid would)
+/// avoids two failure modes a 2D grid has no cheap fix for: stars
+/// straddling the RA=0/2pi seam, which would otherwise land in
+/// non-adjacent cells, and stars near the celestial poles, where a fixed
+/// RA cell width corresponds to a wildly different angular size depending
+/// on `cos(dec)`. This is the same on-sphere indexing approach
+/// `sky_engine_core::catalog`'s kd-tree cone search uses, simplified down
+/// to a uniform grid since the match radius here is a small, fixed
+/// constant rather than an arbitrary query cone. A match backfills any
+/// `bv`/parallax/proper-motion/radial-velocity the existing entry is
+/// missing and records the incoming catalog's id; it never overwrites the
+/// position, since entries are folded in precision order. A non-match
+/// starts a new entry at its own (lower-precision) position.
+fn run_merge(args: &[String]) {
+    let match_radius_arcsec = args
+        .iter()
+        .position(|a| a == "--match-radius")
+        .and_then(|i| args.get(i + 1))
+        .map(|v| v.parse::<f64>().expect("--match-radius expects arcseconds"))
+        .unwrap_or(1.0);
+    let compress = args.iter().any(|a| a == "--compress");
+    let target_epoch = args
+        .iter()
+        .position(|a| a == "--epoch")
+        .and_then(|i| args.get(i + 1))
+        .map(|v| v.parse::<f64>().expect("--epoch expects a decimal year"));
+
+    // Strip the flags (and their values) out, leaving the positional
+    // catalog paths with the output path last.
+    let mut positional = Vec::new();
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--compress" => i += 1,
+            "--epoch" | "--match-radius" => i += 2,
+            other => {
+                positional.push(other.to_string());
+                i += 1;
+            }
+        }
+    }
+    if positional.len() < 2 {
+        eprintln!("--merge requires at least one input catalog and an output path");
+        std::process::exit(1);
+    }
+    let output_path = positional.pop().unwrap();
+    let input_paths = positional;
+
+    // Merged positions are all normalized to one epoch before cross-match,
+    // since comparing un-normalized positions from catalogs 25 years apart
+    // would bias the match (and the output) by each star's proper motion
+    // over that gap. Default to J2000.0, the BSC's own epoch and the
+    // convention used elsewhere in this tool when none is requested.
+    let output_epoch = target_epoch.unwrap_or(2000.0);
+
+    println!(
+        "Merging {} catalogs to epoch {} (match radius {} arcsec)...",
+        input_paths.len(),
+        output_epoch,
+        match_radius_arcsec
+    );
+
+    let mut loaded: Vec<(u8, &'static str, Vec<Star>)> = input_paths
+        .iter()
+        .map(|path| {
+            let file = File::open(path)
+                .unwrap_or_else(|e| panic!("Failed to open input file {}: {}", path, e));
+            let reader = BufReader::new(file);
+            let lines: Vec<String> = reader.lines().filter_map(|l| l.ok()).collect();
+            if lines.is_empty() {
+                eprintln!("Empty input file: {}", path);
+                std::process::exit(1);
+            }
+
+            let format = detect_format(&lines[0]);
+            let (mut stars, skipped) = parse_all(&lines, format);
+            println!(
+                "  {}: detected {}, parsed {} stars, skipped {} lines",
+                path,
+                format,
+                stars.len(),
+                skipped
+            );
+
+            let native_epoch = native_epoch_for(format);
+            if native_epoch != output_epoch {
+                for star in &mut stars {
+                    let (ra, dec) = apply_pm(
+                        star.ra_rad as f64,
+                        star.dec_rad as f64,
+                        star.pm_ra_masyr as f64,
+                        star.pm_dec_masyr as f64,
+                        star.rv_kms as f64,
+                        star.parallax_mas as f64,
+                        native_epoch,
+                        output_epoch,
+                    );
+                    star.ra_rad = ra as f32;
+                    star.dec_rad = dec as f32;
+                }
+            }
+
+            // Gaia > Hipparcos > BSC, the cross-match precision order.
+            let precision = match format {
+                "gaia" => 2,
+                "hipparcos" => 1,
+                _ => 0,
+            };
+            (precision, format, stars)
+        })
+        .collect();
+    loaded.sort_by_key(|(precision, _, _)| std::cmp::Reverse(*precision));
+
+    let match_radius_rad = match_radius_arcsec * (PI / 648_000.0); // arcsec -> rad
+    // Chord length between two points on the unit sphere separated by
+    // `match_radius_rad`: the grid cell size in Cartesian direction space
+    // that corresponds to the angular match radius.
+    let cell_size = (2.0 * (match_radius_rad / 2.0).sin()).max(1e-12);
+    let cell_of = |(x, y, z): (f64, f64, f64)| -> (i64, i64, i64) {
+        (
+            (x / cell_size).floor() as i64,
+            (y / cell_size).floor() as i64,
+            (z / cell_size).floor() as i64,
+        )
+    };
+
+    let mut entries: Vec<MergeEntry> = Vec::new();
+    let mut grid: std::collections::HashMap<(i64, i64, i64), Vec<usize>> =
+        std::collections::HashMap::new();
+    let mut cross_matched = 0usize;
+
+    for (_precision, format, stars) in loaded {
+        for star in stars {
+            let (hr_id, hip_id, gaia_id) = match format {
+                "gaia" => (None, None, Some(star.id)),
+                "hipparcos" => (None, Some(star.id), None),
+                _ => (Some(star.id), None, None),
+            };
+
+            let ra = star.ra_rad as f64;
+            let dec = star.dec_rad as f64;
+            let direction = unit_direction(ra, dec);
+            let cell = cell_of(direction);
+            let mut best: Option<(usize, f64)> = None;
+            for d_x in -1..=1 {
+                for d_y in -1..=1 {
+                    for d_z in -1..=1 {
+                        let Some(candidates) =
+                            grid.get(&(cell.0 + d_x, cell.1 + d_y, cell.2 + d_z))
+                        else {
+                            continue;
+                        };
+                        for &idx in candidates {
+                            let other = &entries[idx].star;
+                            let sep = angular_separation_rad(
+                                ra,
+                                dec,
+                                other.ra_rad as f64,
+                                other.dec_rad as f64,
+                            );
+                            if sep <= match_radius_rad && best.is_none_or(|(_, best_sep)| sep < best_sep) {
+                                best = Some((idx, sep));
+                            }
+                        }
+                    }
+                }
+            }
+
+            if let Some((idx, _)) = best {
+                cross_matched += 1;
+                let entry = &mut entries[idx];
+                if entry.star.bv == 0.0 {
+                    entry.star.bv = star.bv;
+                }
+                if entry.star.parallax_mas <= 0.0 {
+                    entry.star.parallax_mas = star.parallax_mas;
+                }
+                if entry.star.pm_ra_masyr == 0.0 && entry.star.pm_dec_masyr == 0.0 {
+                    entry.star.pm_ra_masyr = star.pm_ra_masyr;
+                    entry.star.pm_dec_masyr = star.pm_dec_masyr;
+                }
+                if entry.star.rv_kms == 0.0 {
+                    entry.star.rv_kms = star.rv_kms;
+                }
+                if hr_id.is_some() {
+                    entry.hr_id = hr_id;
+                }
+                if hip_id.is_some() {
+                    entry.hip_id = hip_id;
+                }
+                if gaia_id.is_some() {
+                    entry.gaia_id = gaia_id;
+                }
+            } else {
+                let new_idx = entries.len();
+                entries.push(MergeEntry { star, hr_id, hip_id, gaia_id });
+                grid.entry(cell).or_default().push(new_idx);
+            }
+        }
+    }
+
+    let multi_catalog = entries
+        .iter()
+        .filter(|e| {
+            [e.hr_id.is_some(), e.hip_id.is_some(), e.gaia_id.is_some()]
+                .iter()
+                .filter(|present| **present)
+                .count()
+                > 1
+        })
+        .count();
+    println!(
+        "\nCross-identification: {} stars matched across catalogs, {} unique stars in the merge ({} total input stars)",
+        cross_matched,
+        entries.len(),
+        cross_matched + entries.len()
+    );
+    println!(
+        "  {} of them carry designations from more than one input catalog",
+        multi_catalog
+    );
+
+    let mut stars: Vec<Star> = entries.into_iter().map(|e| e.star).collect();
+    stars.sort_by(|a, b| a.vmag.partial_cmp(&b.vmag).unwrap());
+
+    write_catalog(&stars, output_path.as_str(), output_epoch, SOURCE_OTHER, compress);
+}
+
 fn main() {
     let args: Vec<String> = env::args().collect();
 
+    if args.len() >= 2 && args[1] == "--merge" {
+        run_merge(&args[2..]);
+        return;
+    }
+
     if args.len() < 3 {
-        eprintln!("Usage: {} <input.dat> <output.bin> [--hipparcos]", args[0]);
+        eprintln!("Usage: {} <input.dat> <output.bin> [--hipparcos] [--gaia] [--epoch <year>] [--compress]", args[0]);
+        eprintln!("       {} --merge <input...> <output.bin> [--epoch <year>] [--compress] [--match-radius <arcsec>]", args[0]);
         eprintln!();
         eprintln!("Supported catalogs:");
         eprintln!("  Yale BSC (bsc5.dat):");
@@ -113,12 +708,33 @@ fn main() {
         eprintln!();
         eprintln!("  Hipparcos (hip_main.dat):");
         eprintln!("    curl -O https://cdsarc.cds.unistra.fr/ftp/cats/I/239/hip_main.dat");
+        eprintln!();
+        eprintln!("  Gaia DR3 source CSV export (Gaia Archive query result, with header row):");
+        eprintln!("    columns: source_id, ra, dec, phot_g_mean_mag, bp_rp, parallax, pmra, pmdec");
+        eprintln!();
+        eprintln!("  --epoch <year>  Propagate every star's position to this decimal year");
+        eprintln!("                  (e.g. 2026.0) via proper motion, parallax and radial");
+        eprintln!("                  velocity, instead of leaving it at the catalog's epoch.");
+        eprintln!();
+        eprintln!("  --compress      Wrap the record block in an xz stream (requires the `xz`");
+        eprintln!("                  binary on PATH); the header and CRC stay uncompressed.");
+        eprintln!();
+        eprintln!("  --merge         Cross-match and merge several input catalogs into one");
+        eprintln!("                  deduplicated output instead of converting a single file.");
+        eprintln!("                  --match-radius <arcsec> sets the match threshold (default 1).");
         std::process::exit(1);
     }
 
     let input_path = &args[1];
     let output_path = &args[2];
     let force_hipparcos = args.iter().any(|a| a == "--hipparcos");
+    let force_gaia = args.iter().any(|a| a == "--gaia");
+    let compress = args.iter().any(|a| a == "--compress");
+    let target_epoch = args
+        .iter()
+        .position(|a| a == "--epoch")
+        .and_then(|i| args.get(i + 1))
+        .map(|v| v.parse::<f64>().expect("--epoch expects a decimal year"));
 
     let file = File::open(input_path).expect("Failed to open input file");
     let reader = BufReader::new(file);
@@ -132,6 +748,8 @@ fn main() {
     // Detect format
     let format = if force_hipparcos {
         "hipparcos"
+    } else if force_gaia {
+        "gaia"
     } else {
         detect_format(&lines[0])
     };
@@ -139,26 +757,31 @@ fn main() {
     println!("Detected format: {}", format);
     println!("Processing {} lines...", lines.len());
 
-    let mut stars: Vec<Star> = Vec::new();
-    let mut skipped = 0;
+    let (mut stars, skipped) = parse_all(&lines, format);
 
-    for line in &lines {
-        let star = match format {
-            "hipparcos" => parse_hipparcos_line(line),
-            _ => parse_bsc_line(line),
-        };
+    println!("Parsed {} stars, skipped {} lines", stars.len(), skipped);
 
-        if let Some(s) = star {
-            if s.vmag < 15.0 {
-                stars.push(s);
-            }
-        } else {
-            skipped += 1;
+    let native_epoch = native_epoch_for(format);
+    let output_epoch = target_epoch.unwrap_or(native_epoch);
+
+    if let Some(target_epoch) = target_epoch {
+        println!("Propagating positions from epoch {} to {}...", native_epoch, target_epoch);
+        for star in &mut stars {
+            let (ra, dec) = apply_pm(
+                star.ra_rad as f64,
+                star.dec_rad as f64,
+                star.pm_ra_masyr as f64,
+                star.pm_dec_masyr as f64,
+                star.rv_kms as f64,
+                star.parallax_mas as f64,
+                native_epoch,
+                target_epoch,
+            );
+            star.ra_rad = ra as f32;
+            star.dec_rad = dec as f32;
         }
     }
 
-    println!("Parsed {} stars, skipped {} lines", stars.len(), skipped);
-
     // Sort by magnitude (brightest first)
     stars.sort_by(|a, b| a.vmag.partial_cmp(&b.vmag).unwrap());
 
@@ -175,22 +798,68 @@ fn main() {
         }
     }
 
-    // Write binary output
-    let mut out = File::create(output_path).expect("Failed to create output file");
+    let source = match format {
+        "hipparcos" => SOURCE_HIPPARCOS,
+        "gaia" => SOURCE_GAIA,
+        _ => SOURCE_BSC,
+    };
+    write_catalog(&stars, output_path, output_epoch, source, compress);
+}
 
-    // Header: star count
-    out.write_all(&(stars.len() as u32).to_le_bytes())
-        .expect("Failed to write header");
+/// Write `stars` to `output_path` in the self-describing `ONCESTAR` binary
+/// format (see `catalog::load_versioned_catalog`'s doc comment for the
+/// exact layout this mirrors): a header naming the source catalog and
+/// epoch, the record block, and a trailing CRC-32 so a reader can detect a
+/// truncated or corrupted file instead of silently loading garbage stars.
+fn write_catalog(stars: &[Star], output_path: &str, output_epoch: f64, source: u8, compress: bool) {
+    let has_parallax = stars.iter().any(|s| s.parallax_mas > 0.0);
+    let has_proper_motion = stars
+        .iter()
+        .any(|s| s.pm_ra_masyr != 0.0 || s.pm_dec_masyr != 0.0);
+    let mut flags = 0u16;
+    if has_proper_motion {
+        flags |= FLAG_PROPER_MOTION;
+    }
+    if has_parallax {
+        flags |= FLAG_PARALLAX;
+    }
 
-    // Per star: ra_rad, dec_rad, vmag, bv, id (20 bytes each)
-    for star in &stars {
-        out.write_all(&star.ra_rad.to_le_bytes()).unwrap();
-        out.write_all(&star.dec_rad.to_le_bytes()).unwrap();
-        out.write_all(&star.vmag.to_le_bytes()).unwrap();
-        out.write_all(&star.bv.to_le_bytes()).unwrap();
-        out.write_all(&star.id.to_le_bytes()).unwrap();
+    let mut out = File::create(output_path).expect("Failed to create output file");
+    out.write_all(HEADER_MAGIC).unwrap();
+    out.write_all(&HEADER_FORMAT_VERSION.to_le_bytes()).unwrap();
+    out.write_all(&flags.to_le_bytes()).unwrap();
+    out.write_all(&[source]).unwrap();
+    out.write_all(&(output_epoch as f32).to_le_bytes()).unwrap();
+    out.write_all(&(stars.len() as u32).to_le_bytes()).unwrap();
+
+    // Per star: ra_rad, dec_rad, vmag, bv, id (24 bytes), then
+    // parallax_mas if present, then pm_ra_masyr/pm_dec_masyr if present.
+    let mut records = Vec::new();
+    for star in stars {
+        records.extend_from_slice(&star.ra_rad.to_le_bytes());
+        records.extend_from_slice(&star.dec_rad.to_le_bytes());
+        records.extend_from_slice(&star.vmag.to_le_bytes());
+        records.extend_from_slice(&star.bv.to_le_bytes());
+        records.extend_from_slice(&star.id.to_le_bytes());
+        if has_parallax {
+            records.extend_from_slice(&star.parallax_mas.to_le_bytes());
+        }
+        if has_proper_motion {
+            records.extend_from_slice(&star.pm_ra_masyr.to_le_bytes());
+            records.extend_from_slice(&star.pm_dec_masyr.to_le_bytes());
+        }
     }
+    let body = if compress {
+        println!("Compressing record block with xz...");
+        let compressed = compress_xz(&records);
+        debug_assert!(compressed.starts_with(&XZ_MAGIC));
+        compressed
+    } else {
+        records.clone()
+    };
+    out.write_all(&body).unwrap();
+    out.write_all(&crc32(&records).to_le_bytes()).unwrap();
 
-    let file_size = 4 + stars.len() * 20;
+    let file_size = HEADER_SIZE + body.len() + 4;
     println!("\nWrote {} bytes ({:.1} KB) to {}", file_size, file_size as f64 / 1024.0, output_path);
 }